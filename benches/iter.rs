@@ -27,7 +27,7 @@ impl Inc for Big {
 
 fn bench_iter_2(c: &mut Criterion, size: usize) {
     let small = (Small(0), Small(1));
-    let mut vec = Vec::from(vec![small]).repeat(size);
+    let mut vec = [small].repeat(size);
     c.bench_function(&format!("iter_vec_small_2x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2) in vec.iter_mut() {
@@ -46,7 +46,7 @@ fn bench_iter_2(c: &mut Criterion, size: usize) {
         })
     });
     let mixed = (Big::default(), Small(1));
-    let mut vec = Vec::from(vec![mixed]).repeat(size);
+    let mut vec = [mixed].repeat(size);
     c.bench_function(&format!("iter_vec_mixed_2x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2) in vec.iter_mut() {
@@ -65,7 +65,7 @@ fn bench_iter_2(c: &mut Criterion, size: usize) {
         })
     });
     let big = (Big::default(), Big::default());
-    let mut vec = Vec::from(vec![big]).repeat(size);
+    let mut vec = [big].repeat(size);
     c.bench_function(&format!("iter_vec_big_2x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2) in vec.iter_mut() {
@@ -87,7 +87,7 @@ fn bench_iter_2(c: &mut Criterion, size: usize) {
 
 fn bench_iter_3(c: &mut Criterion, size: usize) {
     let small = (Small(0), Small(1), Small(2));
-    let mut vec = Vec::from(vec![small]).repeat(size);
+    let mut vec = [small].repeat(size);
     c.bench_function(&format!("iter_vec_small_3x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2, item_3) in vec.iter_mut() {
@@ -108,7 +108,7 @@ fn bench_iter_3(c: &mut Criterion, size: usize) {
         })
     });
     let mixed = (Big::default(), Small(1), Big::default());
-    let mut vec = Vec::from(vec![mixed]).repeat(size);
+    let mut vec = [mixed].repeat(size);
     c.bench_function(&format!("iter_vec_mixed_3x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2, item_3) in vec.iter_mut() {
@@ -129,7 +129,7 @@ fn bench_iter_3(c: &mut Criterion, size: usize) {
         })
     });
     let big = (Big::default(), Big::default(), Big::default());
-    let mut vec = Vec::from(vec![big]).repeat(size);
+    let mut vec = [big].repeat(size);
     c.bench_function(&format!("iter_vec_big_3x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2, item_3) in vec.iter_mut() {
@@ -153,7 +153,7 @@ fn bench_iter_3(c: &mut Criterion, size: usize) {
 
 fn bench_iter_4(c: &mut Criterion, size: usize) {
     let small = (Small(0), Small(1), Small(2), Small(3));
-    let mut vec = Vec::from(vec![small]).repeat(size);
+    let mut vec = [small].repeat(size);
     c.bench_function(&format!("iter_vec_small_4x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2, item_3, item_4) in vec.iter_mut() {
@@ -176,7 +176,7 @@ fn bench_iter_4(c: &mut Criterion, size: usize) {
         })
     });
     let mixed = (Big::default(), Small(1), Big::default(), Small(2));
-    let mut vec = Vec::from(vec![mixed]).repeat(size);
+    let mut vec = [mixed].repeat(size);
     c.bench_function(&format!("iter_vec_mixed_4x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2, item_3, item_4) in vec.iter_mut() {
@@ -204,7 +204,7 @@ fn bench_iter_4(c: &mut Criterion, size: usize) {
         Big::default(),
         Big::default(),
     );
-    let mut vec = Vec::from(vec![big]).repeat(size);
+    let mut vec = [big].repeat(size);
     c.bench_function(&format!("iter_vec_big_4x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2, item_3, item_4) in vec.iter_mut() {
@@ -230,7 +230,7 @@ fn bench_iter_4(c: &mut Criterion, size: usize) {
 
 fn bench_iter_5(c: &mut Criterion, size: usize) {
     let small = (Small(0), Small(1), Small(2), Small(3), Small(4));
-    let mut vec = Vec::from(vec![small]).repeat(size);
+    let mut vec = [small].repeat(size);
     c.bench_function(&format!("iter_vec_small_5x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2, item_3, item_4, item_5) in vec.iter_mut() {
@@ -261,7 +261,7 @@ fn bench_iter_5(c: &mut Criterion, size: usize) {
         Small(2),
         Big::default(),
     );
-    let mut vec = Vec::from(vec![mixed]).repeat(size);
+    let mut vec = [mixed].repeat(size);
     c.bench_function(&format!("iter_vec_mixed_5x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2, item_3, item_4, item_5) in vec.iter_mut() {
@@ -292,7 +292,7 @@ fn bench_iter_5(c: &mut Criterion, size: usize) {
         Big::default(),
         Big::default(),
     );
-    let mut vec = Vec::from(vec![big]).repeat(size);
+    let mut vec = [big].repeat(size);
     c.bench_function(&format!("iter_vec_big_5x_{}", size), |b| {
         b.iter(|| {
             for (item_1, item_2, item_3, item_4, item_5) in vec.iter_mut() {
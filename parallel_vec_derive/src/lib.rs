@@ -0,0 +1,33 @@
+//! Derive macros for the [`parallel_vec`](https://crates.io/crates/parallel_vec) crate.
+//!
+//! This crate should not be used directly. Enable the `derive` feature of `parallel_vec`
+//! and use the re-exported `parallel_vec::ParallelVecParam` and `parallel_vec::soa`
+//! instead.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, Error};
+
+mod param;
+mod soa;
+
+/// Implements `ParallelParam` for a struct, storing each of its fields in its own column.
+///
+/// See `parallel_vec::ParallelVecParam` for the full documentation.
+#[proc_macro_derive(ParallelVecParam)]
+pub fn derive_parallel_vec_param(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    param::expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Generates a dedicated container type for a struct, with methods named after its fields.
+///
+/// See `parallel_vec::soa` for the full documentation.
+#[proc_macro_attribute]
+pub fn soa(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    soa::expand(args.into(), input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
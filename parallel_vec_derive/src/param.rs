@@ -0,0 +1,332 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, Index};
+
+/// The largest number of fields supported by `ParallelParam`.
+const MAX_FIELDS: usize = 16;
+
+/// Expands `#[derive(ParallelVecParam)]`.
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            Fields::Unnamed(fields) => &fields.unnamed,
+            Fields::Unit => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "ParallelVecParam cannot be derived for unit structs",
+                ))
+            }
+        },
+        Data::Enum(data) => {
+            return Err(Error::new_spanned(
+                data.enum_token,
+                "ParallelVecParam can only be derived for structs",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "ParallelVecParam can only be derived for structs",
+            ))
+        }
+    };
+    if fields.is_empty() || fields.len() > MAX_FIELDS {
+        return Err(Error::new_spanned(
+            &input.ident,
+            format!("ParallelVecParam requires between 1 and {MAX_FIELDS} fields"),
+        ));
+    }
+    for attr in &input.attrs {
+        if attr.path().is_ident("repr") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("packed") {
+                    return Err(meta.error("ParallelVecParam cannot be derived for packed structs"));
+                }
+                // Skip over the arguments of other representations, e.g. `align(8)`.
+                if meta.input.peek(syn::token::Paren) {
+                    let args;
+                    syn::parenthesized!(args in meta.input);
+                    args.parse::<TokenStream2>()?;
+                }
+                Ok(())
+            })?;
+        }
+    }
+
+    let krate = quote!(::parallel_vec);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let members: Vec<syn::Member> = fields
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(Index {
+                index: idx as u32,
+                span: Span::call_site(),
+            }),
+        })
+        .collect();
+    let types: Vec<&syn::Type> = fields.iter().map(|field| &field.ty).collect();
+    let values: Vec<syn::Ident> = (0..fields.len())
+        .map(|idx| format_ident!("__field{}", idx))
+        .collect();
+    let tuple = quote!((#(#types,)*));
+    let inner = quote!(<#tuple as #krate::ParallelParam>);
+
+    let columns = types.iter().enumerate().map(|(idx, ty)| {
+        let idx = Index::from(idx);
+        quote! {
+            impl #impl_generics #krate::ParallelColumn<#idx> for #name #ty_generics #where_clause {
+                type Column = #ty;
+
+                #[inline(always)]
+                fn column<'__a>(slices: Self::Slices<'__a>) -> &'__a [#ty]
+                where
+                    Self: '__a,
+                {
+                    slices.#idx
+                }
+
+                #[inline(always)]
+                fn column_storage(storage: Self::Storage) -> ::core::ptr::NonNull<#ty> {
+                    storage.#idx
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #krate::__private::Sealed for #name #ty_generics #where_clause {}
+
+        // SAFE: Every operation is forwarded to the tuple of the types of the fields, and
+        // values are only converted to and from that tuple by moving their fields.
+        unsafe impl #impl_generics #krate::ParallelParam for #name #ty_generics #where_clause {
+            type Storage = #inner::Storage;
+            type Ptr = #inner::Ptr;
+            type ConstPtr = #inner::ConstPtr;
+            type Offsets = #inner::Offsets;
+            type Ref<'__a> = #inner::Ref<'__a> where Self: '__a;
+            type RefMut<'__a> = #inner::RefMut<'__a> where Self: '__a;
+            type Vecs = #inner::Vecs;
+            type Slices<'__a> = #inner::Slices<'__a> where Self: '__a;
+            type SlicesMut<'__a> = #inner::SlicesMut<'__a> where Self: '__a;
+            type UninitSlicesMut<'__a> = #inner::UninitSlicesMut<'__a> where Self: '__a;
+            type Iters<'__a> = #inner::Iters<'__a> where Self: '__a;
+            type ItersMut<'__a> = #inner::ItersMut<'__a> where Self: '__a;
+            type Arrays<const __N: usize> = #inner::Arrays<__N>;
+
+            #[inline(always)]
+            fn dangling() -> Self::Storage {
+                #inner::dangling()
+            }
+
+            #[inline(always)]
+            fn as_ptr(storage: Self::Storage) -> Self::Ptr {
+                #inner::as_ptr(storage)
+            }
+
+            #[inline(always)]
+            fn as_const_ptr(storage: Self::Storage) -> Self::ConstPtr {
+                #inner::as_const_ptr(storage)
+            }
+
+            #[inline(always)]
+            unsafe fn from_base(
+                base: ::core::ptr::NonNull<u8>,
+                offsets: &Self::Offsets,
+            ) -> Self::Storage {
+                #inner::from_base(base, offsets)
+            }
+
+            #[inline(always)]
+            fn base(storage: Self::Storage) -> ::core::ptr::NonNull<u8> {
+                #inner::base(storage)
+            }
+
+            #[inline(always)]
+            fn field_sizes(len: usize) -> Self::Offsets {
+                #inner::field_sizes(len)
+            }
+
+            #[inline(always)]
+            fn try_layout_for_capacity_aligned(
+                capacity: usize,
+                column_align: usize,
+            ) -> ::core::option::Option<#krate::param::MemoryLayout<Self>> {
+                #inner::try_layout_for_capacity_aligned(capacity, column_align)
+                    .map(#krate::__private::cast_layout)
+            }
+
+            #[inline(always)]
+            fn get_vec_len(vecs: &Self::Vecs) -> ::core::option::Option<usize> {
+                #inner::get_vec_len(vecs)
+            }
+
+            #[inline(always)]
+            unsafe fn get_vec_ptrs(vecs: &mut Self::Vecs) -> Self::Ptr {
+                #inner::get_vec_ptrs(vecs)
+            }
+
+            #[inline(always)]
+            fn vecs_with_capacity(capacity: usize) -> Self::Vecs {
+                #inner::vecs_with_capacity(capacity)
+            }
+
+            #[inline(always)]
+            unsafe fn set_vec_len(vecs: &mut Self::Vecs, len: usize) {
+                #inner::set_vec_len(vecs, len)
+            }
+
+            #[inline(always)]
+            fn uninit_arrays<const __N: usize>() -> Self::Arrays<__N> {
+                #inner::uninit_arrays()
+            }
+
+            #[inline(always)]
+            fn arrays_storage<const __N: usize>(arrays: &Self::Arrays<__N>) -> Self::Storage {
+                #inner::arrays_storage(arrays)
+            }
+
+            #[inline(always)]
+            fn arrays_storage_mut<const __N: usize>(
+                arrays: &mut Self::Arrays<__N>,
+            ) -> Self::Storage {
+                #inner::arrays_storage_mut(arrays)
+            }
+
+            #[inline(always)]
+            fn get_slices_len(slices: &Self::SlicesMut<'_>) -> ::core::option::Option<usize> {
+                #inner::get_slices_len(slices)
+            }
+
+            #[inline(always)]
+            fn get_slices_ptrs(slices: &mut Self::SlicesMut<'_>) -> Self::Ptr {
+                #inner::get_slices_ptrs(slices)
+            }
+
+            #[inline(always)]
+            unsafe fn add(base: Self::Ptr, offset: usize) -> Self::Ptr {
+                #inner::add(base, offset)
+            }
+
+            #[inline(always)]
+            unsafe fn copy_to(src: Self::Ptr, dst: Self::Ptr, size: usize) {
+                #inner::copy_to(src, dst, size)
+            }
+
+            #[inline(always)]
+            unsafe fn copy_to_nonoverlapping(src: Self::Ptr, dst: Self::Ptr, size: usize) {
+                #inner::copy_to_nonoverlapping(src, dst, size)
+            }
+
+            #[inline(always)]
+            unsafe fn as_slices<'__a>(ptr: Self::Ptr, len: usize) -> Self::Slices<'__a> {
+                #inner::as_slices(ptr, len)
+            }
+
+            #[inline(always)]
+            unsafe fn as_slices_mut<'__a>(ptr: Self::Ptr, len: usize) -> Self::SlicesMut<'__a> {
+                #inner::as_slices_mut(ptr, len)
+            }
+
+            #[inline(always)]
+            unsafe fn as_uninit_slices_mut<'__a>(
+                ptr: Self::Ptr,
+                len: usize,
+            ) -> Self::UninitSlicesMut<'__a> {
+                #inner::as_uninit_slices_mut(ptr, len)
+            }
+
+            #[inline(always)]
+            fn iters<'__a>(slices: Self::Slices<'__a>) -> Self::Iters<'__a> {
+                #inner::iters(slices)
+            }
+
+            #[inline(always)]
+            fn iters_mut<'__a>(slices: Self::SlicesMut<'__a>) -> Self::ItersMut<'__a> {
+                #inner::iters_mut(slices)
+            }
+
+            #[inline(always)]
+            fn reverse(slices: Self::SlicesMut<'_>) {
+                #inner::reverse(slices)
+            }
+
+            #[inline(always)]
+            unsafe fn as_ref<'__a>(ptr: Self::Ptr) -> Self::Ref<'__a> {
+                #inner::as_ref(ptr)
+            }
+
+            #[inline(always)]
+            fn field_ptrs(value: &Self) -> Self::Ptr {
+                (#(::core::ptr::NonNull::from(&value.#members),)*)
+            }
+
+            #[inline(always)]
+            unsafe fn as_storage(ptr: Self::Ptr) -> Self::Storage {
+                #inner::as_storage(ptr)
+            }
+
+            #[inline(always)]
+            unsafe fn as_mut<'__a>(ptr: Self::Ptr) -> Self::RefMut<'__a> {
+                #inner::as_mut(ptr)
+            }
+
+            #[inline(always)]
+            unsafe fn read(ptr: Self::Ptr) -> Self {
+                let (#(#values,)*) = #inner::read(ptr);
+                Self { #(#members: #values,)* }
+            }
+
+            #[inline(always)]
+            unsafe fn write(ptr: Self::Ptr, value: Self) {
+                #inner::write(ptr, (#(value.#members,)*))
+            }
+
+            #[inline(always)]
+            unsafe fn write_rows(dst: Self::Ptr, src: *const Self, len: usize) {
+                let (#(#values,)*) = dst;
+                #(
+                    for idx in 0..len {
+                        let value = ::core::ptr::addr_of!((*src.add(idx)).#members).read();
+                        #values.as_ptr().add(idx).write(value);
+                    }
+                )*
+            }
+
+            #[inline(always)]
+            unsafe fn swap(a: Self::Ptr, b: Self::Ptr) {
+                #inner::swap(a, b)
+            }
+
+            #[inline(always)]
+            unsafe fn swap_nonoverlapping(a: Self::Ptr, b: Self::Ptr, size: usize) {
+                #inner::swap_nonoverlapping(a, b, size)
+            }
+
+            #[inline(always)]
+            unsafe fn drop(ptr: Self::Ptr) {
+                #inner::drop(ptr)
+            }
+
+            #[inline(always)]
+            unsafe fn drop_slice(ptr: Self::Ptr, len: usize) {
+                #inner::drop_slice(ptr, len)
+            }
+        }
+
+        #(#columns)*
+
+        // The fields are stored and dropped separately, so the struct cannot implement
+        // `Drop`. If it does, these implementations conflict.
+        const _: () = {
+            trait MustNotImplDrop {}
+            #[allow(drop_bounds)]
+            impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+            impl #impl_generics MustNotImplDrop for #name #ty_generics #where_clause {}
+        };
+    })
+}
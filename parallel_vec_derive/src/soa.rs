@@ -0,0 +1,449 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, GenericParam, Index, Lifetime, LifetimeParam};
+
+/// Expands `#[soa]`.
+pub(crate) fn expand(args: TokenStream2, input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !args.is_empty() {
+        return Err(Error::new_spanned(args, "soa does not take any arguments"));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "soa can only be used on structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "soa can only be used on structs with named fields",
+            ))
+        }
+    };
+    let param = crate::param::expand(input.clone())?;
+
+    let krate = quote!(::parallel_vec);
+    let vis = &input.vis;
+    let name = &input.ident;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let vec_name = format_ident!("{}Vec", name);
+    let ref_name = format_ident!("{}Ref", name);
+    let ref_mut_name = format_ident!("{}RefMut", name);
+    let iter_name = format_ident!("{}Iter", name);
+    let iter_mut_name = format_ident!("{}IterMut", name);
+
+    // The lifetime of the borrows of the vector, which must not shadow one of the struct.
+    let lifetime = ["'a", "'__a"]
+        .iter()
+        .map(|lifetime| Lifetime::new(lifetime, Span::call_site()))
+        .find(|lifetime| {
+            generics
+                .lifetimes()
+                .all(|param| param.lifetime != *lifetime)
+        })
+        .unwrap();
+    let mut ref_generics = generics.clone();
+    ref_generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())),
+    );
+    let (ref_impl_generics, ref_ty_generics, _) = ref_generics.split_for_impl();
+
+    let field_names: Vec<&syn::Ident> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+    let field_vis: Vec<&syn::Visibility> = fields.iter().map(|f| &f.vis).collect();
+    let field_types: Vec<&syn::Type> = fields.iter().map(|f| &f.ty).collect();
+    let field_docs: Vec<String> = field_names
+        .iter()
+        .map(|field_name| format!("The `{}` field of the element.", field_name))
+        .collect();
+    let values: Vec<syn::Ident> = (0..fields.len())
+        .map(|idx| format_ident!("__field{}", idx))
+        .collect();
+
+    let ty_args: Vec<TokenStream2> = generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                quote!(#lifetime)
+            }
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                quote!(#ident)
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                quote!(#ident)
+            }
+        })
+        .collect();
+    let param_ty = quote!(#name #ty_generics);
+    let vec_ty = quote!(#krate::ParallelVec<#param_ty>);
+    let to_ref = quote! {
+        |(#(#values,)*)| #ref_name { #(#field_names: #values,)* }
+    };
+    let to_ref_mut = quote! {
+        |(#(#values,)*)| #ref_mut_name { #(#field_names: #values,)* }
+    };
+
+    let accessors = fields.iter().enumerate().map(|(idx, field)| {
+        let field_name = field.ident.as_ref().unwrap();
+        let field_mut = format_ident!("{}_mut", field_name);
+        let ty = &field.ty;
+        let idx = Index::from(idx);
+        let doc = format!(
+            "Gets the `{}` field of every element as a slice.",
+            field_name
+        );
+        let doc_mut = format!(
+            "Gets the `{}` field of every element as a mutable slice.",
+            field_name
+        );
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            #vis fn #field_name(&self) -> &[#ty] {
+                self.inner.as_slices().#idx
+            }
+
+            #[doc = #doc_mut]
+            #[inline]
+            #vis fn #field_mut(&mut self) -> &mut [#ty] {
+                self.inner.as_slices_mut().#idx
+            }
+        }
+    });
+
+    let vec_doc = format!(
+        "A [`ParallelVec`](parallel_vec::ParallelVec) of [`{name}`], with one column per \
+         field and methods named after the fields."
+    );
+    let ref_doc = format!("A reference to the fields of an element of a [`{vec_name}`].");
+    let ref_mut_doc =
+        format!("A mutable reference to the fields of an element of a [`{vec_name}`].");
+    let iter_doc = format!("An iterator over references to the elements of a [`{vec_name}`].");
+    let iter_mut_doc =
+        format!("An iterator over mutable references to the elements of a [`{vec_name}`].");
+
+    Ok(quote! {
+        #input
+
+        #param
+
+        #[doc = #vec_doc]
+        #vis struct #vec_name #generics #where_clause {
+            inner: #vec_ty,
+        }
+
+        #[doc = #ref_doc]
+        #[allow(dead_code)]
+        #vis struct #ref_name #ref_generics #where_clause {
+            #(
+                #[doc = #field_docs]
+                #field_vis #field_names: &#lifetime #field_types,
+            )*
+        }
+
+        impl #ref_impl_generics ::core::clone::Clone for #ref_name #ref_ty_generics #where_clause {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl #ref_impl_generics ::core::marker::Copy for #ref_name #ref_ty_generics #where_clause {}
+
+        #[doc = #ref_mut_doc]
+        #[allow(dead_code)]
+        #vis struct #ref_mut_name #ref_generics #where_clause {
+            #(
+                #[doc = #field_docs]
+                #field_vis #field_names: &#lifetime mut #field_types,
+            )*
+        }
+
+        #[doc = #iter_doc]
+        #vis struct #iter_name #ref_generics #where_clause {
+            inner: #krate::iter::Iter<#lifetime, #param_ty>,
+        }
+
+        impl #ref_impl_generics ::core::iter::Iterator for #iter_name #ref_ty_generics #where_clause {
+            type Item = #ref_name #ref_ty_generics;
+
+            #[inline]
+            fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                self.inner.next().map(#to_ref)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl #ref_impl_generics ::core::iter::DoubleEndedIterator for #iter_name #ref_ty_generics
+        #where_clause
+        {
+            #[inline]
+            fn next_back(&mut self) -> ::core::option::Option<Self::Item> {
+                self.inner.next_back().map(#to_ref)
+            }
+        }
+
+        impl #ref_impl_generics ::core::iter::ExactSizeIterator for #iter_name #ref_ty_generics
+        #where_clause
+        {
+        }
+
+        #[doc = #iter_mut_doc]
+        #vis struct #iter_mut_name #ref_generics #where_clause {
+            inner: #krate::iter::IterMut<#lifetime, #param_ty>,
+        }
+
+        impl #ref_impl_generics ::core::iter::Iterator for #iter_mut_name #ref_ty_generics
+        #where_clause
+        {
+            type Item = #ref_mut_name #ref_ty_generics;
+
+            #[inline]
+            fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                self.inner.next().map(#to_ref_mut)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl #ref_impl_generics ::core::iter::DoubleEndedIterator for #iter_mut_name #ref_ty_generics
+        #where_clause
+        {
+            #[inline]
+            fn next_back(&mut self) -> ::core::option::Option<Self::Item> {
+                self.inner.next_back().map(#to_ref_mut)
+            }
+        }
+
+        impl #ref_impl_generics ::core::iter::ExactSizeIterator for #iter_mut_name #ref_ty_generics
+        #where_clause
+        {
+        }
+
+        impl #impl_generics #vec_name #ty_generics #where_clause {
+            /// Constructs a new, empty vector. It will not allocate until elements are
+            /// pushed onto it.
+            #[inline]
+            #vis fn new() -> Self {
+                Self { inner: #krate::ParallelVec::new() }
+            }
+
+            /// Constructs a new, empty vector with at least the provided capacity.
+            #[inline]
+            #vis fn with_capacity(capacity: usize) -> Self {
+                Self { inner: #krate::ParallelVec::with_capacity(capacity) }
+            }
+
+            /// Returns the number of elements in the vector.
+            #[inline]
+            #vis fn len(&self) -> usize {
+                self.inner.len()
+            }
+
+            /// Returns `true` if the vector contains no elements.
+            #[inline]
+            #vis fn is_empty(&self) -> bool {
+                self.inner.is_empty()
+            }
+
+            /// Returns the number of elements the vector can hold without reallocating.
+            #[inline]
+            #vis fn capacity(&self) -> usize {
+                self.inner.capacity()
+            }
+
+            /// Reserves capacity for at least `additional` more elements.
+            #[inline]
+            #vis fn reserve(&mut self, additional: usize) {
+                self.inner.reserve(additional)
+            }
+
+            /// Clears the vector, removing all values.
+            #[inline]
+            #vis fn clear(&mut self) {
+                self.inner.clear()
+            }
+
+            /// Shortens the vector, keeping the first `len` elements and dropping the rest.
+            #[inline]
+            #vis fn truncate(&mut self, len: usize) {
+                self.inner.truncate(len)
+            }
+
+            /// Appends an element to the back of the vector.
+            #[inline]
+            #vis fn push(&mut self, value: #param_ty) {
+                self.inner.push(value)
+            }
+
+            /// Removes the last element from the vector and returns it, or `None` if it
+            /// is empty.
+            #[inline]
+            #vis fn pop(&mut self) -> ::core::option::Option<#param_ty> {
+                self.inner.pop()
+            }
+
+            /// Inserts an element at position `index`, shifting all elements after it to
+            /// the right.
+            ///
+            /// # Panics
+            /// Panics if `index` is greater than the length of the vector.
+            #[inline]
+            #vis fn insert(&mut self, index: usize, value: #param_ty) {
+                self.inner.insert(index, value)
+            }
+
+            /// Removes and returns the element at position `index`, shifting all elements
+            /// after it to the left. Returns `None` if `index` is out of bounds.
+            #[inline]
+            #vis fn remove(&mut self, index: usize) -> ::core::option::Option<#param_ty> {
+                self.inner.remove(index)
+            }
+
+            /// Removes and returns the element at position `index`, replacing it with the
+            /// last element of the vector.
+            ///
+            /// # Panics
+            /// Panics if `index` is out of bounds.
+            #[inline]
+            #vis fn swap_remove(&mut self, index: usize) -> #param_ty {
+                self.inner.swap_remove(index)
+            }
+
+            /// Returns a reference to the element at `index`, or `None` if it is out of
+            /// bounds.
+            #[inline]
+            #vis fn get(&self, index: usize) -> ::core::option::Option<#ref_name<'_ #(, #ty_args)*>> {
+                self.inner.get(index).map(#to_ref)
+            }
+
+            /// Returns a mutable reference to the element at `index`, or `None` if it is
+            /// out of bounds.
+            #[inline]
+            #vis fn get_mut(&mut self, index: usize) -> ::core::option::Option<#ref_mut_name<'_ #(, #ty_args)*>> {
+                self.inner.get_mut(index).map(#to_ref_mut)
+            }
+
+            /// Returns an iterator over references to the elements of the vector.
+            #[inline]
+            #vis fn iter(&self) -> #iter_name<'_ #(, #ty_args)*> {
+                #iter_name { inner: self.inner.iter() }
+            }
+
+            /// Returns an iterator over mutable references to the elements of the vector.
+            #[inline]
+            #vis fn iter_mut(&mut self) -> #iter_mut_name<'_ #(, #ty_args)*> {
+                #iter_mut_name { inner: self.inner.iter_mut() }
+            }
+
+            #(#accessors)*
+
+            /// Gets the underlying [`ParallelVec`](parallel_vec::ParallelVec).
+            #[inline]
+            #vis fn as_parallel_vec(&self) -> &#vec_ty {
+                &self.inner
+            }
+
+            /// Gets the underlying [`ParallelVec`](parallel_vec::ParallelVec) mutably.
+            #[inline]
+            #vis fn as_parallel_vec_mut(&mut self) -> &mut #vec_ty {
+                &mut self.inner
+            }
+
+            /// Converts the vector into the underlying
+            /// [`ParallelVec`](parallel_vec::ParallelVec).
+            #[inline]
+            #vis fn into_parallel_vec(self) -> #vec_ty {
+                self.inner
+            }
+        }
+
+        impl #impl_generics ::core::default::Default for #vec_name #ty_generics #where_clause {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#vec_ty> for #vec_name #ty_generics #where_clause {
+            #[inline]
+            fn from(inner: #vec_ty) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#vec_name #ty_generics> for #vec_ty #where_clause {
+            #[inline]
+            fn from(vec: #vec_name #ty_generics) -> Self {
+                vec.inner
+            }
+        }
+
+        impl #impl_generics ::core::iter::FromIterator<#param_ty> for #vec_name #ty_generics
+        #where_clause
+        {
+            #[inline]
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #param_ty>>(iter: I) -> Self {
+                Self { inner: ::core::iter::FromIterator::from_iter(iter) }
+            }
+        }
+
+        impl #impl_generics ::core::iter::Extend<#param_ty> for #vec_name #ty_generics #where_clause {
+            #[inline]
+            fn extend<I: ::core::iter::IntoIterator<Item = #param_ty>>(&mut self, iter: I) {
+                self.inner.extend(iter)
+            }
+        }
+
+        impl #impl_generics ::core::iter::IntoIterator for #vec_name #ty_generics #where_clause {
+            type Item = #param_ty;
+            type IntoIter = #krate::iter::IntoIter<#param_ty>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.inner.into_iter()
+            }
+        }
+
+        impl #ref_impl_generics ::core::iter::IntoIterator for &#lifetime #vec_name #ty_generics
+        #where_clause
+        {
+            type Item = #ref_name #ref_ty_generics;
+            type IntoIter = #iter_name #ref_ty_generics;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl #ref_impl_generics ::core::iter::IntoIterator for &#lifetime mut #vec_name #ty_generics
+        #where_clause
+        {
+            type Item = #ref_mut_name #ref_ty_generics;
+            type IntoIter = #iter_mut_name #ref_ty_generics;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+    })
+}
@@ -0,0 +1,55 @@
+use crate::{GrowthPolicy, ParallelParam, ParallelVec, Storage};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a, Param, Growth, A> Arbitrary<'a> for ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + Arbitrary<'a>,
+    Growth: GrowthPolicy + Default,
+    A: Storage + Default,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ParallelVec;
+    use arbitrary::{Arbitrary, Unstructured};
+    use std::vec::Vec;
+
+    #[test]
+    fn test_arbitrary_matches_vec() {
+        let bytes: Vec<u8> = (0..=255).map(|b: u8| b | 1).collect();
+        let vec = Vec::<(u8, u16, bool)>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        let parallel_vec =
+            ParallelVec::<(u8, u16, bool)>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert!(!vec.is_empty());
+        assert!(parallel_vec == vec[..]);
+    }
+
+    #[test]
+    fn test_arbitrary_take_rest() {
+        let bytes: Vec<u8> = (0..=255).rev().collect();
+        let vec = Vec::<(u32, i8)>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+        let parallel_vec =
+            ParallelVec::<(u32, i8)>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+        assert!(!vec.is_empty());
+        assert!(parallel_vec == vec[..]);
+    }
+
+    #[test]
+    fn test_arbitrary_empty() {
+        let vec = ParallelVec::<(u32, i8)>::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert!(vec.is_empty());
+    }
+}
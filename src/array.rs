@@ -0,0 +1,288 @@
+use crate::{
+    assert_in_bounds,
+    iter::{Iter, IterMut},
+    CapacityError, ParallelParam, ParallelSlice, ParallelSliceMut,
+};
+use core::marker::PhantomData;
+
+/// A [`ParallelVec`] with a fixed capacity of `N` elements, stored in a fixed-size
+/// array per field inside of the container.
+///
+/// The container never allocates, which makes it usable where heap allocation is not
+/// available, such as in firmware. Pushing onto a full container fails instead of
+/// growing it.
+///
+/// As the elements move with the container, it does not dereference to a
+/// [`ParallelSliceMut`] like [`ParallelVec`]. Use [`as_parallel_slice`] and
+/// [`as_parallel_slice_mut`] instead.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::{ArrayParallelVec, CapacityError};
+///
+/// let mut readings = ArrayParallelVec::<(u16, i8), 2>::new();
+/// assert_eq!(readings.push((100, -1)), Ok(()));
+/// assert_eq!(readings.push((200, 3)), Ok(()));
+/// assert_eq!(readings.push((300, 2)), Err(CapacityError((300, 2))));
+/// assert!(readings.is_full());
+/// assert_eq!(readings.as_slices().1, &[-1, 3]);
+/// ```
+///
+/// [`ParallelVec`]: crate::ParallelVec
+/// [`as_parallel_slice`]: Self::as_parallel_slice
+/// [`as_parallel_slice_mut`]: Self::as_parallel_slice_mut
+pub struct ArrayParallelVec<Param: ParallelParam, const N: usize> {
+    pub(crate) arrays: Param::Arrays<N>,
+    pub(crate) len: usize,
+}
+
+impl<Param: ParallelParam, const N: usize> ArrayParallelVec<Param, N> {
+    /// Constructs a new, empty `ArrayParallelVec`.
+    pub fn new() -> Self {
+        Self {
+            arrays: Param::uninit_arrays(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the container.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the container contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the container can hold, which is always `N`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the container holds `N` elements.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Appends an element to the back of the container.
+    ///
+    /// Returns the element in a [`CapacityError`] if the container is full.
+    pub fn push(&mut self, value: Param) -> Result<(), CapacityError<Param>> {
+        if self.len == N {
+            return Err(CapacityError(value));
+        }
+        // SAFE: The slot past the last element is within the arrays and uninitialized.
+        unsafe { Param::write(self.ptr_at(self.len), value) };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the last element from the container and returns it, or `None` if it is
+    /// empty.
+    pub fn pop(&mut self) -> Option<Param> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFE: The element is initialized, and is past the new length.
+        Some(unsafe { Param::read(self.ptr_at(self.len)) })
+    }
+
+    /// Removes the element at `index` and returns it, moving the last element into its
+    /// place.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> Param {
+        assert_in_bounds(index, self.len);
+        self.len -= 1;
+        // SAFE: Both elements are initialized and in bounds. The last element is past
+        // the new length once it is moved.
+        unsafe {
+            let storage = Param::arrays_storage_mut(&mut self.arrays);
+            let target = Param::ptr_at(storage, index);
+            let value = Param::read(target);
+            if index != self.len {
+                Param::copy_to_nonoverlapping(Param::ptr_at(storage, self.len), target, 1);
+            }
+            value
+        }
+    }
+
+    /// Shortens the container to `len` elements, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let removed = self.len - len;
+        self.len = len;
+        // SAFE: The elements are initialized, and are past the new length.
+        unsafe { Param::drop_slice(self.ptr_at(len), removed) };
+    }
+
+    /// Removes every element from the container.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Param::Ref<'_>> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFE: The element is in bounds, and borrowed from `self`.
+        Some(unsafe { Param::as_ref(Param::ptr_at(Param::arrays_storage(&self.arrays), index)) })
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of
+    /// bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<Param::RefMut<'_>> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFE: The element is in bounds, and borrowed mutably from `self`.
+        Some(unsafe { Param::as_mut(self.ptr_at(index)) })
+    }
+
+    /// Gets the elements of the container as a [`ParallelSlice`].
+    pub fn as_parallel_slice(&self) -> ParallelSlice<'_, Param> {
+        // SAFE: The elements are initialized, and borrowed from `self`.
+        unsafe { ParallelSlice::from_raw_parts(Param::arrays_storage(&self.arrays), self.len) }
+    }
+
+    /// Gets the elements of the container as a [`ParallelSliceMut`].
+    pub fn as_parallel_slice_mut(&mut self) -> ParallelSliceMut<'_, Param> {
+        let storage = Param::arrays_storage_mut(&mut self.arrays);
+        // SAFE: The elements are initialized, and borrowed mutably from `self`.
+        unsafe { ParallelSliceMut::from_raw_parts(storage, self.len) }
+    }
+
+    /// Gets the fields of every element as slices.
+    pub fn as_slices(&self) -> Param::Slices<'_> {
+        let ptr = Param::as_ptr(Param::arrays_storage(&self.arrays));
+        // SAFE: The elements are initialized, and borrowed from `self`.
+        unsafe { Param::as_slices(ptr, self.len) }
+    }
+
+    /// Gets the fields of every element as mutable slices.
+    pub fn as_slices_mut(&mut self) -> Param::SlicesMut<'_> {
+        let ptr = Param::as_ptr(Param::arrays_storage_mut(&mut self.arrays));
+        // SAFE: The elements are initialized, and borrowed mutably from `self`.
+        unsafe { Param::as_slices_mut(ptr, self.len) }
+    }
+
+    /// Returns an iterator over references to the elements.
+    pub fn iter(&self) -> Iter<'_, Param> {
+        Iter {
+            ptr: Param::as_ptr(Param::arrays_storage(&self.arrays)),
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> IterMut<'_, Param> {
+        IterMut {
+            ptr: Param::as_ptr(Param::arrays_storage_mut(&mut self.arrays)),
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the pointers to the element at `index`, which are valid for writes.
+    ///
+    /// The pointers are invalidated by the next call, as it borrows the arrays again.
+    ///
+    /// # Safety
+    /// `index` must not be greater than `N`.
+    unsafe fn ptr_at(&mut self, index: usize) -> Param::Ptr {
+        Param::ptr_at(Param::arrays_storage_mut(&mut self.arrays), index)
+    }
+}
+
+impl<Param: ParallelParam, const N: usize> Drop for ArrayParallelVec<Param, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<Param: ParallelParam, const N: usize> Default for ArrayParallelVec<Param, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param: ParallelParam, const N: usize> Extend<Param> for ArrayParallelVec<Param, N> {
+    /// Appends every element of `iter`.
+    ///
+    /// # Panics
+    /// Panics if the container becomes full before `iter` is exhausted.
+    fn extend<I: IntoIterator<Item = Param>>(&mut self, iter: I) {
+        for value in iter {
+            if self.push(value).is_err() {
+                panic!("ArrayParallelVec is full");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ArrayParallelVec;
+    use crate::CapacityError;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_push_pop() {
+        let mut vec = ArrayParallelVec::<(u32, f32), 3>::new();
+        assert_eq!(vec.capacity(), 3);
+        for idx in 0..3 {
+            assert_eq!(vec.push((idx, idx as f32)), Ok(()));
+        }
+        assert_eq!(vec.push((3, 3.0)), Err(CapacityError((3, 3.0))));
+        assert!(vec.is_full());
+        assert_eq!(vec.get(1), Some((&1, &1.0)));
+        *vec.get_mut(1).unwrap().1 = 10.0;
+        assert_eq!(vec.swap_remove(0), (0, 0.0));
+        assert_eq!(vec.as_slices(), (&[2, 1][..], &[2.0, 10.0][..]));
+        assert_eq!(vec.pop(), Some((1, 10.0)));
+        assert_eq!(vec.pop(), Some((2, 2.0)));
+        assert_eq!(vec.pop(), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut vec = ArrayParallelVec::<(u8, u16), 4>::default();
+        vec.extend((0..4).map(|idx| (idx, 0)));
+        for (a, b) in vec.iter_mut() {
+            *b = u16::from(*a) * 3;
+        }
+        assert!(vec
+            .iter()
+            .map(|(a, b)| (*a, *b))
+            .eq([(0, 0), (1, 3), (2, 6), (3, 9)]));
+        assert_eq!(vec.as_parallel_slice_mut().len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_extend_overflow() {
+        let mut vec = ArrayParallelVec::<(u8,), 2>::new();
+        vec.extend((0..3).map(|idx| (idx,)));
+    }
+
+    #[test]
+    fn test_drop() {
+        let rc = Rc::new(());
+        let mut vec = ArrayParallelVec::<(Rc<()>, u8), 4>::new();
+        vec.extend((0..4).map(|idx| (rc.clone(), idx)));
+        assert_eq!(Rc::strong_count(&rc), 5);
+        vec.truncate(2);
+        assert_eq!(Rc::strong_count(&rc), 3);
+        core::mem::drop(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{GrowthPolicy, ParallelParam, ParallelVec, Storage};
+use alloc::{format, sync::Arc, vec};
+use arrow_array::{
+    cast::AsArray,
+    types::{
+        Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
+        UInt32Type, UInt64Type, UInt8Type,
+    },
+    Array, ArrayRef, ArrowPrimitiveType, PrimitiveArray, RecordBatch,
+};
+use arrow_buffer::{alloc::Allocation, ArrowNativeType, Buffer, ScalarBuffer};
+use arrow_schema::{ArrowError, SchemaRef};
+use core::{mem::size_of, panic::RefUnwindSafe, ptr::NonNull};
+
+/// A field type that can be stored in an Arrow [`PrimitiveArray`].
+///
+/// This trait is sealed and cannot be implemented outside of
+/// `parallel_vec`.
+pub trait ArrowColumn: ArrowNativeType + private::Sealed {
+    /// The Arrow type of the column.
+    type ArrowType: ArrowPrimitiveType<Native = Self>;
+}
+
+macro_rules! impl_arrow_column {
+    ($($native:ty => $arrow:ty),*) => {
+        $(
+            impl private::Sealed for $native {}
+
+            impl ArrowColumn for $native {
+                type ArrowType = $arrow;
+            }
+        )*
+    };
+}
+
+impl_arrow_column!(
+    i8 => Int8Type,
+    i16 => Int16Type,
+    i32 => Int32Type,
+    i64 => Int64Type,
+    u8 => UInt8Type,
+    u16 => UInt16Type,
+    u32 => UInt32Type,
+    u64 => UInt64Type,
+    f32 => Float32Type,
+    f64 => Float64Type
+);
+
+/// Keeps the buffer of a [`ParallelVec`] alive while it is shared with Arrow arrays.
+struct ArrowOwner<Param: ParallelParam>(ParallelVec<Param>);
+
+/// Gets the values of the column at `idx` of `batch`, if it has the type of `T`
+/// and does not contain any nulls.
+fn column<T: ArrowColumn>(batch: &RecordBatch, idx: usize) -> Result<&[T], ArrowError> {
+    let column = batch.column(idx);
+    let array = column.as_primitive_opt::<T::ArrowType>().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "column {} has type {}, expected {}",
+            idx,
+            column.data_type(),
+            T::ArrowType::DATA_TYPE
+        ))
+    })?;
+    if array.null_count() > 0 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "column {} contains nulls",
+            idx
+        )));
+    }
+    Ok(array.values())
+}
+
+macro_rules! impl_arrow {
+    ($($ts:ident $vs:ident $idx:tt),*) => {
+        // SAFE: The owner is never accessed again, it only frees the buffer when dropped,
+        // and every column is plain data.
+        unsafe impl<$($ts: ArrowColumn),*> Send for ArrowOwner<($($ts,)*)> {}
+        unsafe impl<$($ts: ArrowColumn),*> Sync for ArrowOwner<($($ts,)*)> {}
+        impl<$($ts: ArrowColumn),*> RefUnwindSafe for ArrowOwner<($($ts,)*)> {}
+
+        impl<$($ts: ArrowColumn,)* Growth: GrowthPolicy, A: Storage>
+            ParallelVec<($($ts,)*), Growth, A>
+        {
+            /// Copies the vector into an Arrow [`RecordBatch`] with the given `schema`,
+            /// with one [`PrimitiveArray`] per field.
+            ///
+            /// Returns an error if the schema does not have a non-nullable or nullable
+            /// field of the matching type for each field.
+            pub fn to_record_batch(&self, schema: SchemaRef) -> Result<RecordBatch, ArrowError> {
+                let ($($vs,)*) = self.as_slices();
+                RecordBatch::try_new(
+                    schema,
+                    vec![$(
+                        Arc::new(PrimitiveArray::<$ts::ArrowType>::from_iter_values(
+                            $vs.iter().copied(),
+                        )) as ArrayRef
+                    ),*],
+                )
+            }
+        }
+
+        impl<$($ts: ArrowColumn),*> ParallelVec<($($ts,)*)> {
+            /// Converts the vector into an Arrow [`RecordBatch`] with the given `schema`,
+            /// with one [`PrimitiveArray`] per field.
+            ///
+            /// This does not copy any of the values: every array shares the buffer of the
+            /// vector, which is freed once all of the arrays have been dropped.
+            ///
+            /// Returns an error if the schema does not have a non-nullable or nullable
+            /// field of the matching type for each field.
+            pub fn into_record_batch(self, schema: SchemaRef) -> Result<RecordBatch, ArrowError> {
+                let len = self.len();
+                let ($($vs,)*) = self.storage;
+                let owner: Arc<dyn Allocation> = Arc::new(ArrowOwner(self));
+                RecordBatch::try_new(
+                    schema,
+                    vec![$({
+                        // SAFE: Every column is valid for `len` elements for as long as
+                        // the vector is alive, which the owner guarantees.
+                        let buffer = unsafe {
+                            Buffer::from_custom_allocation(
+                                $vs.cast::<u8>(),
+                                len * size_of::<$ts>(),
+                                owner.clone(),
+                            )
+                        };
+                        Arc::new(PrimitiveArray::<$ts::ArrowType>::new(
+                            ScalarBuffer::from(buffer),
+                            None,
+                        )) as ArrayRef
+                    }),*],
+                )
+            }
+        }
+
+        impl<$($ts: ArrowColumn),*> TryFrom<&RecordBatch> for ParallelVec<($($ts,)*)> {
+            type Error = ArrowError;
+
+            /// Copies the columns of an Arrow [`RecordBatch`] into a new vector.
+            ///
+            /// Returns an error if the batch does not have exactly one column per field,
+            /// if the type of a column does not match its field, or if a column contains
+            /// nulls.
+            fn try_from(batch: &RecordBatch) -> Result<Self, ArrowError> {
+                let columns = [$($idx),*].len();
+                if batch.num_columns() != columns {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "expected {} columns, found {}",
+                        columns,
+                        batch.num_columns()
+                    )));
+                }
+                $(let $vs = column::<$ts>(batch, $idx)?;)*
+                let len = batch.num_rows();
+                let mut vec = Self::with_capacity(len);
+                // SAFE: Every column of the batch has `len` values, and the vector has
+                // the capacity for `len` values.
+                unsafe {
+                    let src = ($(NonNull::from($vs).cast::<$ts>(),)*);
+                    let dst = <($($ts,)*) as ParallelParam>::as_ptr(vec.storage);
+                    <($($ts,)*) as ParallelParam>::copy_to_nonoverlapping(src, dst, len);
+                    vec.len = len;
+                }
+                Ok(vec)
+            }
+        }
+    };
+}
+
+impl_arrow!(T1 V1 0);
+impl_arrow!(T1 V1 0, T2 V2 1);
+impl_arrow!(T1 V1 0, T2 V2 1, T3 V3 2);
+impl_arrow!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3);
+impl_arrow!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4);
+impl_arrow!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5);
+impl_arrow!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6);
+impl_arrow!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7);
+impl_arrow!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8);
+impl_arrow!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9
+);
+impl_arrow!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10
+);
+impl_arrow!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11
+);
+impl_arrow!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12
+);
+impl_arrow!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13
+);
+impl_arrow!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13, T15 V15 14
+);
+impl_arrow!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13, T15 V15 14, T16 V16 15
+);
+
+mod private {
+    pub trait Sealed {}
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ParallelVec;
+    use arrow_array::{
+        cast::AsArray,
+        types::{Float64Type, Int32Type},
+        Array, ArrayRef, Int32Array, RecordBatch,
+    };
+    use arrow_schema::{DataType, Field, Schema, SchemaRef};
+    use std::sync::Arc;
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("weight", DataType::Float64, false),
+        ]))
+    }
+
+    #[test]
+    fn test_to_record_batch() {
+        let vec = ParallelVec::from(vec![(1i32, 0.5f64), (2, 1.5), (3, 2.5)]);
+        let batch = vec.to_record_batch(schema()).unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(
+            batch.column(0).as_primitive::<Int32Type>().values(),
+            &[1, 2, 3]
+        );
+        assert_eq!(
+            batch.column(1).as_primitive::<Float64Type>().values(),
+            &[0.5, 1.5, 2.5]
+        );
+    }
+
+    #[test]
+    fn test_into_record_batch_is_zero_copy() {
+        let mut vec = ParallelVec::from(vec![(1i32, 0.5f64), (2, 1.5), (3, 2.5)]);
+        let (ids, weights) = vec.as_parallel_slice_mut().as_mut_ptrs();
+        let batch = vec.into_record_batch(schema()).unwrap();
+        let id_column = batch.column(0).as_primitive::<Int32Type>();
+        let weight_column = batch.column(1).as_primitive::<Float64Type>();
+        assert_eq!(id_column.values().as_ptr(), ids.as_ptr().cast_const());
+        assert_eq!(
+            weight_column.values().as_ptr(),
+            weights.as_ptr().cast_const()
+        );
+        assert_eq!(id_column.values(), &[1, 2, 3]);
+        assert_eq!(weight_column.values(), &[0.5, 1.5, 2.5]);
+
+        // The buffer outlives the batch as long as one of the columns is alive.
+        let column: ArrayRef = batch.column(1).clone();
+        drop(batch);
+        assert_eq!(
+            column.as_primitive::<Float64Type>().values(),
+            &[0.5, 1.5, 2.5]
+        );
+    }
+
+    #[test]
+    fn test_into_record_batch_empty() {
+        let vec: ParallelVec<(i32, f64)> = ParallelVec::new();
+        let batch = vec.into_record_batch(schema()).unwrap();
+        assert_eq!(batch.num_rows(), 0);
+    }
+
+    #[test]
+    fn test_record_batch_schema_mismatch() {
+        let vec = ParallelVec::from(vec![(1i64, 0.5f64)]);
+        assert!(vec.to_record_batch(schema()).is_err());
+        assert!(vec.into_record_batch(schema()).is_err());
+    }
+
+    #[test]
+    fn test_try_from_record_batch() {
+        let vec = ParallelVec::from(vec![(1i32, 0.5f64), (2, 1.5), (3, 2.5)]);
+        let batch = vec.to_record_batch(schema()).unwrap();
+        let round_trip = ParallelVec::<(i32, f64)>::try_from(&batch).unwrap();
+        assert!(round_trip == vec);
+
+        assert!(ParallelVec::<(i32, f32)>::try_from(&batch).is_err());
+        assert!(ParallelVec::<(i32,)>::try_from(&batch).is_err());
+    }
+
+    #[test]
+    fn test_try_from_record_batch_with_nulls() {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, true)]));
+        let column = Int32Array::from(vec![Some(1), None]);
+        assert_eq!(column.null_count(), 1);
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(column)]).unwrap();
+        assert!(ParallelVec::<(i32,)>::try_from(&batch).is_err());
+    }
+}
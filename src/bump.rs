@@ -0,0 +1,60 @@
+use crate::{growth::Doubling, ParallelParam, ParallelVec};
+use bumpalo::Bump;
+
+/// A [`ParallelVec`] that allocates its buffer from a [`Bump`] arena.
+///
+/// This is useful for short lived scratch data, like per-frame buffers, where the
+/// arena is reset wholesale instead of freeing each individual allocation.
+pub type BumpParallelVec<'bump, Param> = ParallelVec<Param, Doubling, &'bump Bump>;
+
+impl<'bump, Param: ParallelParam> ParallelVec<Param, Doubling, &'bump Bump> {
+    /// Constructs a new, empty `ParallelVec` which allocates from the provided
+    /// [`Bump`] arena.
+    ///
+    /// The vector will not allocate until elements are pushed onto it.
+    pub fn new_in_bump(bump: &'bump Bump) -> Self {
+        Self::new_in(bump)
+    }
+
+    /// Constructs a new, empty `ParallelVec` with the specified capacity, which
+    /// allocates from the provided [`Bump`] arena.
+    ///
+    /// The vector will be able to hold exactly capacity elements without reallocating.
+    /// If capacity is 0, the vector will not allocate.
+    pub fn with_capacity_in_bump(capacity: usize, bump: &'bump Bump) -> Self {
+        Self::with_capacity_in(capacity, bump)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BumpParallelVec;
+    use bumpalo::Bump;
+
+    #[test]
+    fn test_bump_push() {
+        let mut bump = Bump::new();
+        {
+            let mut vec: BumpParallelVec<(u32, f64)> = BumpParallelVec::new_in_bump(&bump);
+            vec.extend((0..100).map(|i| (i, i as f64)));
+            assert_eq!(vec.len(), 100);
+            assert_eq!(vec.index(50), (&50, &50.0));
+            assert!(bump.allocated_bytes() > 0);
+        }
+        bump.reset();
+    }
+
+    #[test]
+    fn test_bump_with_capacity() {
+        let bump = Bump::new();
+        let before = bump.allocated_bytes();
+        let mut vec = BumpParallelVec::with_capacity_in_bump(16, &bump);
+        vec.push((1u8, 2u64));
+        vec.push((3u8, 4u64));
+        assert_eq!(vec.capacity(), 16);
+        assert!(bump.allocated_bytes() >= before);
+        let (a, b) = vec.as_slices();
+        assert_eq!(a, &[1, 3]);
+        assert_eq!(b, &[2, 4]);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{GrowthPolicy, ParallelParam, ParallelSlice, ParallelSliceMut, ParallelVec, Storage};
+use bytemuck::Pod;
+use core::mem::size_of;
+
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> ParallelVec<Param, Growth, A> {
+    /// Zeroes the padding between the fields and the spare capacity of each field, then
+    /// returns the base pointer and the size of the buffer.
+    fn zero_uninit(&mut self) -> (*mut u8, usize) {
+        if self.capacity == 0 || size_of::<Param>() == 0 {
+            return (Param::base(self.storage).as_ptr(), 0);
+        }
+        let layout = self.memory_layout();
+        let offsets = layout.offsets();
+        let sizes = Param::field_sizes(self.len);
+        let base = Param::base(self.storage).as_ptr();
+        for (idx, (offset, size)) in offsets.iter().zip(sizes.as_ref()).enumerate() {
+            let start = offset + size;
+            let end = offsets
+                .get(idx + 1)
+                .copied()
+                .unwrap_or(layout.layout().size());
+            // SAFE: Both the padding and the spare capacity are within the allocation
+            // and do not overlap any of the initialized elements.
+            unsafe { base.add(start).write_bytes(0, end - start) };
+        }
+        (base, layout.layout().size())
+    }
+}
+
+macro_rules! bytes {
+    ($t:ident) => {
+        &[u8]
+    };
+}
+
+macro_rules! bytes_mut {
+    ($t:ident) => {
+        &mut [u8]
+    };
+}
+
+macro_rules! impl_bytemuck {
+    ($($ts:ident $vs:ident),*) => {
+        impl<'a, $($ts: Pod),*> ParallelSlice<'a, ($($ts,)*)> {
+            /// Gets the bytes of every field.
+            pub fn as_bytes(&self) -> ($(bytes!($ts),)*) {
+                let ($($vs,)*) = self.as_slices();
+                ($(bytemuck::cast_slice::<$ts, u8>($vs),)*)
+            }
+        }
+
+        impl<'a, $($ts: Pod),*> ParallelSliceMut<'a, ($($ts,)*)> {
+            /// Gets the bytes of every field.
+            pub fn as_bytes(&self) -> ($(bytes!($ts),)*) {
+                let ($($vs,)*) = self.as_slices();
+                ($(bytemuck::cast_slice::<$ts, u8>($vs),)*)
+            }
+
+            /// Gets the mutable bytes of every field.
+            pub fn as_bytes_mut(&mut self) -> ($(bytes_mut!($ts),)*) {
+                let ($($vs,)*) = self.as_slices_mut();
+                ($(bytemuck::cast_slice_mut::<$ts, u8>($vs),)*)
+            }
+        }
+
+        impl<$($ts: Pod,)* Growth: GrowthPolicy, A: Storage> ParallelVec<($($ts,)*), Growth, A> {
+            /// Gets the bytes of the entire buffer of the vector, including the spare
+            /// capacity of each field.
+            ///
+            /// The buffer can be viewed again with [`ParallelSlice::from_bytes`], given the
+            /// capacity and length of the vector. The padding between the fields and the
+            /// spare capacity of each field are zeroed first, which is why this requires
+            /// a mutable reference.
+            pub fn allocation_bytes(&mut self) -> &[u8] {
+                let (base, size) = self.zero_uninit();
+                // SAFE: Every byte of the buffer has been initialized, and all fields are `Pod`.
+                unsafe { core::slice::from_raw_parts(base, size) }
+            }
+
+            /// Gets the mutable bytes of the entire buffer of the vector, including the
+            /// spare capacity of each field.
+            ///
+            /// The padding between the fields and the spare capacity of each field are
+            /// zeroed first.
+            pub fn allocation_bytes_mut(&mut self) -> &mut [u8] {
+                let (base, size) = self.zero_uninit();
+                // SAFE: Every byte of the buffer has been initialized, all fields are `Pod`,
+                // and so any bytes written are valid values.
+                unsafe { core::slice::from_raw_parts_mut(base, size) }
+            }
+        }
+    };
+}
+
+impl_bytemuck!(T1 V1);
+impl_bytemuck!(T1 V1, T2 V2);
+impl_bytemuck!(T1 V1, T2 V2, T3 V3);
+impl_bytemuck!(T1 V1, T2 V2, T3 V3, T4 V4);
+impl_bytemuck!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5);
+impl_bytemuck!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6);
+impl_bytemuck!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7);
+impl_bytemuck!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8);
+impl_bytemuck!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9);
+impl_bytemuck!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10);
+impl_bytemuck!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11);
+impl_bytemuck!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12
+);
+impl_bytemuck!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13
+);
+impl_bytemuck!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14
+);
+impl_bytemuck!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14, T15 V15
+);
+impl_bytemuck!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14, T15 V15, T16 V16
+);
+
+#[cfg(test)]
+mod test {
+    use crate::{ParallelSlice, ParallelVec};
+
+    #[test]
+    fn test_as_bytes() {
+        let mut vec = ParallelVec::from(vec![(1u8, 2u32), (3, 4)]);
+        let (a, b) = vec.as_bytes();
+        assert_eq!(a, &[1, 3]);
+        assert_eq!(b.len(), 8);
+        assert_eq!(&b[..4], &2u32.to_ne_bytes());
+        assert_eq!(&b[4..], &4u32.to_ne_bytes());
+
+        let mut slice = vec.as_parallel_slice_mut();
+        let (a, b) = slice.as_bytes_mut();
+        a[1] = 5;
+        b[4..].copy_from_slice(&6u32.to_ne_bytes());
+        assert_eq!(vec.as_slices(), (&[1, 5][..], &[2, 6][..]));
+        assert_eq!(vec.get(..).unwrap().as_bytes().0, &[1, 5]);
+    }
+
+    #[test]
+    fn test_allocation_bytes() {
+        let mut vec: ParallelVec<(u8, u64, u16)> = ParallelVec::with_capacity(4);
+        vec.push((1, 2, 3));
+        vec.push((4, 5, 6));
+        let (capacity, len) = (vec.capacity(), vec.len());
+        let bytes = vec.allocation_bytes();
+        assert_eq!(&bytes[2..8], &[0; 6]);
+        let slice =
+            unsafe { ParallelSlice::<(u8, u64, u16)>::from_bytes(bytes, capacity, len) }.unwrap();
+        assert_eq!(slice.as_slices(), (&[1, 4][..], &[2, 5][..], &[3, 6][..]));
+    }
+
+    #[test]
+    fn test_allocation_bytes_mut() {
+        let mut vec = ParallelVec::from(vec![(1u16, 2u16)]);
+        let bytes = vec.allocation_bytes_mut();
+        bytes.fill(0);
+        assert_eq!(vec.as_slices(), (&[0][..], &[0][..]));
+    }
+
+    #[test]
+    fn test_allocation_bytes_empty() {
+        let mut vec: ParallelVec<(u8, u32)> = ParallelVec::new();
+        assert!(vec.allocation_bytes().is_empty());
+        let mut vec: ParallelVec<((), ())> = ParallelVec::new();
+        vec.push(((), ()));
+        assert!(vec.allocation_bytes().is_empty());
+    }
+}
@@ -0,0 +1,475 @@
+use crate::{
+    iter::{Iter, IterMut},
+    ParallelParam, ParallelSlice, ParallelSliceMut, TryReserveError,
+};
+use alloc::vec::Vec;
+use allocator_api2::alloc::Global;
+use core::marker::PhantomData;
+
+/// A [`ParallelVec`] that stores its elements in separately allocated chunks of `CHUNK`
+/// elements, with a separate column per field within each chunk.
+///
+/// Growing the container only allocates new chunks, so existing elements are never
+/// moved or copied. References and pointers to the fields of an element remain valid
+/// until the element itself is removed, which makes it possible for other structures to
+/// hold raw pointers into the columns.
+///
+/// The fields are only contiguous within a chunk. Use [`chunk`] and [`chunk_mut`] to
+/// access them as slices.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::ChunkedParallelVec;
+///
+/// let mut nodes = ChunkedParallelVec::<(u32, f32), 4>::new();
+/// nodes.push((0, 0.5));
+/// let first: *const u32 = nodes.get(0).unwrap().0;
+/// nodes.extend((1..10).map(|idx| (idx, 0.0)));
+/// assert_eq!(nodes.num_chunks(), 3);
+/// assert_eq!(first, nodes.get(0).unwrap().0 as *const u32);
+/// assert_eq!(nodes.chunk(2).unwrap().as_slices().0, &[8, 9]);
+/// ```
+///
+/// [`ParallelVec`]: crate::ParallelVec
+/// [`chunk`]: Self::chunk
+/// [`chunk_mut`]: Self::chunk_mut
+pub struct ChunkedParallelVec<Param: ParallelParam, const CHUNK: usize> {
+    /// Every chunk is allocated with the capacity for `CHUNK` elements.
+    chunks: Vec<Param::Storage>,
+    len: usize,
+}
+
+// SAFE: The container owns its elements, like a `Vec`.
+unsafe impl<Param: ParallelParam + Send, const CHUNK: usize> Send
+    for ChunkedParallelVec<Param, CHUNK>
+{
+}
+
+// SAFE: Shared references to the container only give out shared references to its
+// elements.
+unsafe impl<Param: ParallelParam + Sync, const CHUNK: usize> Sync
+    for ChunkedParallelVec<Param, CHUNK>
+{
+}
+
+impl<Param: ParallelParam, const CHUNK: usize> ChunkedParallelVec<Param, CHUNK> {
+    /// Constructs a new, empty `ChunkedParallelVec`.
+    ///
+    /// The container will not allocate until elements are pushed onto it.
+    ///
+    /// # Panics
+    /// Panics if `CHUNK` is zero.
+    pub fn new() -> Self {
+        assert!(CHUNK > 0, "ChunkedParallelVec chunks must not be empty");
+        Self {
+            chunks: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Constructs a new, empty `ChunkedParallelVec` with enough chunks for at least
+    /// `capacity` elements.
+    ///
+    /// # Panics
+    /// Panics if `CHUNK` is zero, or if the new capacity overflows.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = Self::new();
+        vec.reserve(capacity);
+        vec
+    }
+
+    /// Returns the number of elements in the container.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the container contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the container can hold without allocating
+    /// another chunk.
+    pub fn capacity(&self) -> usize {
+        self.chunks.len() * CHUNK
+    }
+
+    /// Returns the number of chunks that hold at least one element.
+    pub fn num_chunks(&self) -> usize {
+        match self.len {
+            0 => 0,
+            len => (len - 1) / CHUNK + 1,
+        }
+    }
+
+    /// Allocates enough chunks for at least `additional` more elements.
+    ///
+    /// Existing elements are not moved.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(err) = self.try_reserve(additional) {
+            crate::handle_reserve_error(err);
+        }
+    }
+
+    /// Tries to allocate enough chunks for at least `additional` more elements.
+    ///
+    /// Existing elements are not moved.
+    ///
+    /// # Errors
+    /// Returns an error if the capacity overflows, or if the allocator reports a
+    /// failure.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        while self.capacity() < required {
+            self.chunks
+                .try_reserve(1)
+                .map_err(|_| TryReserveError::CapacityOverflow)?;
+            // SAFE: `CHUNK` is non-zero, and one is a power of two.
+            let storage = unsafe { Param::try_alloc(CHUNK, 1, &Global)? };
+            self.chunks.push(storage);
+        }
+        Ok(())
+    }
+
+    /// Appends an element to the back of the container, allocating a new chunk if the
+    /// last one is full.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn push(&mut self, value: Param) {
+        self.reserve(1);
+        // SAFE: The slot past the last element is allocated and uninitialized.
+        unsafe { Param::write(self.ptr_at(self.len), value) };
+        self.len += 1;
+    }
+
+    /// Removes the last element from the container and returns it, or `None` if it is
+    /// empty.
+    ///
+    /// The chunk of the element is kept allocated.
+    pub fn pop(&mut self) -> Option<Param> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFE: The element is initialized, and is past the new length.
+        Some(unsafe { Param::read(self.ptr_at(self.len)) })
+    }
+
+    /// Removes the element at `index` and returns it, moving the last element into its
+    /// place.
+    ///
+    /// This changes the address of the last element.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> Param {
+        crate::assert_in_bounds(index, self.len);
+        self.len -= 1;
+        // SAFE: Both elements are initialized and in bounds. The last element is past
+        // the new length once it is moved.
+        unsafe {
+            let target = self.ptr_at(index);
+            let value = Param::read(target);
+            if index != self.len {
+                Param::copy_to_nonoverlapping(self.ptr_at(self.len), target, 1);
+            }
+            value
+        }
+    }
+
+    /// Shortens the container to `len` elements, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than the current length. The chunks are kept
+    /// allocated.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            // Dropped one chunk at a time, from the back, as the chunks are not
+            // contiguous.
+            let start = len.max((self.len - 1) / CHUNK * CHUNK);
+            let removed = self.len - start;
+            self.len = start;
+            // SAFE: The elements are initialized, within a single chunk, and are past
+            // the new length.
+            unsafe { Param::drop_slice(self.ptr_at(start), removed) };
+        }
+    }
+
+    /// Removes every element from the container. The chunks are kept allocated.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Deallocates the chunks that do not hold any elements.
+    pub fn shrink_to_fit(&mut self) {
+        let used = self.num_chunks();
+        for mut storage in self.chunks.drain(used..) {
+            // SAFE: The chunk was allocated with the capacity for `CHUNK` elements, and
+            // holds no elements.
+            unsafe { Param::dealloc(&mut storage, CHUNK, 1, &Global) };
+        }
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Param::Ref<'_>> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFE: The element is in bounds, and borrowed from `self`.
+        Some(unsafe { Param::as_ref(self.ptr_at(index)) })
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of
+    /// bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<Param::RefMut<'_>> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFE: The element is in bounds, and borrowed mutably from `self`.
+        Some(unsafe { Param::as_mut(self.ptr_at(index)) })
+    }
+
+    /// Gets the elements of the chunk at `index` as a [`ParallelSlice`], or `None` if
+    /// the chunk holds no elements.
+    pub fn chunk(&self, index: usize) -> Option<ParallelSlice<'_, Param>> {
+        let (storage, len) = self.chunk_parts(index)?;
+        // SAFE: The elements of the chunk are initialized, and borrowed from `self`.
+        Some(unsafe { ParallelSlice::from_raw_parts(storage, len) })
+    }
+
+    /// Gets the elements of the chunk at `index` as a [`ParallelSliceMut`], or `None`
+    /// if the chunk holds no elements.
+    pub fn chunk_mut(&mut self, index: usize) -> Option<ParallelSliceMut<'_, Param>> {
+        let (storage, len) = self.chunk_parts(index)?;
+        // SAFE: The elements of the chunk are initialized, and borrowed mutably from
+        // `self`.
+        Some(unsafe { ParallelSliceMut::from_raw_parts(storage, len) })
+    }
+
+    /// Returns an iterator over references to the elements.
+    pub fn iter(&self) -> ChunkedIter<'_, Param> {
+        ChunkedIter {
+            chunks: self.chunks.iter(),
+            current: Iter {
+                ptr: Param::as_ptr(Param::dangling()),
+                remaining: 0,
+                _marker: PhantomData,
+            },
+            chunk_len: CHUNK,
+            remaining: self.len,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> ChunkedIterMut<'_, Param> {
+        ChunkedIterMut {
+            chunks: self.chunks.iter(),
+            current: IterMut {
+                ptr: Param::as_ptr(Param::dangling()),
+                remaining: 0,
+                _marker: PhantomData,
+            },
+            chunk_len: CHUNK,
+            remaining: self.len,
+        }
+    }
+
+    /// Gets the pointers to the element at `index`.
+    ///
+    /// # Safety
+    /// `index` must be less than the capacity of the container.
+    unsafe fn ptr_at(&self, index: usize) -> Param::Ptr {
+        Param::ptr_at(*self.chunks.get_unchecked(index / CHUNK), index % CHUNK)
+    }
+
+    fn chunk_parts(&self, index: usize) -> Option<(Param::Storage, usize)> {
+        let start = index.checked_mul(CHUNK).filter(|start| *start < self.len)?;
+        Some((self.chunks[index], (self.len - start).min(CHUNK)))
+    }
+}
+
+impl<Param: ParallelParam, const CHUNK: usize> Drop for ChunkedParallelVec<Param, CHUNK> {
+    fn drop(&mut self) {
+        self.clear();
+        self.shrink_to_fit();
+    }
+}
+
+impl<Param: ParallelParam, const CHUNK: usize> Default for ChunkedParallelVec<Param, CHUNK> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param: ParallelParam, const CHUNK: usize> Extend<Param> for ChunkedParallelVec<Param, CHUNK> {
+    fn extend<I: IntoIterator<Item = Param>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<Param: ParallelParam, const CHUNK: usize> FromIterator<Param>
+    for ChunkedParallelVec<Param, CHUNK>
+{
+    fn from_iter<I: IntoIterator<Item = Param>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+/// An iterator over references to the elements of a [`ChunkedParallelVec`].
+///
+/// Created by [`ChunkedParallelVec::iter`].
+pub struct ChunkedIter<'a, Param: ParallelParam> {
+    chunks: core::slice::Iter<'a, Param::Storage>,
+    current: Iter<'a, Param>,
+    chunk_len: usize,
+    /// The number of elements after the current chunk.
+    remaining: usize,
+}
+
+impl<'a, Param: ParallelParam> Iterator for ChunkedIter<'a, Param> {
+    type Item = Param::Ref<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.remaining == 0 {
+            let storage = *self.chunks.next().filter(|_| self.remaining > 0)?;
+            let len = self.remaining.min(self.chunk_len);
+            self.remaining -= len;
+            self.current = Iter {
+                ptr: Param::as_ptr(storage),
+                remaining: len,
+                _marker: PhantomData,
+            };
+        }
+        self.current.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.current.remaining + self.remaining;
+        (len, Some(len))
+    }
+}
+
+impl<'a, Param: ParallelParam> ExactSizeIterator for ChunkedIter<'a, Param> {}
+
+/// An iterator over mutable references to the elements of a [`ChunkedParallelVec`].
+///
+/// Created by [`ChunkedParallelVec::iter_mut`].
+pub struct ChunkedIterMut<'a, Param: ParallelParam> {
+    chunks: core::slice::Iter<'a, Param::Storage>,
+    current: IterMut<'a, Param>,
+    chunk_len: usize,
+    /// The number of elements after the current chunk.
+    remaining: usize,
+}
+
+impl<'a, Param: ParallelParam> Iterator for ChunkedIterMut<'a, Param> {
+    type Item = Param::RefMut<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.remaining == 0 {
+            let storage = *self.chunks.next().filter(|_| self.remaining > 0)?;
+            let len = self.remaining.min(self.chunk_len);
+            self.remaining -= len;
+            // The chunks are disjoint, and the container is borrowed mutably for `'a`.
+            self.current = IterMut {
+                ptr: Param::as_ptr(storage),
+                remaining: len,
+                _marker: PhantomData,
+            };
+        }
+        self.current.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.current.remaining + self.remaining;
+        (len, Some(len))
+    }
+}
+
+impl<'a, Param: ParallelParam> ExactSizeIterator for ChunkedIterMut<'a, Param> {}
+
+#[cfg(test)]
+mod test {
+    use super::ChunkedParallelVec;
+    use alloc::{string::String, vec::Vec};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_stable_addresses() {
+        let mut vec = ChunkedParallelVec::<(u64, String), 2>::new();
+        vec.push((0, String::from("0")));
+        let ptr: *const u64 = vec.get(0).unwrap().0;
+        for idx in 1..9 {
+            vec.push((idx, String::new()));
+        }
+        assert_eq!(vec.capacity(), 10);
+        assert_eq!(vec.num_chunks(), 5);
+        assert_eq!(ptr, vec.get(0).unwrap().0 as *const u64);
+        // SAFE: The element has not been removed.
+        assert_eq!(unsafe { *ptr }, 0);
+        assert_eq!(vec.chunk(4).unwrap().len(), 1);
+        assert!(vec.chunk(5).is_none());
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut vec = ChunkedParallelVec::<(u32, f32), 3>::with_capacity(4);
+        assert_eq!(vec.capacity(), 6);
+        vec.extend((0..7).map(|idx| (idx, idx as f32)));
+        assert_eq!(vec.swap_remove(1), (1, 1.0));
+        assert_eq!(vec.get(1), Some((&6, &6.0)));
+        *vec.get_mut(5).unwrap().0 = 50;
+        assert_eq!(vec.pop(), Some((50, 5.0)));
+        assert_eq!(vec.len(), 5);
+        assert_eq!(vec.chunk_mut(1).unwrap().as_slices_mut().0, &mut [3, 4]);
+        vec.shrink_to_fit();
+        assert_eq!(vec.capacity(), 6);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut vec: ChunkedParallelVec<(u32, u32), 4> = (0..10).map(|idx| (idx, 0)).collect();
+        assert_eq!(vec.iter_mut().len(), 10);
+        for (a, b) in vec.iter_mut() {
+            *b = *a + 1;
+        }
+        let values: Vec<u32> = vec.iter().map(|(_, b)| *b).collect();
+        assert_eq!(values, (1..11).collect::<Vec<_>>());
+        vec.truncate(4);
+        assert_eq!(vec.iter().count(), 4);
+        assert_eq!(ChunkedParallelVec::<(u8,), 1>::new().iter().next(), None);
+    }
+
+    #[test]
+    fn test_drop() {
+        let rc = Rc::new(());
+        let mut vec = ChunkedParallelVec::<(u8, Rc<()>), 3>::new();
+        vec.extend((0..8).map(|idx| (idx, rc.clone())));
+        assert_eq!(Rc::strong_count(&rc), 9);
+        vec.truncate(2);
+        assert_eq!(Rc::strong_count(&rc), 3);
+        vec.shrink_to_fit();
+        assert_eq!(vec.capacity(), 3);
+        core::mem::drop(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_zero_sized() {
+        let mut vec = ChunkedParallelVec::<((), ()), 2>::new();
+        vec.extend((0..5).map(|_| ((), ())));
+        assert_eq!(vec.iter().len(), 5);
+        vec.clear();
+        assert!(vec.is_empty());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{
+    iter::Iter, CapacityError, ParallelParam, ParallelSlice, ParallelSliceMut, ParallelVec,
+};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// An append-only [`ParallelVec`] with a fixed capacity, which many threads can
+/// [`push`] onto at the same time through a shared reference.
+///
+/// Every push claims the next free slot, writes the element into it, and then
+/// publishes it. Elements are published in the order their slots were claimed, so
+/// readers always see a consistent prefix of the elements, and never one that is only
+/// partially written. A push only waits for pushes that claimed an earlier slot to
+/// publish their elements, which never run any user code.
+///
+/// The capacity can only grow with exclusive access, using [`reserve`].
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::ConcurrentParallelVec;
+///
+/// let hits = ConcurrentParallelVec::with_capacity(64);
+/// std::thread::scope(|scope| {
+///     for thread in 0..4u32 {
+///         let hits = &hits;
+///         scope.spawn(move || {
+///             for idx in 0..16u32 {
+///                 hits.push((thread, idx as f32)).unwrap();
+///             }
+///         });
+///     }
+/// });
+/// assert_eq!(hits.len(), 64);
+/// let hits = hits.into_parallel_vec();
+/// assert_eq!(hits.as_slices().0.iter().filter(|thread| **thread == 2).count(), 16);
+/// ```
+///
+/// [`push`]: Self::push
+/// [`reserve`]: Self::reserve
+pub struct ConcurrentParallelVec<Param: ParallelParam> {
+    /// The allocation of the elements. Its length is kept at zero, and only set while
+    /// it is used with exclusive access.
+    vec: ParallelVec<Param>,
+    /// The number of slots that have been claimed by a push.
+    claimed: AtomicUsize,
+    /// The number of elements that have been written and can be read.
+    len: AtomicUsize,
+}
+
+// SAFE: The container owns its elements, like a `Vec`.
+unsafe impl<Param: ParallelParam + Send> Send for ConcurrentParallelVec<Param> {}
+
+// SAFE: Shared references move elements in from other threads, which requires `Send`,
+// and only give out shared references to the published elements, which requires `Sync`.
+unsafe impl<Param: ParallelParam + Send + Sync> Sync for ConcurrentParallelVec<Param> {}
+
+impl<Param: ParallelParam> ConcurrentParallelVec<Param> {
+    /// Constructs a new, empty `ConcurrentParallelVec` with the capacity for exactly
+    /// `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from(ParallelVec::with_capacity(capacity))
+    }
+
+    /// Returns the number of published elements.
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if no elements have been published.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements the container can hold.
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Appends an element, returning its index.
+    ///
+    /// The element is visible to every reader once this returns.
+    ///
+    /// # Errors
+    /// Returns the element in a [`CapacityError`] if the container is full.
+    pub fn push(&self, value: Param) -> Result<usize, CapacityError<Param>> {
+        let capacity = self.vec.capacity();
+        let claim = self
+            .claimed
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |claimed| {
+                (claimed < capacity).then(|| claimed + 1)
+            });
+        let Ok(index) = claim else {
+            return Err(CapacityError(value));
+        };
+        // SAFE: The slot is within the capacity, and was claimed by this push only.
+        unsafe { Param::write(Param::ptr_at(self.vec.storage, index), value) };
+        // Publish the elements in order, so that every published element is initialized.
+        while self.len.load(Ordering::Acquire) != index {
+            core::hint::spin_loop();
+        }
+        self.len.store(index + 1, Ordering::Release);
+        Ok(index)
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it has not been
+    /// published.
+    pub fn get(&self, index: usize) -> Option<Param::Ref<'_>> {
+        if index >= self.len() {
+            return None;
+        }
+        // SAFE: The element is published, and is never mutated while `self` is borrowed.
+        Some(unsafe { Param::as_ref(Param::ptr_at(self.vec.storage, index)) })
+    }
+
+    /// Gets the published elements as a [`ParallelSlice`].
+    pub fn as_parallel_slice(&self) -> ParallelSlice<'_, Param> {
+        // SAFE: The elements are published, and are never mutated while `self` is
+        // borrowed.
+        unsafe { ParallelSlice::from_raw_parts(self.vec.storage, self.len()) }
+    }
+
+    /// Gets the elements as a [`ParallelSliceMut`].
+    pub fn as_parallel_slice_mut(&mut self) -> ParallelSliceMut<'_, Param> {
+        let len = *self.len.get_mut();
+        // SAFE: The elements are published, and borrowed mutably from `self`.
+        unsafe { ParallelSliceMut::from_raw_parts(self.vec.storage, len) }
+    }
+
+    /// Gets the fields of every published element as slices.
+    pub fn as_slices(&self) -> Param::Slices<'_> {
+        // SAFE: The elements are published, and are never mutated while `self` is
+        // borrowed.
+        unsafe { Param::as_slices(Param::as_ptr(self.vec.storage), self.len()) }
+    }
+
+    /// Returns an iterator over the published elements.
+    pub fn iter(&self) -> Iter<'_, Param> {
+        self.as_parallel_slice().iter()
+    }
+
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.len = *self.len.get_mut();
+        self.vec.reserve(additional);
+        self.vec.len = 0;
+    }
+
+    /// Converts the container into a [`ParallelVec`] of its elements.
+    pub fn into_parallel_vec(mut self) -> ParallelVec<Param> {
+        let mut vec = core::mem::replace(&mut self.vec, ParallelVec::new());
+        vec.len = *self.len.get_mut();
+        *self.len.get_mut() = 0;
+        vec
+    }
+}
+
+impl<Param: ParallelParam> Drop for ConcurrentParallelVec<Param> {
+    fn drop(&mut self) {
+        // The allocation drops the published elements.
+        self.vec.len = *self.len.get_mut();
+    }
+}
+
+impl<Param: ParallelParam> From<ParallelVec<Param>> for ConcurrentParallelVec<Param> {
+    /// Appends to the elements of `vec`, up to its current capacity.
+    fn from(mut vec: ParallelVec<Param>) -> Self {
+        let len = core::mem::replace(&mut vec.len, 0);
+        Self {
+            vec,
+            claimed: AtomicUsize::new(len),
+            len: AtomicUsize::new(len),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConcurrentParallelVec;
+    use crate::{CapacityError, ParallelVec};
+    use alloc::{string::String, vec::Vec};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_concurrent_push() {
+        let vec = ConcurrentParallelVec::with_capacity(200);
+        std::thread::scope(|scope| {
+            for thread in 0..4u32 {
+                let vec = &vec;
+                scope.spawn(move || {
+                    for idx in 0..50u32 {
+                        vec.push((thread, idx, String::from("x"))).unwrap();
+                        // Every published element is readable.
+                        let names = vec.as_slices().2;
+                        assert_eq!(names[names.len() - 1], "x");
+                        assert!(vec.get(names.len() - 1).is_some());
+                    }
+                });
+            }
+        });
+        assert_eq!(vec.push((0, 0, String::new())).unwrap_err().0 .0, 0);
+        let vec = vec.into_parallel_vec();
+        for thread in 0..4 {
+            let indices: Vec<u32> = vec
+                .iter()
+                .filter(|(t, _, _)| **t == thread)
+                .map(|(_, idx, _)| *idx)
+                .collect();
+            assert_eq!(indices, (0..50).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut vec = ConcurrentParallelVec::from(ParallelVec::from(alloc::vec![(1u8,)]));
+        assert_eq!(vec.push((2,)), Err(CapacityError((2,))));
+        vec.reserve(2);
+        assert_eq!(vec.push((2,)), Ok(1));
+        assert_eq!(vec.push((3,)), Ok(2));
+        vec.as_parallel_slice_mut().reverse();
+        assert_eq!(vec.as_parallel_slice().as_slices().0, &[3, 2, 1]);
+        assert_eq!(vec.iter().len(), 3);
+    }
+
+    #[test]
+    fn test_drop() {
+        let rc = Rc::new(());
+        let vec = ConcurrentParallelVec::with_capacity(4);
+        for _ in 0..3 {
+            vec.push((rc.clone(),)).unwrap();
+        }
+        assert_eq!(Rc::strong_count(&rc), 4);
+        core::mem::drop(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{GrowthPolicy, ParallelParam, ParallelVec, Storage};
+use csv::{Reader, Writer};
+use serde::{de::DeserializeOwned, Serialize};
+use std::io::{Read, Write};
+
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> ParallelVec<Param, Growth, A> {
+    /// Writes every element of the vector as a CSV record, with one CSV field per field.
+    ///
+    /// No header record is written. Use [`ParallelVec::to_csv_writer_with_headers`] to
+    /// write one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let vec = ParallelVec::from(vec![(1, 0.5), (2, 1.5)]);
+    /// let mut writer = csv::Writer::from_writer(vec![]);
+    /// vec.to_csv_writer_with_headers(&mut writer, |idx| ["id", "weight"][idx]).unwrap();
+    /// let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    /// assert_eq!(csv, "id,weight\n1,0.5\n2,1.5\n");
+    /// ```
+    pub fn to_csv_writer<'a, W: Write>(&'a self, writer: &mut Writer<W>) -> csv::Result<()>
+    where
+        Param::Ref<'a>: Serialize,
+    {
+        for row in self.iter() {
+            writer.serialize(row)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes a header record, then every element of the vector as a CSV record, with one
+    /// CSV field per field.
+    ///
+    /// `header` is called with the index of every field to get the name of its column.
+    pub fn to_csv_writer_with_headers<'a, W, F, H>(
+        &'a self,
+        writer: &mut Writer<W>,
+        header: F,
+    ) -> csv::Result<()>
+    where
+        Param::Ref<'a>: Serialize,
+        W: Write,
+        F: FnMut(usize) -> H,
+        H: AsRef<[u8]>,
+    {
+        let fields = Param::field_sizes(0).as_ref().len();
+        writer.write_record((0..fields).map(header))?;
+        self.to_csv_writer(writer)
+    }
+}
+
+impl<Param, Growth, A> ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + DeserializeOwned,
+    Growth: GrowthPolicy + Default,
+    A: Storage + Default,
+{
+    /// Reads every CSV record as an element of a new vector, with one CSV field per field.
+    ///
+    /// Fields are matched to CSV fields by position. If the reader is configured to expect
+    /// a header record, as it is by default, the header record is skipped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let mut reader = csv::Reader::from_reader("id,weight\n1,0.5\n2,1.5\n".as_bytes());
+    /// let vec: ParallelVec<(u32, f32)> = ParallelVec::from_csv_reader(&mut reader).unwrap();
+    /// assert_eq!(vec.as_slices(), (&[1, 2][..], &[0.5, 1.5][..]));
+    /// ```
+    pub fn from_csv_reader<R: Read>(reader: &mut Reader<R>) -> csv::Result<Self> {
+        reader.deserialize().collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ParallelVec;
+    use std::string::String;
+
+    fn to_csv(vec: &ParallelVec<(u32, String, Option<f64>)>) -> String {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        vec.to_csv_writer(&mut writer).unwrap();
+        String::from_utf8(writer.into_inner().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let vec = ParallelVec::from(vec![
+            (1u32, String::from("a"), Some(0.5)),
+            (2, String::from("b,c"), None),
+        ]);
+        let csv = to_csv(&vec);
+        assert_eq!(csv, "1,a,0.5\n2,\"b,c\",\n");
+
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(csv.as_bytes());
+        let round_trip: ParallelVec<(u32, String, Option<f64>)> =
+            ParallelVec::from_csv_reader(&mut reader).unwrap();
+        assert!(round_trip == vec);
+    }
+
+    #[test]
+    fn test_csv_headers() {
+        let vec = ParallelVec::from(vec![(1u8, 2u16)]);
+        let mut writer = csv::Writer::from_writer(vec![]);
+        vec.to_csv_writer_with_headers(&mut writer, |idx| format!("column_{}", idx))
+            .unwrap();
+        let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        assert_eq!(csv, "column_0,column_1\n1,2\n");
+
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        let round_trip: ParallelVec<(u8, u16)> = ParallelVec::from_csv_reader(&mut reader).unwrap();
+        assert!(round_trip == vec);
+    }
+
+    #[test]
+    fn test_csv_invalid_record() {
+        let mut reader = csv::Reader::from_reader("a,b\n1,2\n3,x\n".as_bytes());
+        let result: csv::Result<ParallelVec<(u8, u8)>> = ParallelVec::from_csv_reader(&mut reader);
+        assert!(result.is_err());
+    }
+}
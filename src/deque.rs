@@ -0,0 +1,476 @@
+use crate::{
+    growth::{Doubling, GrowthPolicy},
+    handle_reserve_error,
+    iter::{Iter, IterMut},
+    ParallelParam, ParallelSlice, ParallelSliceMut, TryReserveError,
+};
+use allocator_api2::alloc::Global;
+use core::{iter::Chain, marker::PhantomData};
+
+/// A double-ended queue of heterogenous values, implemented as a ring buffer with a
+/// separate column per field, like a [`ParallelVec`].
+///
+/// The elements may wrap around the end of the buffer, in which case every field wraps
+/// at the same index. [`as_slices`] gets the elements as two slices, and
+/// [`make_contiguous`] rearranges them into a single slice.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::ParallelVecDeque;
+///
+/// let mut window = ParallelVecDeque::with_capacity(3);
+/// for sample in 0..5u32 {
+///     if window.len() == 3 {
+///         window.pop_front();
+///     }
+///     window.push_back((sample, sample as f32 * 0.5));
+/// }
+/// assert_eq!(window.front(), Some((&2, &1.0)));
+/// assert_eq!(window.make_contiguous().as_slices().0, &[2, 3, 4]);
+/// ```
+///
+/// [`ParallelVec`]: crate::ParallelVec
+/// [`as_slices`]: Self::as_slices
+/// [`make_contiguous`]: Self::make_contiguous
+pub struct ParallelVecDeque<Param: ParallelParam> {
+    storage: Param::Storage,
+    /// The physical index of the first element.
+    head: usize,
+    len: usize,
+    capacity: usize,
+}
+
+// SAFE: The queue owns its elements, like a `VecDeque`.
+unsafe impl<Param: ParallelParam + Send> Send for ParallelVecDeque<Param> {}
+
+// SAFE: Shared references to the queue only give out shared references to its elements.
+unsafe impl<Param: ParallelParam + Sync> Sync for ParallelVecDeque<Param> {}
+
+impl<Param: ParallelParam> ParallelVecDeque<Param> {
+    /// Constructs a new, empty `ParallelVecDeque`.
+    ///
+    /// The queue will not allocate until elements are pushed onto it.
+    pub fn new() -> Self {
+        Self {
+            storage: Param::dangling(),
+            head: 0,
+            len: 0,
+            capacity: 0,
+        }
+    }
+
+    /// Constructs a new, empty `ParallelVecDeque` with the capacity for exactly
+    /// `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut deque = Self::new();
+        deque.reserve_exact(capacity);
+        deque
+    }
+
+    /// Returns the number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the queue can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn reserve(&mut self, additional: usize) {
+        let result = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)
+            .and_then(|required| {
+                if required <= self.capacity {
+                    return Ok(());
+                }
+                let capacity = Doubling
+                    .grow(self.capacity, required)
+                    .ok_or(TryReserveError::CapacityOverflow)?;
+                self.grow_to(capacity)
+            });
+        if let Err(err) = result {
+            handle_reserve_error(err);
+        }
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more elements.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let result = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)
+            .and_then(|required| {
+                if required <= self.capacity {
+                    return Ok(());
+                }
+                self.grow_to(required)
+            });
+        if let Err(err) = result {
+            handle_reserve_error(err);
+        }
+    }
+
+    /// Appends an element to the back of the queue.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn push_back(&mut self, value: Param) {
+        self.reserve(1);
+        // SAFE: The slot past the last element is in bounds and uninitialized.
+        unsafe { Param::write(self.ptr_at(self.len), value) };
+        self.len += 1;
+    }
+
+    /// Prepends an element to the front of the queue.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn push_front(&mut self, value: Param) {
+        self.reserve(1);
+        self.head = self.wrap_sub(self.head, 1);
+        // SAFE: The slot before the first element is in bounds and uninitialized.
+        unsafe { Param::write(Param::ptr_at(self.storage, self.head), value) };
+        self.len += 1;
+    }
+
+    /// Removes the last element from the queue and returns it, or `None` if it is
+    /// empty.
+    pub fn pop_back(&mut self) -> Option<Param> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFE: The element is initialized, and is past the new length of the queue.
+        Some(unsafe { Param::read(self.ptr_at(self.len)) })
+    }
+
+    /// Removes the first element from the queue and returns it, or `None` if it is
+    /// empty.
+    pub fn pop_front(&mut self) -> Option<Param> {
+        if self.len == 0 {
+            return None;
+        }
+        let head = self.head;
+        self.head = self.wrap_add(self.head, 1);
+        self.len -= 1;
+        // SAFE: The element is initialized, and is before the new head of the queue.
+        Some(unsafe { Param::read(Param::ptr_at(self.storage, head)) })
+    }
+
+    /// Returns a reference to the element at `index` from the front of the queue, or
+    /// `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Param::Ref<'_>> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFE: The element is in bounds, and borrowed from `self`.
+        Some(unsafe { Param::as_ref(self.ptr_at(index)) })
+    }
+
+    /// Returns a mutable reference to the element at `index` from the front of the
+    /// queue, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<Param::RefMut<'_>> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFE: The element is in bounds, and borrowed mutably from `self`.
+        Some(unsafe { Param::as_mut(self.ptr_at(index)) })
+    }
+
+    /// Returns a reference to the first element, or `None` if the queue is empty.
+    pub fn front(&self) -> Option<Param::Ref<'_>> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the last element, or `None` if the queue is empty.
+    pub fn back(&self) -> Option<Param::Ref<'_>> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    /// Removes every element from the queue.
+    pub fn clear(&mut self) {
+        let (front, back) = self.slice_ranges();
+        self.len = 0;
+        self.head = 0;
+        // SAFE: The elements are initialized, and are no longer part of the queue.
+        unsafe {
+            Param::drop_slice(Param::ptr_at(self.storage, front.0), front.1);
+            Param::drop_slice(Param::ptr_at(self.storage, back.0), back.1);
+        }
+    }
+
+    /// Gets the elements of the queue, in order, as two slices. The second slice is
+    /// only non-empty if the elements wrap around the end of the buffer.
+    pub fn as_slices(&self) -> (ParallelSlice<'_, Param>, ParallelSlice<'_, Param>) {
+        let (front, back) = self.slice_ranges();
+        // SAFE: Both ranges are initialized, and borrowed from `self`.
+        unsafe {
+            (
+                ParallelSlice::from_raw_parts(self.storage_at(front.0), front.1),
+                ParallelSlice::from_raw_parts(self.storage_at(back.0), back.1),
+            )
+        }
+    }
+
+    /// Gets the elements of the queue, in order, as two mutable slices. The second
+    /// slice is only non-empty if the elements wrap around the end of the buffer.
+    pub fn as_mut_slices(&mut self) -> (ParallelSliceMut<'_, Param>, ParallelSliceMut<'_, Param>) {
+        let (front, back) = self.slice_ranges();
+        // SAFE: Both ranges are initialized, disjoint, and borrowed mutably from `self`.
+        unsafe {
+            (
+                ParallelSliceMut::from_raw_parts(self.storage_at(front.0), front.1),
+                ParallelSliceMut::from_raw_parts(self.storage_at(back.0), back.1),
+            )
+        }
+    }
+
+    /// Returns an iterator over the elements of the queue, from front to back.
+    pub fn iter(&self) -> Chain<Iter<'_, Param>, Iter<'_, Param>> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back.iter())
+    }
+
+    /// Returns an iterator over mutable references to the elements of the queue, from
+    /// front to back.
+    pub fn iter_mut(&mut self) -> Chain<IterMut<'_, Param>, IterMut<'_, Param>> {
+        let (front, back) = self.slice_ranges();
+        let iter = |(start, len)| IterMut {
+            ptr: Param::as_ptr(self.storage_at(start)),
+            remaining: len,
+            _marker: PhantomData,
+        };
+        // The iterators borrow disjoint ranges of the elements, and borrow `self` mutably.
+        iter(front).chain(iter(back))
+    }
+
+    /// Rearranges the elements of the queue so that they are stored contiguously, and
+    /// returns them as a single slice.
+    ///
+    /// This does not allocate. Every field is moved by the same amount.
+    pub fn make_contiguous(&mut self) -> ParallelSliceMut<'_, Param> {
+        if self.head + self.len > self.capacity {
+            let front_len = self.capacity - self.head;
+            let back_len = self.len - front_len;
+            let free = self.capacity - self.len;
+            // SAFE: Every range is within the buffer, and only initialized elements are
+            // swapped.
+            unsafe {
+                // Move the back half against the front half, so that the elements fill
+                // `free..capacity` with the back half first.
+                if free > 0 {
+                    let base = Param::as_ptr(self.storage);
+                    Param::copy_to(base, Param::add(base, free), back_len);
+                }
+                // Then rotate the range so that the front half comes first.
+                let start = Param::ptr_at(self.storage, free);
+                Param::reverse(Param::as_slices_mut(start, self.len));
+                Param::reverse(Param::as_slices_mut(start, front_len));
+                Param::reverse(Param::as_slices_mut(Param::add(start, front_len), back_len));
+            }
+            self.head = free;
+        }
+        // SAFE: The elements are initialized and contiguous, and borrowed mutably from
+        // `self`.
+        unsafe { ParallelSliceMut::from_raw_parts(self.storage_at(self.head), self.len) }
+    }
+
+    /// Gets the physical start and length of the front and back halves of the elements.
+    fn slice_ranges(&self) -> ((usize, usize), (usize, usize)) {
+        let front_len = self.len.min(self.capacity - self.head);
+        ((self.head, front_len), (0, self.len - front_len))
+    }
+
+    /// Gets the pointers to the element at `index` from the front of the queue.
+    ///
+    /// # Safety
+    /// `index` must be less than the capacity of the queue.
+    unsafe fn ptr_at(&self, index: usize) -> Param::Ptr {
+        Param::ptr_at(self.storage, self.wrap_add(self.head, index))
+    }
+
+    fn storage_at(&self, physical: usize) -> Param::Storage {
+        if self.capacity == 0 {
+            return self.storage;
+        }
+        // SAFE: Physical indices are within the buffer.
+        unsafe { Param::as_storage(Param::ptr_at(self.storage, physical)) }
+    }
+
+    fn wrap_add(&self, index: usize, offset: usize) -> usize {
+        let index = index + offset;
+        if index >= self.capacity {
+            index - self.capacity
+        } else {
+            index
+        }
+    }
+
+    fn wrap_sub(&self, index: usize, offset: usize) -> usize {
+        if index >= offset {
+            index - offset
+        } else {
+            index + self.capacity - offset
+        }
+    }
+
+    /// Moves the elements into a new buffer of `capacity` elements, starting at the
+    /// front of the buffer.
+    fn grow_to(&mut self, capacity: usize) -> Result<(), TryReserveError> {
+        // SAFE: The capacity is non-zero, as it is greater than the current one. Both
+        // halves of the elements are moved into the new buffer before the old one is
+        // deallocated.
+        unsafe {
+            let storage = Param::try_alloc(capacity, 1, &Global)?;
+            let (front, back) = self.slice_ranges();
+            let dst = Param::as_ptr(storage);
+            Param::copy_to_nonoverlapping(Param::ptr_at(self.storage, front.0), dst, front.1);
+            Param::copy_to_nonoverlapping(
+                Param::ptr_at(self.storage, back.0),
+                Param::add(dst, front.1),
+                back.1,
+            );
+            if self.capacity > 0 {
+                Param::dealloc(&mut self.storage, self.capacity, 1, &Global);
+            }
+            self.storage = storage;
+        }
+        self.head = 0;
+        self.capacity = capacity;
+        Ok(())
+    }
+}
+
+impl<Param: ParallelParam> Drop for ParallelVecDeque<Param> {
+    fn drop(&mut self) {
+        self.clear();
+        if self.capacity > 0 {
+            // SAFE: The buffer was allocated with the capacity of the queue.
+            unsafe { Param::dealloc(&mut self.storage, self.capacity, 1, &Global) };
+        }
+    }
+}
+
+impl<Param: ParallelParam> Default for ParallelVecDeque<Param> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param: ParallelParam> Extend<Param> for ParallelVecDeque<Param> {
+    fn extend<I: IntoIterator<Item = Param>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<Param: ParallelParam> FromIterator<Param> for ParallelVecDeque<Param> {
+    fn from_iter<I: IntoIterator<Item = Param>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ParallelVecDeque;
+    use alloc::{string::String, vec::Vec};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_push_pop() {
+        let mut deque = ParallelVecDeque::new();
+        deque.push_back((1u32, 1.0f32));
+        deque.push_front((0, 0.0));
+        deque.push_back((2, 2.0));
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.front(), Some((&0, &0.0)));
+        assert_eq!(deque.back(), Some((&2, &2.0)));
+        assert_eq!(deque.get(1), Some((&1, &1.0)));
+        assert_eq!(deque.pop_front(), Some((0, 0.0)));
+        assert_eq!(deque.pop_back(), Some((2, 2.0)));
+        assert_eq!(deque.pop_back(), Some((1, 1.0)));
+        assert_eq!(deque.pop_front(), None);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let mut deque = ParallelVecDeque::with_capacity(4);
+        for idx in 0..4u32 {
+            deque.push_back((idx, String::from("x")));
+        }
+        deque.pop_front();
+        deque.pop_front();
+        deque.push_back((4, String::from("y")));
+        assert_eq!(deque.capacity(), 4);
+        let (front, back) = deque.as_slices();
+        assert_eq!(front.as_slices().0, &[2, 3]);
+        assert_eq!(back.as_slices().0, &[4]);
+        let values: Vec<u32> = deque.iter().map(|(idx, _)| *idx).collect();
+        assert_eq!(values, [2, 3, 4]);
+
+        // Growing while wrapped keeps the order of the elements.
+        deque.push_front((1, String::from("z")));
+        deque.push_back((5, String::from("w")));
+        let values: Vec<u32> = deque.iter().map(|(idx, _)| *idx).collect();
+        assert_eq!(values, [1, 2, 3, 4, 5]);
+        assert_eq!(deque.get(4).unwrap().1, "w");
+    }
+
+    #[test]
+    fn test_make_contiguous() {
+        for shift in 0..6 {
+            let mut deque = ParallelVecDeque::with_capacity(6);
+            for idx in 0..shift {
+                deque.push_back((idx as u32, idx as f64));
+                deque.pop_front();
+            }
+            for idx in 0..5u32 {
+                deque.push_back((idx, f64::from(idx)));
+            }
+            assert_eq!(deque.capacity(), 6);
+            let slice = deque.make_contiguous();
+            assert_eq!(slice.as_slices().0, &[0, 1, 2, 3, 4]);
+            assert_eq!(slice.as_slices().1, &[0.0, 1.0, 2.0, 3.0, 4.0]);
+            assert_eq!(deque.as_slices().1.len(), 0);
+        }
+    }
+
+    #[test]
+    fn test_iter_mut_and_drop() {
+        let value = Rc::new(());
+        let mut deque: ParallelVecDeque<(u32, Rc<()>)> =
+            (0..3).map(|idx| (idx, value.clone())).collect();
+        deque.push_front((10, value.clone()));
+        for (idx, _) in deque.iter_mut() {
+            *idx += 1;
+        }
+        let values: Vec<u32> = deque.iter().map(|(idx, _)| *idx).collect();
+        assert_eq!(values, [11, 1, 2, 3]);
+        assert_eq!(Rc::strong_count(&value), 5);
+        *deque.get_mut(0).unwrap().0 = 0;
+        assert_eq!(deque.front().unwrap().0, &0);
+        drop(deque);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+}
@@ -0,0 +1,189 @@
+//! Support for `#[derive(ParallelVecParam)]`.
+
+/// Items used by the code generated by `#[derive(ParallelVecParam)]`. Not part of the
+/// public API.
+#[doc(hidden)]
+pub mod __private {
+    use crate::param::MemoryLayout;
+    use crate::ParallelParam;
+
+    pub use crate::param::private::Sealed;
+
+    /// Converts the memory layout of a parameter into the layout of a parameter with the
+    /// same fields.
+    #[inline(always)]
+    pub fn cast_layout<P, Q>(layout: MemoryLayout<P>) -> MemoryLayout<Q>
+    where
+        P: ParallelParam,
+        Q: ParallelParam<Offsets = P::Offsets>,
+    {
+        MemoryLayout {
+            layout: layout.layout,
+            offsets: layout.offsets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{soa, Column, ParallelVec, ParallelVecParam};
+    use alloc::{string::String, vec, vec::Vec};
+
+    #[derive(ParallelVecParam, Debug, Clone, PartialEq)]
+    struct Particle {
+        pos: [f32; 3],
+        vel: [f32; 3],
+        ttl: f32,
+    }
+
+    #[derive(ParallelVecParam, Debug, PartialEq)]
+    struct Named(u8, String);
+
+    #[derive(ParallelVecParam, Debug, PartialEq)]
+    struct Pair<T> {
+        key: u32,
+        value: T,
+    }
+
+    #[soa]
+    #[derive(Debug, PartialEq)]
+    struct Entity<T> {
+        id: u32,
+        name: String,
+        data: T,
+    }
+
+    fn entity(id: u32) -> Entity<Vec<u32>> {
+        Entity {
+            id,
+            name: alloc::format!("e{}", id),
+            data: vec![id; id as usize],
+        }
+    }
+
+    fn particle(idx: u8) -> Particle {
+        let idx = f32::from(idx);
+        Particle {
+            pos: [idx; 3],
+            vel: [1.0, 0.0, 0.0],
+            ttl: idx * 10.0,
+        }
+    }
+
+    #[test]
+    fn test_push_and_get() {
+        let mut vec: ParallelVec<Particle> = ParallelVec::new();
+        for idx in 0..4 {
+            vec.push(particle(idx));
+        }
+        assert_eq!(vec.len(), 4);
+        let (pos, vel, ttl) = vec.index(2);
+        assert_eq!(*pos, [2.0; 3]);
+        assert_eq!(*vel, [1.0, 0.0, 0.0]);
+        assert_eq!(*ttl, 20.0);
+        assert_eq!(vec.pop(), Some(particle(3)));
+        assert_eq!(vec.swap_remove(0), particle(0));
+        assert_eq!(vec.len(), 2);
+    }
+
+    #[test]
+    fn test_columns() {
+        let mut vec: ParallelVec<Particle> = (0..4).map(particle).collect();
+        {
+            let (pos, vel, _) = vec.as_slices_mut();
+            for (pos, vel) in pos.iter_mut().zip(vel.iter()) {
+                pos[0] += vel[0];
+            }
+        }
+        let (pos, _, ttl) = vec.as_slices();
+        assert_eq!(pos[3], [4.0, 3.0, 3.0]);
+        assert_eq!(ttl, &[0.0, 10.0, 20.0, 30.0]);
+        let ttl = vec.select::<(Column<2>,)>();
+        assert_eq!(ttl.as_slices().0, &[0.0, 10.0, 20.0, 30.0]);
+    }
+
+    #[test]
+    fn test_tuple_struct_drops() {
+        let mut vec: ParallelVec<Named> = ParallelVec::new();
+        vec.push(Named(1, String::from("a")));
+        vec.push(Named(2, String::from("b")));
+        vec.insert(0, Named(0, String::from("c")));
+        let (ids, names) = vec.as_slices();
+        assert_eq!(ids, &[0, 1, 2]);
+        assert_eq!(names, &["c", "a", "b"]);
+        let values: Vec<Named> = vec.into_iter().collect();
+        assert_eq!(values[1], Named(1, String::from("a")));
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let rows: Vec<Particle> = (0..200).map(particle).collect();
+        let vec = ParallelVec::from(rows.clone());
+        assert_eq!(vec.len(), 200);
+        assert_eq!(vec.as_slices().2[199], 1990.0);
+        assert_eq!(vec.into_vec(), rows);
+    }
+
+    #[test]
+    fn test_generic_struct() {
+        let mut vec: ParallelVec<Pair<Vec<u32>>> = ParallelVec::new();
+        vec.push(Pair {
+            key: 1,
+            value: vec![1, 2],
+        });
+        vec.push(Pair {
+            key: 0,
+            value: vec![3],
+        });
+        vec.as_parallel_slice_mut().sort_by_column::<0>();
+        let (keys, values) = vec.as_slices();
+        assert_eq!(keys, &[0, 1]);
+        assert_eq!(values[0], vec![3]);
+        assert_eq!(vec.into_vec().remove(1).value, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_soa_vec() {
+        let mut vec: EntityVec<Vec<u32>> = (0..3).map(entity).collect();
+        vec.push(entity(3));
+        vec.insert(0, entity(4));
+        assert_eq!(vec.len(), 5);
+        assert_eq!(vec.id(), &[4, 0, 1, 2, 3]);
+        assert_eq!(vec.name()[1], "e0");
+        assert_eq!(vec.remove(0), Some(entity(4)));
+        assert_eq!(vec.swap_remove(0), entity(0));
+        assert_eq!(vec.pop(), Some(entity(2)));
+        assert_eq!(vec.id(), &[3, 1]);
+
+        vec.id_mut()[0] = 7;
+        let entity_ref = vec.get(0).unwrap();
+        assert_eq!(*entity_ref.id, 7);
+        assert_eq!(entity_ref.data, &vec![3; 3]);
+        assert!(vec.get(2).is_none());
+        vec.get_mut(1).unwrap().data.push(9);
+        assert_eq!(vec.data()[1], vec![1, 9]);
+    }
+
+    #[test]
+    fn test_soa_iter() {
+        let mut vec = EntityVec::default();
+        vec.extend((0..4).map(entity));
+        for entity in &mut vec {
+            *entity.id *= 2;
+            entity.name.push('!');
+        }
+        let ids: Vec<u32> = vec.iter().rev().map(|entity| *entity.id).collect();
+        assert_eq!(ids, [6, 4, 2, 0]);
+        assert_eq!(vec.iter().len(), 4);
+        let names: Vec<&str> = (&vec)
+            .into_iter()
+            .map(|entity| entity.name.as_str())
+            .collect();
+        assert_eq!(names, ["e0!", "e1!", "e2!", "e3!"]);
+
+        let parallel: ParallelVec<Entity<Vec<u32>>> = vec.into();
+        let vec = EntityVec::from(parallel);
+        let entities: Vec<Entity<Vec<u32>>> = vec.into_iter().collect();
+        assert_eq!(entities[3].data, vec![3; 3]);
+    }
+}
@@ -0,0 +1,632 @@
+use crate::{
+    assert_in_bounds,
+    growth::{Doubling, GrowthPolicy},
+    handle_reserve_error,
+    param::{realloc_fields, try_fields_layout},
+    Storage, TryReserveError,
+};
+use alloc::{alloc::Layout, boxed::Box, vec, vec::Vec};
+use allocator_api2::alloc::Global;
+use core::{any::TypeId, ptr::NonNull};
+
+/// Describes a column of a [`DynParallelVec`]: the layout of its values, and how to
+/// drop them.
+#[derive(Debug, Clone, Copy)]
+pub struct DynColumn {
+    layout: Layout,
+    drop: Option<unsafe fn(*mut u8)>,
+    type_id: Option<TypeId>,
+}
+
+impl DynColumn {
+    /// Describes a column of values of type `T`.
+    ///
+    /// The values of the column can be accessed as a `&[T]` with
+    /// [`DynParallelVec::column`].
+    pub fn of<T: 'static>() -> Self {
+        Self {
+            layout: Layout::new::<T>(),
+            drop: if core::mem::needs_drop::<T>() {
+                Some(drop_value::<T>)
+            } else {
+                None
+            },
+            type_id: Some(TypeId::of::<T>()),
+        }
+    }
+
+    /// Describes a column of values with the provided `layout`, which are dropped by
+    /// calling `drop` with a pointer to the value, if it is provided.
+    ///
+    /// The values of the column can only be accessed through raw pointers.
+    pub fn new(layout: Layout, drop: Option<unsafe fn(*mut u8)>) -> Self {
+        Self {
+            layout,
+            drop,
+            type_id: None,
+        }
+    }
+
+    /// The layout of a single value of the column.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// The distance in bytes between consecutive values of the column.
+    fn stride(&self) -> usize {
+        self.layout.pad_to_align().size()
+    }
+}
+
+unsafe fn drop_value<T>(ptr: *mut u8) {
+    ptr.cast::<T>().drop_in_place();
+}
+
+/// Creates a dangling pointer with the provided alignment, for buffers with no size.
+fn dangling(align: usize) -> NonNull<u8> {
+    // SAFE: Alignments are never zero. The pointer has no provenance, so it is never
+    // used to access memory other than zero-sized values.
+    unsafe { NonNull::new_unchecked(core::ptr::null_mut::<u8>().wrapping_add(align)) }
+}
+
+/// A [`ParallelVec`] whose columns are described at runtime instead of by a tuple of
+/// types.
+///
+/// Each column is described by a [`DynColumn`], with the layout of its values and
+/// the function used to drop them. This allows building tables whose set of columns
+/// is not known at compile time, such as the archetypes of an Entity-Component-System.
+/// Like [`ParallelVec`], the columns share a single allocation, which grows according
+/// to a [`GrowthPolicy`] and is allocated from a [`Storage`].
+///
+/// As the types of the values are not known, elements are added and moved out of the
+/// vector through raw pointers. Columns created with [`DynColumn::of`] can also be
+/// accessed as typed slices.
+///
+/// This type is neither `Send` nor `Sync`, as the columns may contain any values.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::{DynColumn, DynParallelVec};
+/// use std::mem::ManuallyDrop;
+///
+/// let mut vec = DynParallelVec::new([DynColumn::of::<u32>(), DynColumn::of::<String>()]);
+/// let id = 7u32;
+/// let name = ManuallyDrop::new(String::from("seven"));
+/// unsafe {
+///     vec.push_raw(&[
+///         (&id as *const u32).cast(),
+///         (&*name as *const String).cast(),
+///     ]);
+/// }
+/// assert_eq!(vec.column::<u32>(0), Some(&[7][..]));
+/// assert_eq!(vec.column::<String>(1).unwrap()[0], "seven");
+/// assert_eq!(vec.column::<u64>(0), None);
+/// ```
+///
+/// [`ParallelVec`]: crate::ParallelVec
+pub struct DynParallelVec<Growth = Doubling, A: Storage = Global> {
+    columns: Box<[DynColumn]>,
+    offsets: Box<[usize]>,
+    base: NonNull<u8>,
+    len: usize,
+    capacity: usize,
+    growth: Growth,
+    alloc: A,
+}
+
+impl DynParallelVec {
+    /// Constructs a new, empty vector with the provided columns.
+    ///
+    /// The vector will not allocate until elements are pushed onto it.
+    pub fn new(columns: impl IntoIterator<Item = DynColumn>) -> Self {
+        Self::with_capacity(columns, 0)
+    }
+
+    /// Constructs a new, empty vector with the provided columns, and the capacity for
+    /// exactly `capacity` elements.
+    pub fn with_capacity(columns: impl IntoIterator<Item = DynColumn>, capacity: usize) -> Self {
+        Self::with_capacity_and_growth_policy_in(columns, capacity, Doubling, Global)
+    }
+}
+
+impl<Growth: GrowthPolicy, A: Storage> DynParallelVec<Growth, A> {
+    /// Constructs a new, empty vector with the provided columns, growth policy, and
+    /// allocator, with the capacity for exactly `capacity` elements.
+    pub fn with_capacity_and_growth_policy_in(
+        columns: impl IntoIterator<Item = DynColumn>,
+        capacity: usize,
+        growth: Growth,
+        alloc: A,
+    ) -> Self {
+        let columns: Box<[DynColumn]> = columns.into_iter().collect();
+        let mut vec = Self {
+            offsets: vec![0; columns.len()].into_boxed_slice(),
+            columns,
+            base: NonNull::dangling(),
+            len: 0,
+            capacity: 0,
+            growth,
+            alloc,
+        };
+        // An empty buffer still needs to be aligned for zero-sized columns.
+        vec.base = dangling(vec.layout_for_capacity(0).align());
+        vec.reserve_exact(capacity);
+        vec
+    }
+
+    /// The columns of the vector.
+    pub fn columns(&self) -> &[DynColumn] {
+        &self.columns
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the vector can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Gets the growth policy of the vector.
+    pub fn growth_policy(&self) -> &Growth {
+        &self.growth
+    }
+
+    /// Gets the allocator of the vector.
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    /// Gets a pointer to the first value of `column`.
+    ///
+    /// # Panics
+    /// Panics if `column` is out of bounds.
+    pub fn column_ptr(&self, column: usize) -> NonNull<u8> {
+        assert_in_bounds(column, self.columns.len());
+        // SAFE: The offset of every column is within the buffer.
+        unsafe { NonNull::new_unchecked(self.base.as_ptr().add(self.offsets[column])) }
+    }
+
+    /// Gets a pointer to the value of `column` for the element at `index`, or `None` if
+    /// `index` is out of bounds.
+    ///
+    /// # Panics
+    /// Panics if `column` is out of bounds.
+    pub fn get_raw(&self, column: usize, index: usize) -> Option<NonNull<u8>> {
+        let ptr = self.column_ptr(column);
+        if index >= self.len {
+            return None;
+        }
+        // SAFE: The element is in bounds of the column.
+        unsafe {
+            let stride = self.columns[column].stride();
+            Some(NonNull::new_unchecked(ptr.as_ptr().add(index * stride)))
+        }
+    }
+
+    /// Gets the values of `column` as a slice, or `None` if the column was not created
+    /// with [`DynColumn::of::<T>`].
+    ///
+    /// # Panics
+    /// Panics if `column` is out of bounds.
+    ///
+    /// [`DynColumn::of::<T>`]: DynColumn::of
+    pub fn column<T: 'static>(&self, column: usize) -> Option<&[T]> {
+        let ptr = self.column_ptr(column);
+        if self.columns[column].type_id != Some(TypeId::of::<T>()) {
+            return None;
+        }
+        // SAFE: The column holds `len` initialized values of `T`.
+        Some(unsafe { core::slice::from_raw_parts(ptr.as_ptr().cast::<T>(), self.len) })
+    }
+
+    /// Gets the values of `column` as a mutable slice, or `None` if the column was not
+    /// created with [`DynColumn::of::<T>`].
+    ///
+    /// # Panics
+    /// Panics if `column` is out of bounds.
+    ///
+    /// [`DynColumn::of::<T>`]: DynColumn::of
+    pub fn column_mut<T: 'static>(&mut self, column: usize) -> Option<&mut [T]> {
+        let ptr = self.column_ptr(column);
+        if self.columns[column].type_id != Some(TypeId::of::<T>()) {
+            return None;
+        }
+        // SAFE: The column holds `len` initialized values of `T`, and is borrowed
+        // mutably from `self`.
+        Some(unsafe { core::slice::from_raw_parts_mut(ptr.as_ptr().cast::<T>(), self.len) })
+    }
+
+    /// Appends an element to the back of the vector, moving the value of each column
+    /// out of the matching pointer in `values`.
+    ///
+    /// # Safety
+    /// Each pointer must point to a valid value described by its column. The values
+    /// are moved into the vector, so they must not be used or dropped afterwards.
+    ///
+    /// # Panics
+    /// Panics if `values` does not have one pointer per column, or if the new capacity
+    /// overflows.
+    pub unsafe fn push_raw(&mut self, values: &[*const u8]) {
+        assert_eq!(
+            values.len(),
+            self.columns.len(),
+            "the number of values does not match the number of columns"
+        );
+        self.reserve(1);
+        for (column, value) in values.iter().enumerate() {
+            let size = self.columns[column].layout.size();
+            let dst = self.column_ptr(column).as_ptr();
+            value.copy_to_nonoverlapping(dst.add(self.len * self.columns[column].stride()), size);
+        }
+        self.len += 1;
+    }
+
+    /// Removes the element at `index`, moving the value of each column into the
+    /// matching pointer in `dst`, and replaces it with the last element of the vector.
+    ///
+    /// # Safety
+    /// Each pointer must be valid for writes of a value described by its column. The
+    /// values are moved out of the vector, so the caller becomes responsible for
+    /// dropping them.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds, or if `dst` does not have one pointer per
+    /// column.
+    pub unsafe fn swap_remove_raw(&mut self, index: usize, dst: &[*mut u8]) {
+        assert_in_bounds(index, self.len);
+        assert_eq!(
+            dst.len(),
+            self.columns.len(),
+            "the number of pointers does not match the number of columns"
+        );
+        let last = self.len - 1;
+        self.len = last;
+        for (column, dst) in dst.iter().enumerate() {
+            let size = self.columns[column].layout.size();
+            let stride = self.columns[column].stride();
+            let ptr = self.column_ptr(column).as_ptr();
+            ptr.add(index * stride).copy_to_nonoverlapping(*dst, size);
+            if index != last {
+                ptr.add(last * stride)
+                    .copy_to_nonoverlapping(ptr.add(index * stride), size);
+            }
+        }
+    }
+
+    /// Removes and drops the element at `index`, and replaces it with the last element
+    /// of the vector.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) {
+        assert_in_bounds(index, self.len);
+        let last = self.len - 1;
+        // Remove the element before dropping it, so that a panicking drop cannot
+        // leave the vector with a dropped element.
+        unsafe {
+            self.swap(index, last);
+            self.len = last;
+            self.drop_range(last, last + 1);
+        }
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest.
+    ///
+    /// Does nothing if `len` is greater than or equal to the length of the vector. If
+    /// dropping a value panics, the remaining values are leaked.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let end = self.len;
+        self.len = len;
+        // SAFE: The elements are past the length of the vector, and are never
+        // accessed again.
+        unsafe { self.drop_range(len, end) };
+    }
+
+    /// Clears the vector, removing all values.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Reserves capacity for at least `additional` more elements, as determined by the
+    /// growth policy of the vector.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(err) = self.try_reserve(additional) {
+            handle_reserve_error(err);
+        }
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more elements.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        if let Err(err) = self.try_reserve_exact(additional) {
+            handle_reserve_error(err);
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements, as determined
+    /// by the growth policy of the vector.
+    ///
+    /// # Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned and the vector is left unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let new_len = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if new_len <= self.capacity {
+            return Ok(());
+        }
+        let capacity = self
+            .growth
+            .grow(self.capacity, new_len)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        debug_assert!(capacity >= new_len);
+        self.grow_to(capacity)
+    }
+
+    /// Tries to reserve the minimum capacity for exactly `additional` more elements.
+    ///
+    /// # Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned and the vector is left unchanged.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let new_len = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if new_len <= self.capacity {
+            return Ok(());
+        }
+        self.grow_to(new_len)
+    }
+
+    /// Shrinks the capacity of the vector as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity > self.len {
+            if let Err(err) = self.grow_to(self.len) {
+                handle_reserve_error(err);
+            }
+        }
+    }
+
+    /// Computes the layout of the buffer for `capacity` elements, writing the offset of
+    /// each column to `offsets`.
+    fn try_layout_for_capacity(&self, capacity: usize, offsets: &mut [usize]) -> Option<Layout> {
+        let layouts = self.columns.iter().map(|column| column.layout);
+        try_fields_layout(layouts, capacity, 1, offsets)
+    }
+
+    fn layout_for_capacity(&self, capacity: usize) -> Layout {
+        let mut offsets = vec![0; self.columns.len()];
+        match self.try_layout_for_capacity(capacity, &mut offsets) {
+            Some(layout) => layout,
+            None => handle_reserve_error(TryReserveError::CapacityOverflow),
+        }
+    }
+
+    /// Resizes the buffer to exactly `capacity` elements, which must be at least the
+    /// length of the vector.
+    fn grow_to(&mut self, capacity: usize) -> Result<(), TryReserveError> {
+        let old_layout = self.layout_for_capacity(self.capacity);
+        let mut offsets = vec![0; self.columns.len()].into_boxed_slice();
+        let new_layout = self
+            .try_layout_for_capacity(capacity, &mut offsets)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        let error = TryReserveError::AllocError { layout: new_layout };
+        // SAFE: The buffer was allocated from `alloc` with `old_layout`, and the first
+        // `len` values of every column fit within both layouts.
+        self.base = unsafe {
+            if old_layout.size() == 0 {
+                if new_layout.size() == 0 {
+                    dangling(new_layout.align())
+                } else {
+                    self.alloc.allocate(new_layout).map_err(|_| error)?
+                }
+            } else if new_layout.size() == 0 {
+                self.alloc.deallocate(self.base, old_layout);
+                dangling(new_layout.align())
+            } else {
+                let sizes: Vec<usize> = self
+                    .columns
+                    .iter()
+                    .map(|column| column.stride() * self.len)
+                    .collect();
+                realloc_fields(
+                    self.base,
+                    (old_layout, &self.offsets),
+                    (new_layout, &offsets),
+                    &sizes,
+                    &self.alloc,
+                )?
+            }
+        };
+        self.offsets = offsets;
+        self.capacity = capacity;
+        Ok(())
+    }
+
+    /// Swaps the values of every column of the elements at `a` and `b`.
+    ///
+    /// # Safety
+    /// Both `a` and `b` must be less than the capacity of the vector.
+    unsafe fn swap(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        for column in 0..self.columns.len() {
+            let stride = self.columns[column].stride();
+            let ptr = self.column_ptr(column).as_ptr();
+            core::ptr::swap_nonoverlapping(ptr.add(a * stride), ptr.add(b * stride), stride);
+        }
+    }
+
+    /// Drops the values of every column of the elements in `start..end`.
+    ///
+    /// # Safety
+    /// The elements must be initialized, and must not be accessed again.
+    unsafe fn drop_range(&mut self, start: usize, end: usize) {
+        for column in 0..self.columns.len() {
+            if let Some(drop) = self.columns[column].drop {
+                let stride = self.columns[column].stride();
+                let ptr = self.column_ptr(column).as_ptr();
+                for idx in start..end {
+                    drop(ptr.add(idx * stride));
+                }
+            }
+        }
+    }
+}
+
+impl<Growth, A: Storage> Drop for DynParallelVec<Growth, A> {
+    fn drop(&mut self) {
+        let len = self.len;
+        self.len = 0;
+        let mut offsets = vec![0; self.columns.len()];
+        let layouts = self.columns.iter().map(|column| column.layout);
+        // SAFE: The first `len` elements are initialized, and the buffer was allocated
+        // from `alloc` with the layout for the capacity of the vector.
+        unsafe {
+            for (column, offset) in self.columns.iter().zip(self.offsets.iter()) {
+                if let Some(drop) = column.drop {
+                    let ptr = self.base.as_ptr().add(*offset);
+                    for idx in 0..len {
+                        drop(ptr.add(idx * column.stride()));
+                    }
+                }
+            }
+            if let Some(layout) = try_fields_layout(layouts, self.capacity, 1, &mut offsets) {
+                if layout.size() > 0 {
+                    self.alloc.deallocate(self.base, layout);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DynColumn, DynParallelVec};
+    use alloc::{alloc::Layout, string::String, vec::Vec};
+    use core::mem::ManuallyDrop;
+    use std::rc::Rc;
+
+    fn push(vec: &mut DynParallelVec, id: u32, name: &str) {
+        let name = ManuallyDrop::new(String::from(name));
+        unsafe {
+            vec.push_raw(&[(&id as *const u32).cast(), (&*name as *const String).cast()]);
+        }
+    }
+
+    #[test]
+    fn test_push_and_columns() {
+        let mut vec = DynParallelVec::new([DynColumn::of::<u32>(), DynColumn::of::<String>()]);
+        assert_eq!(vec.capacity(), 0);
+        for id in 0..10 {
+            push(&mut vec, id, &alloc::format!("{}", id));
+        }
+        assert_eq!(vec.len(), 10);
+        assert!(vec.capacity() >= 10);
+        let ids: Vec<u32> = (0..10).collect();
+        assert_eq!(vec.column::<u32>(0), Some(&ids[..]));
+        assert_eq!(vec.column::<String>(1).unwrap()[9], "9");
+        assert_eq!(vec.column::<String>(0), None);
+
+        vec.column_mut::<u32>(0).unwrap()[3] = 30;
+        let ptr = vec.get_raw(0, 3).unwrap();
+        assert_eq!(unsafe { *ptr.as_ptr().cast::<u32>() }, 30);
+        assert!(vec.get_raw(0, 10).is_none());
+    }
+
+    #[test]
+    fn test_swap_remove() {
+        let mut vec = DynParallelVec::new([DynColumn::of::<u32>(), DynColumn::of::<String>()]);
+        for (id, name) in ["a", "b", "c", "d"].iter().enumerate() {
+            push(&mut vec, id as u32, name);
+        }
+        vec.swap_remove(0);
+        assert_eq!(vec.column::<u32>(0), Some(&[3, 1, 2][..]));
+
+        let mut id = 0u32;
+        let mut name = core::mem::MaybeUninit::<String>::uninit();
+        unsafe {
+            vec.swap_remove_raw(1, &[(&mut id as *mut u32).cast(), name.as_mut_ptr().cast()]);
+        }
+        let name = unsafe { name.assume_init() };
+        assert_eq!((id, name.as_str()), (1, "b"));
+        assert_eq!(vec.column::<u32>(0), Some(&[3, 2][..]));
+        assert_eq!(vec.column::<String>(1).unwrap(), &["d", "c"]);
+    }
+
+    #[test]
+    fn test_drops_values() {
+        let value = Rc::new(());
+        let mut vec = DynParallelVec::new([DynColumn::of::<Rc<()>>(), DynColumn::of::<u8>()]);
+        for _ in 0..6 {
+            let clone = ManuallyDrop::new(value.clone());
+            unsafe { vec.push_raw(&[(&*clone as *const Rc<()>).cast(), (&0u8 as *const u8)]) };
+        }
+        assert_eq!(Rc::strong_count(&value), 7);
+        vec.truncate(4);
+        assert_eq!(Rc::strong_count(&value), 5);
+        vec.swap_remove(0);
+        assert_eq!(Rc::strong_count(&value), 4);
+        vec.shrink_to_fit();
+        assert_eq!(vec.capacity(), 3);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_raw_and_zero_sized_columns() {
+        let mut vec = DynParallelVec::new([
+            DynColumn::new(Layout::new::<[u16; 3]>(), None),
+            DynColumn::of::<()>(),
+            DynColumn::new(Layout::from_size_align(0, 64).unwrap(), None),
+        ]);
+        for idx in 0..5u16 {
+            let value = [idx; 3];
+            unsafe {
+                vec.push_raw(&[
+                    (&value as *const [u16; 3]).cast(),
+                    (&() as *const ()).cast(),
+                    core::ptr::null::<u8>().wrapping_add(64),
+                ]);
+            }
+        }
+        assert_eq!(vec.column::<()>(1).unwrap().len(), 5);
+        assert_eq!(vec.column::<[u16; 3]>(0), None);
+        assert_eq!(vec.column_ptr(2).as_ptr().align_offset(64), 0);
+        let ptr = vec.get_raw(0, 4).unwrap().as_ptr().cast::<[u16; 3]>();
+        assert_eq!(unsafe { *ptr }, [4; 3]);
+        vec.clear();
+        vec.shrink_to_fit();
+        assert_eq!(vec.capacity(), 0);
+    }
+
+    #[test]
+    fn test_no_columns() {
+        let mut vec = DynParallelVec::new([]);
+        unsafe { vec.push_raw(&[]) };
+        unsafe { vec.push_raw(&[]) };
+        assert_eq!(vec.len(), 2);
+        vec.swap_remove(1);
+        assert_eq!(vec.len(), 1);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    assert_bitmask_len, assert_in_bounds, bitmask_get, ParallelParam, ParallelSlice,
+    ParallelSliceMut, ParallelVec,
+};
+use alloc::vec::Vec;
+
+macro_rules! impl_gather {
+    ($($ts:ident $vs:ident $idx:tt),*) => {
+        impl<'a, $($ts: Clone),*> ParallelSlice<'a, ($($ts,)*)> {
+            /// Creates a new [`ParallelVec`] from clones of the elements at `indices`, in
+            /// order. An index may be repeated to clone an element more than once.
+            ///
+            /// The values are gathered one field at a time, so each field of the slice is
+            /// only read while its own values are gathered.
+            ///
+            /// # Panics
+            /// Panics if any of the indices are out of bounds. If cloning a value panics,
+            /// the values that were already cloned are leaked.
+            pub fn gather(&self, indices: &[usize]) -> ParallelVec<($($ts,)*)> {
+                for idx in indices {
+                    assert_in_bounds(*idx, self.len);
+                }
+                let mut vec = ParallelVec::with_capacity(indices.len());
+                let ($($vs,)*) = self.as_slices();
+                let dst = <($($ts,)*)>::as_ptr(vec.storage);
+                // SAFE: The vector has the capacity for every index, and the length is only
+                // set once every field has been written.
+                unsafe {
+                    $(
+                        for (offset, idx) in indices.iter().enumerate() {
+                            dst.$idx.as_ptr().add(offset).write($vs.get_unchecked(*idx).clone());
+                        }
+                    )*
+                }
+                vec.len = indices.len();
+                vec.validate();
+                vec
+            }
+
+            /// Creates a new [`ParallelVec`] from clones of the elements whose entry in
+            /// `mask` is `true`, in order.
+            ///
+            /// # Panics
+            /// Panics if `mask` is not the same length as the slice.
+            pub fn select_by_mask(&self, mask: &[bool]) -> ParallelVec<($($ts,)*)> {
+                assert_eq!(
+                    mask.len(),
+                    self.len,
+                    "mask length does not match the length of the slice"
+                );
+                let indices: Vec<usize> = (0..self.len).filter(|idx| mask[*idx]).collect();
+                self.gather(&indices)
+            }
+
+            /// Creates a new [`ParallelVec`] from clones of the elements whose bit in
+            /// `bitmask` is set, in order.
+            ///
+            /// The bit for the element at `idx` is bit `idx % 64` of `bitmask[idx / 64]`,
+            /// counting from the least significant bit. Bits past the length of the slice
+            /// are ignored.
+            ///
+            /// # Panics
+            /// Panics if `bitmask` has fewer bits than the length of the slice.
+            pub fn select_by_bitmask(&self, bitmask: &[u64]) -> ParallelVec<($($ts,)*)> {
+                assert_bitmask_len(bitmask, self.len);
+                let indices: Vec<usize> =
+                    (0..self.len).filter(|idx| bitmask_get(bitmask, *idx)).collect();
+                self.gather(&indices)
+            }
+        }
+
+        impl<'a, $($ts: Clone),*> ParallelSliceMut<'a, ($($ts,)*)> {
+            /// Creates a new [`ParallelVec`] from clones of the elements at `indices`, in
+            /// order. An index may be repeated to clone an element more than once.
+            ///
+            /// The values are gathered one field at a time, so each field of the slice is
+            /// only read while its own values are gathered.
+            ///
+            /// # Panics
+            /// Panics if any of the indices are out of bounds. If cloning a value panics,
+            /// the values that were already cloned are leaked.
+            pub fn gather(&self, indices: &[usize]) -> ParallelVec<($($ts,)*)> {
+                // SAFE: The shared slice borrows from `self`, so it cannot be mutated.
+                unsafe { ParallelSlice::<($($ts,)*)>::from_raw_parts(self.storage, self.len) }
+                    .gather(indices)
+            }
+
+            /// Creates a new [`ParallelVec`] from clones of the elements whose entry in
+            /// `mask` is `true`, in order.
+            ///
+            /// # Panics
+            /// Panics if `mask` is not the same length as the slice.
+            pub fn select_by_mask(&self, mask: &[bool]) -> ParallelVec<($($ts,)*)> {
+                // SAFE: The shared slice borrows from `self`, so it cannot be mutated.
+                unsafe { ParallelSlice::<($($ts,)*)>::from_raw_parts(self.storage, self.len) }
+                    .select_by_mask(mask)
+            }
+
+            /// Creates a new [`ParallelVec`] from clones of the elements whose bit in
+            /// `bitmask` is set, in order.
+            ///
+            /// The bit for the element at `idx` is bit `idx % 64` of `bitmask[idx / 64]`,
+            /// counting from the least significant bit. Bits past the length of the slice
+            /// are ignored.
+            ///
+            /// # Panics
+            /// Panics if `bitmask` has fewer bits than the length of the slice.
+            pub fn select_by_bitmask(&self, bitmask: &[u64]) -> ParallelVec<($($ts,)*)> {
+                // SAFE: The shared slice borrows from `self`, so it cannot be mutated.
+                unsafe { ParallelSlice::<($($ts,)*)>::from_raw_parts(self.storage, self.len) }
+                    .select_by_bitmask(bitmask)
+            }
+        }
+    };
+}
+
+impl_gather!(T1 V1 0);
+impl_gather!(T1 V1 0, T2 V2 1);
+impl_gather!(T1 V1 0, T2 V2 1, T3 V3 2);
+impl_gather!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3);
+impl_gather!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4);
+impl_gather!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5);
+impl_gather!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6);
+impl_gather!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7);
+impl_gather!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8);
+impl_gather!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9
+);
+impl_gather!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10
+);
+impl_gather!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11
+);
+impl_gather!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12
+);
+impl_gather!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13
+);
+impl_gather!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13, T15 V15 14
+);
+impl_gather!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13, T15 V15 14, T16 V16 15
+);
@@ -0,0 +1,66 @@
+/// A strategy for computing the new capacity of a [`ParallelVec`] when it
+/// needs to reallocate to fit more elements.
+///
+/// The policy is only consulted by amortized growth (i.e. [`push`], [`reserve`],
+/// [`extend`]). Exact reservations like [`reserve_exact`] always allocate exactly
+/// the requested capacity.
+///
+/// [`ParallelVec`]: crate::ParallelVec
+/// [`push`]: crate::ParallelVec::push
+/// [`reserve`]: crate::ParallelVec::reserve
+/// [`extend`]: core::iter::Extend::extend
+/// [`reserve_exact`]: crate::ParallelVec::reserve_exact
+pub trait GrowthPolicy {
+    /// Computes the capacity to grow to, given the `current` capacity and the
+    /// `required` minimum capacity. `required` is always greater than `current`.
+    ///
+    /// The returned capacity must be greater than or equal to `required`.
+    /// Returns `None` if the computed capacity overflows.
+    fn grow(&self, current: usize, required: usize) -> Option<usize>;
+}
+
+/// Grows the capacity to the next power of two that fits the required capacity,
+/// with a minimum non-zero capacity of 4.
+///
+/// This is the default growth policy.
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, PartialEq)]
+pub struct Doubling;
+
+impl GrowthPolicy for Doubling {
+    #[inline]
+    fn grow(&self, _: usize, required: usize) -> Option<usize> {
+        Some(required.checked_next_power_of_two()?.max(4))
+    }
+}
+
+/// Grows the capacity by a factor of 1.5, with a minimum non-zero capacity of 4.
+///
+/// This trades off more frequent reallocations for less wasted memory than
+/// [`Doubling`].
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, PartialEq)]
+pub struct OneAndAHalf;
+
+impl GrowthPolicy for OneAndAHalf {
+    #[inline]
+    fn grow(&self, current: usize, required: usize) -> Option<usize> {
+        let grown = current.checked_add(current / 2)?;
+        Some(grown.max(required).max(4))
+    }
+}
+
+/// Grows the capacity to exactly the required capacity.
+///
+/// This never over-allocates, but makes repeated calls to [`push`] `O(N)`
+/// each. Useful for memory constrained environments where the final size
+/// is reserved upfront.
+///
+/// [`push`]: crate::ParallelVec::push
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, PartialEq)]
+pub struct Exact;
+
+impl GrowthPolicy for Exact {
+    #[inline]
+    fn grow(&self, _: usize, required: usize) -> Option<usize> {
+        Some(required)
+    }
+}
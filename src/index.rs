@@ -0,0 +1,77 @@
+use core::{fmt::Debug, hash::Hash};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for usize {}
+}
+
+/// An unsigned integer type used to store indices into a [`ParallelVec`], such as the
+/// permutations returned by [`argsort_by_as`].
+///
+/// Storing indices as `u32` instead of `usize` halves the size of index buffers on
+/// 64-bit targets, for containers that are known to stay under `u32::MAX` elements.
+///
+/// This trait is sealed, and is implemented for `u8`, `u16`, `u32` and `usize`.
+///
+/// [`ParallelVec`]: crate::ParallelVec
+/// [`argsort_by_as`]: crate::ParallelSlice::argsort_by_as
+pub trait ParallelIndex: Copy + Ord + Hash + Debug + private::Sealed {
+    /// Converts an index into `Self`, or returns `None` if it does not fit.
+    fn try_from_index(index: usize) -> Option<Self>;
+
+    /// Converts `self` into an index.
+    fn index(self) -> usize;
+}
+
+macro_rules! impl_parallel_index {
+    ($($ts:ty),*) => {
+        $(
+            impl ParallelIndex for $ts {
+                #[inline(always)]
+                fn try_from_index(index: usize) -> Option<Self> {
+                    Self::try_from(index).ok()
+                }
+
+                #[inline(always)]
+                fn index(self) -> usize {
+                    self as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_parallel_index!(u8, u16, u32, usize);
+
+/// Creates the indices `0..len` as `I`.
+///
+/// # Panics
+/// Panics if `len` does not fit in `I`.
+pub(crate) fn indices<I: ParallelIndex>(len: usize) -> alloc::vec::Vec<I> {
+    (0..len)
+        .map(|idx| I::try_from_index(idx).expect("length does not fit in the index type"))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{indices, ParallelIndex};
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(u8::try_from_index(255), Some(255));
+        assert_eq!(u8::try_from_index(256), None);
+        assert_eq!(u32::try_from_index(7).map(u32::index), Some(7));
+        assert_eq!(indices::<u16>(3), [0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in the index type")]
+    fn test_indices_overflow() {
+        indices::<u8>(257);
+    }
+}
@@ -1,9 +1,15 @@
-use crate::ParallelParam;
+use crate::vec::DeallocOnDrop;
+use crate::{ParallelParam, Storage};
+use allocator_api2::alloc::Global;
 use core::{
     iter::{DoubleEndedIterator, ExactSizeIterator},
     marker::PhantomData,
 };
 
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub use crate::rayon::{IntoParIter, ParChunksMut, ParIter, ParIterMut};
+
 /// An iterator over immutable references to values in a [`ParallelSlice`].
 ///
 /// See [`ParallelSlice::iter`].
@@ -16,6 +22,10 @@ pub struct Iter<'a, Param: ParallelParam> {
     pub(crate) _marker: PhantomData<&'a Param>,
 }
 
+// SAFE: The iterator only gives out shared references to the elements, like a `slice::Iter`.
+unsafe impl<'a, Param: ParallelParam + Sync> Send for Iter<'a, Param> {}
+unsafe impl<'a, Param: ParallelParam + Sync> Sync for Iter<'a, Param> {}
+
 impl<'a, Param: ParallelParam> Iterator for Iter<'a, Param> {
     type Item = Param::Ref<'a>;
     fn next(&mut self) -> Option<Param::Ref<'a>> {
@@ -62,6 +72,10 @@ pub struct IterMut<'a, Param: ParallelParam> {
     pub(crate) _marker: PhantomData<&'a Param>,
 }
 
+// SAFE: The iterator uniquely borrows the elements, like a `slice::IterMut`.
+unsafe impl<'a, Param: ParallelParam + Send> Send for IterMut<'a, Param> {}
+unsafe impl<'a, Param: ParallelParam + Sync> Sync for IterMut<'a, Param> {}
+
 impl<'a, Param: ParallelParam> Iterator for IterMut<'a, Param> {
     type Item = Param::RefMut<'a>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -75,6 +89,10 @@ impl<'a, Param: ParallelParam> Iterator for IterMut<'a, Param> {
             Some(output)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
 }
 
 impl<'a, Param: ParallelParam> ExactSizeIterator for IterMut<'a, Param> {}
@@ -99,14 +117,20 @@ impl<'a, Param: ParallelParam> DoubleEndedIterator for IterMut<'a, Param> {
 /// [`ParallelVec`]: crate::ParallelVec
 /// [`ParallelVec::iter_mut`]: crate::ParallelVec::into_iter
 #[repr(C)]
-pub struct IntoIter<Param: ParallelParam> {
+pub struct IntoIter<Param: ParallelParam, A: Storage = Global> {
     pub(crate) len: usize,
     pub(crate) storage: Param::Storage,
     pub(crate) capacity: usize,
+    pub(crate) column_align: usize,
     pub(crate) idx: usize,
+    pub(crate) alloc: A,
 }
 
-impl<Param: ParallelParam> Iterator for IntoIter<Param> {
+// SAFE: The iterator owns the remaining elements, like a `vec::IntoIter`.
+unsafe impl<Param: ParallelParam + Send, A: Storage + Send> Send for IntoIter<Param, A> {}
+unsafe impl<Param: ParallelParam + Sync, A: Storage + Sync> Sync for IntoIter<Param, A> {}
+
+impl<Param: ParallelParam, A: Storage> Iterator for IntoIter<Param, A> {
     type Item = Param;
     fn next(&mut self) -> Option<Self::Item> {
         unsafe {
@@ -126,9 +150,9 @@ impl<Param: ParallelParam> Iterator for IntoIter<Param> {
     }
 }
 
-impl<Param: ParallelParam> ExactSizeIterator for IntoIter<Param> {}
+impl<Param: ParallelParam, A: Storage> ExactSizeIterator for IntoIter<Param, A> {}
 
-impl<Param: ParallelParam> DoubleEndedIterator for IntoIter<Param> {
+impl<Param: ParallelParam, A: Storage> DoubleEndedIterator for IntoIter<Param, A> {
     fn next_back(&mut self) -> Option<Param> {
         unsafe {
             if self.len == 0 {
@@ -141,14 +165,19 @@ impl<Param: ParallelParam> DoubleEndedIterator for IntoIter<Param> {
     }
 }
 
-impl<Param: ParallelParam> Drop for IntoIter<Param> {
+impl<Param: ParallelParam, A: Storage> Drop for IntoIter<Param, A> {
     fn drop(&mut self) {
         unsafe {
+            let _guard = DeallocOnDrop::<Param, A> {
+                storage: self.storage,
+                capacity: self.capacity,
+                column_align: self.column_align,
+                alloc: &self.alloc,
+            };
             // Drop the unconsumed items.
-            for idx in self.idx..self.len {
-                Param::drop(Param::ptr_at(self.storage, idx));
-            }
-            Param::dealloc(&mut self.storage, self.capacity);
+            let remaining = self.len - self.idx;
+            self.len = self.idx;
+            Param::drop_slice(Param::ptr_at(self.storage, self.idx), remaining);
         }
     }
 }
@@ -0,0 +1,431 @@
+use crate::{iter::Iter, DuplicateKeyError, ParallelColumn, ParallelSlice, ParallelVec};
+use core::{borrow::Borrow, hash::Hash};
+use std::collections::HashMap;
+
+/// A [`ParallelVec`] that keeps a hash index from the `N`th field of every element to
+/// its index, so elements can be looked up by key in constant time.
+///
+/// Keys are unique. The index is kept in sync by every operation that adds, removes or
+/// moves elements, and the key field can only be changed through [`update`], which
+/// re-indexes the element.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::KeyedParallelVec;
+///
+/// // Entities keyed by their id, in field 0.
+/// let mut entities = KeyedParallelVec::<_, 0>::new();
+/// entities.push((17u64, "player", 100u32)).unwrap();
+/// entities.push((42, "enemy", 30)).unwrap();
+/// assert!(entities.push((42, "duplicate", 0)).is_err());
+///
+/// assert_eq!(entities.get_by_key(&42), Some((&42, &"enemy", &30)));
+/// entities.remove_by_key(&17);
+/// assert_eq!(entities.index_of(&42), Some(0));
+/// ```
+///
+/// [`update`]: Self::update
+pub struct KeyedParallelVec<Param: ParallelColumn<N>, const N: usize> {
+    vec: ParallelVec<Param>,
+    index: HashMap<Param::Column, usize>,
+}
+
+impl<Param, const N: usize> KeyedParallelVec<Param, N>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Hash + Eq + Clone,
+{
+    /// Constructs a new, empty `KeyedParallelVec`.
+    pub fn new() -> Self {
+        Self {
+            vec: ParallelVec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Constructs a new, empty `KeyedParallelVec` with the capacity for `capacity`
+    /// elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: ParallelVec::with_capacity(capacity),
+            index: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of elements in the container.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if the container contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Appends an element to the back of the container, and returns its index.
+    ///
+    /// # Errors
+    /// Returns the element in a [`DuplicateKeyError`] if an element with the same key
+    /// is already in the container.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn push(&mut self, value: Param) -> Result<usize, DuplicateKeyError<Param>> {
+        let index = self.vec.len();
+        self.vec.push(value);
+        let key = key_at(&self.vec, index);
+        if self.index.contains_key(key) {
+            return match self.vec.pop() {
+                Some(value) => Err(DuplicateKeyError(value)),
+                None => unreachable!(),
+            };
+        }
+        self.index.insert(key.clone(), index);
+        Ok(index)
+    }
+
+    /// Removes the last element from the container and returns it, or `None` if it is
+    /// empty.
+    pub fn pop(&mut self) -> Option<Param> {
+        let last = self.vec.len().checked_sub(1)?;
+        self.index.remove(key_at(&self.vec, last));
+        self.vec.pop()
+    }
+
+    /// Removes the element at `index` and returns it, shifting all elements after it to
+    /// the left.
+    ///
+    /// Every element after it is re-indexed, so this takes linear time.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Param {
+        crate::assert_in_bounds(index, self.len());
+        self.index.remove(key_at(&self.vec, index));
+        let value = match self.vec.remove(index) {
+            Some(value) => value,
+            None => unreachable!(),
+        };
+        let keys = Param::column(self.vec.as_slices());
+        for (idx, key) in keys.iter().enumerate().skip(index) {
+            self.index.insert(key.clone(), idx);
+        }
+        value
+    }
+
+    /// Removes the element at `index` and returns it, moving the last element into its
+    /// place.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> Param {
+        crate::assert_in_bounds(index, self.len());
+        self.index.remove(key_at(&self.vec, index));
+        let last = self.vec.len() - 1;
+        if index != last {
+            if let Some(moved) = self.index.get_mut(key_at(&self.vec, last)) {
+                *moved = index;
+            }
+        }
+        self.vec.swap_remove(index)
+    }
+
+    /// Removes the element with the given key and returns it, or `None` if there is no
+    /// such element.
+    ///
+    /// The last element is moved into its place, as with [`swap_remove`], so this takes
+    /// constant time.
+    ///
+    /// [`swap_remove`]: Self::swap_remove
+    pub fn remove_by_key<Q>(&mut self, key: &Q) -> Option<Param>
+    where
+        Param::Column: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.index_of(key)?;
+        Some(self.swap_remove(index))
+    }
+
+    /// Shortens the container to `len` elements, dropping the rest.
+    pub fn truncate(&mut self, len: usize) {
+        let keys = Param::column(self.vec.as_slices());
+        for key in keys.iter().skip(len) {
+            self.index.remove(key);
+        }
+        self.vec.truncate(len);
+    }
+
+    /// Removes every element from the container.
+    pub fn clear(&mut self) {
+        self.index.clear();
+        self.vec.clear();
+    }
+
+    /// Returns the index of the element with the given key, or `None` if there is no
+    /// such element.
+    pub fn index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Param::Column: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.get(key).copied()
+    }
+
+    /// Returns `true` if the container contains an element with the given key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Param::Column: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.contains_key(key)
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Param::Ref<'_>> {
+        self.vec.get(index)
+    }
+
+    /// Returns a reference to the element with the given key, or `None` if there is no
+    /// such element.
+    pub fn get_by_key<Q>(&self, key: &Q) -> Option<Param::Ref<'_>>
+    where
+        Param::Column: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.vec.get(self.index_of(key)?)
+    }
+
+    /// Calls `f` with a mutable reference to the element at `index`, and returns its
+    /// result, or `None` if it is out of bounds.
+    ///
+    /// If `f` changes the key of the element, it is re-indexed, even if `f` panics.
+    ///
+    /// # Panics
+    /// Panics if `f` changes the key to that of another element. The key of the element
+    /// is restored before panicking.
+    pub fn update<R>(&mut self, index: usize, f: impl FnOnce(Param::RefMut<'_>) -> R) -> Option<R> {
+        let old = Param::column(self.vec.as_slices()).get(index)?.clone();
+        let mut guard = UpdateGuard {
+            keyed: self,
+            index,
+            old: Some(old),
+        };
+        let result = f(guard.keyed.vec.get_mut(index)?);
+        let duplicate = guard.reindex();
+        drop(guard);
+        assert!(!duplicate, "an element with the key already exists");
+        Some(result)
+    }
+
+    /// Gets the elements as a [`ParallelSlice`].
+    pub fn as_parallel_slice(&self) -> ParallelSlice<'_, Param> {
+        self.vec.as_parallel_slice()
+    }
+
+    /// Gets the fields of every element as slices.
+    pub fn as_slices(&self) -> Param::Slices<'_> {
+        self.vec.as_slices()
+    }
+
+    /// Returns an iterator over references to the elements.
+    pub fn iter(&self) -> Iter<'_, Param> {
+        self.vec.iter()
+    }
+
+    /// Converts the container into a [`ParallelVec`], discarding the index.
+    pub fn into_parallel_vec(self) -> ParallelVec<Param> {
+        self.vec
+    }
+}
+
+impl<Param, const N: usize> Default for KeyedParallelVec<Param, N>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Hash + Eq + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param, const N: usize> TryFrom<ParallelVec<Param>> for KeyedParallelVec<Param, N>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Hash + Eq + Clone,
+{
+    type Error = DuplicateKeyError<ParallelVec<Param>>;
+
+    /// Indexes the elements of `vec`, or returns it if any two elements have the same
+    /// key.
+    fn try_from(vec: ParallelVec<Param>) -> Result<Self, Self::Error> {
+        let mut index = HashMap::with_capacity(vec.len());
+        for (idx, key) in Param::column(vec.as_slices()).iter().enumerate() {
+            if index.insert(key.clone(), idx).is_some() {
+                return Err(DuplicateKeyError(vec));
+            }
+        }
+        Ok(Self { vec, index })
+    }
+}
+
+/// Re-indexes an element when dropped, so that the index stays in sync with its key
+/// even if the function updating it panics.
+struct UpdateGuard<'a, Param, const N: usize>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Hash + Eq + Clone,
+{
+    keyed: &'a mut KeyedParallelVec<Param, N>,
+    index: usize,
+    /// The key of the element before it was updated, or `None` once it is re-indexed.
+    old: Option<Param::Column>,
+}
+
+impl<'a, Param, const N: usize> UpdateGuard<'a, Param, N>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Hash + Eq + Clone,
+{
+    /// Moves the element to its new key in the index. If another element already has
+    /// that key, restores the old key instead and returns `true`.
+    fn reindex(&mut self) -> bool {
+        let Some(old) = self.old.take() else {
+            return false;
+        };
+        let keyed = &mut *self.keyed;
+        let new = key_at(&keyed.vec, self.index);
+        if *new == old {
+            return false;
+        }
+        if keyed.index.contains_key(new) {
+            // SAFE: The element is in bounds, and no references to it are alive.
+            unsafe {
+                *Param::column_storage(keyed.vec.storage)
+                    .as_ptr()
+                    .add(self.index) = old
+            };
+            return true;
+        }
+        keyed.index.remove(&old);
+        keyed.index.insert(new.clone(), self.index);
+        false
+    }
+}
+
+impl<'a, Param, const N: usize> Drop for UpdateGuard<'a, Param, N>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Hash + Eq + Clone,
+{
+    fn drop(&mut self) {
+        self.reindex();
+    }
+}
+
+/// Gets the key of the element at `index`.
+///
+/// # Panics
+/// Panics if `index` is out of bounds.
+fn key_at<Param: ParallelColumn<N>, const N: usize>(
+    vec: &ParallelVec<Param>,
+    index: usize,
+) -> &Param::Column {
+    &Param::column(vec.as_slices())[index]
+}
+
+#[cfg(test)]
+mod test {
+    use super::KeyedParallelVec;
+    use crate::{DuplicateKeyError, ParallelVec};
+    use alloc::string::String;
+
+    fn entities() -> KeyedParallelVec<(String, u32), 0> {
+        let mut vec = KeyedParallelVec::new();
+        for (idx, name) in ["a", "b", "c", "d"].into_iter().enumerate() {
+            assert_eq!(vec.push((String::from(name), idx as u32)), Ok(idx));
+        }
+        vec
+    }
+
+    #[test]
+    fn test_lookup() {
+        let mut vec = entities();
+        assert_eq!(
+            vec.push((String::from("b"), 9)),
+            Err(DuplicateKeyError((String::from("b"), 9)))
+        );
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec.get_by_key("c").map(|(_, value)| *value), Some(2));
+        assert!(vec.contains_key("d"));
+        assert!(!vec.contains_key("e"));
+
+        assert_eq!(vec.remove_by_key("a"), Some((String::from("a"), 0)));
+        assert_eq!(vec.remove_by_key("a"), None);
+        assert_eq!(vec.index_of("d"), Some(0));
+        assert_eq!(vec.remove(0).1, 3);
+        assert_eq!(vec.index_of("c"), Some(1));
+        assert_eq!(vec.pop().unwrap().1, 2);
+        assert_eq!(vec.index_of("c"), None);
+        assert_eq!(vec.get_by_key("b").map(|(_, value)| *value), Some(1));
+        vec.truncate(0);
+        assert!(!vec.contains_key("b"));
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn test_update() {
+        let mut vec = entities();
+        assert_eq!(vec.update(1, |(_, value)| *value += 10), Some(()));
+        assert_eq!(vec.update(4, |_| ()), None);
+        vec.update(1, |(key, _)| key.push('!'));
+        assert_eq!(vec.get_by_key("b!").map(|(_, value)| *value), Some(11));
+        assert_eq!(vec.index_of("b"), None);
+        assert_eq!(vec.push((String::from("b"), 1)), Ok(4));
+    }
+
+    #[test]
+    fn test_update_duplicate() {
+        let mut vec = entities();
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            vec.update(0, |(key, _)| *key = String::from("c"));
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec.as_slices().0[0], "a");
+        assert_eq!(vec.index_of("a"), Some(0));
+        assert_eq!(vec.index_of("c"), Some(2));
+    }
+
+    #[test]
+    fn test_update_panics() {
+        let mut vec = entities();
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            vec.update(1, |(key, _)| {
+                *key = String::from("e");
+                panic!("update failed");
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec.index_of("e"), Some(1));
+        assert_eq!(vec.index_of("b"), None);
+
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            vec.update(1, |(key, _)| {
+                *key = String::from("a");
+                panic!("update failed");
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec.as_slices().0[1], "e");
+        assert_eq!(vec.index_of("e"), Some(1));
+        assert_eq!(vec.index_of("a"), Some(0));
+    }
+
+    #[test]
+    fn test_try_from() {
+        let vec = ParallelVec::from(alloc::vec![(1u8, 'a'), (2, 'b')]);
+        let keyed = KeyedParallelVec::<_, 1>::try_from(vec).unwrap();
+        assert_eq!(keyed.get_by_key(&'b'), Some((&2, &'b')));
+        let vec = ParallelVec::from(alloc::vec![(1u8, 'a'), (1, 'b')]);
+        assert!(KeyedParallelVec::<_, 0>::try_from(vec).is_err());
+    }
+}
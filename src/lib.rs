@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 #![deny(missing_docs)]
-#![feature(generic_associated_types)]
-#![feature(doc_cfg)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
 #![no_std]
 
 //! [`ParallelVec`] is a generic collection of contiguously stored heterogenous values with
@@ -45,42 +45,398 @@
 //! entities.swap_remove(0);
 //! ```
 //!
-//! ## Nightly
-//! This crate requires use of GATs and therefore requires the following nightly features:
-//! * `generic_associated_types`
+//! ## Minimum Supported Rust Version
+//! This crate requires use of GATs and therefore requires Rust 1.65 or newer.
 //!
 //! ## `no_std` Support
 //! By default, this crate requires the standard library. Disabling the default features
 //! enables this crate to compile in `#![no_std]` environments. There must be a set global
-//! allocator and heap support for this crate to work.
+//! allocator and heap support for this crate to work. `LockedParallelVec` and
+//! `KeyedParallelVec` are only available with the `std` feature, as they use the locks and
+//! the `HashMap` of the standard library.
+//!
+//! ## Custom Allocators
+//! `ParallelVec` can allocate its buffer from any [`Allocator`] via [`ParallelVec::new_in`]
+//! and [`ParallelVec::with_capacity_in`]. As the `Allocator` trait is not yet stable, this
+//! crate uses the [`allocator-api2`](https://crates.io/crates/allocator-api2) polyfill,
+//! which is re-exported here.
+//!
+//! More generally, the buffer is allocated, resized, and freed through the [`Storage`]
+//! trait, which every `Allocator` implements. It is the only part of `ParallelVec` that
+//! touches the backing memory directly, so it is also the extension point for
+//! alternative backing stores: [`FixedStorage`] hands out a fixed buffer that the vector
+//! does not own, and a memory map or a foreign buffer can be used the same way without
+//! any other changes.
+//!
+//! This also allows sharing a `ParallelVec` between processes: the writer allocates from
+//! a storage backed by a shared memory segment, such as `ShmStorage` with the `shm`
+//! feature, and readers view the same segment with [`ParallelSlice::from_bytes`], given
+//! the capacity and length of the writer. The placement of each field for a given
+//! capacity is described by [`ParallelParam::layout_for_capacity`]. Synchronizing access
+//! between the writer and the readers is left to the user.
+//!
+//! ## Dynamic Columns
+//! When the set of columns is only known at runtime, such as for the archetypes of an
+//! Entity-Component-System, [`DynParallelVec`] describes each column with a [`DynColumn`]
+//! holding the layout of its values and how to drop them. It shares the single buffer,
+//! growth policies, and allocators of `ParallelVec`, and moves values in and out through
+//! raw pointers.
+//!
+//! ## `arbitrary` Support
+//! With the `arbitrary` feature enabled, `ParallelVec` implements `Arbitrary` from the
+//! [`arbitrary`](https://crates.io/crates/arbitrary) crate, generating the same elements as
+//! the equivalent `Vec` would. This allows fuzz targets to take a `ParallelVec` as input
+//! directly. This is disabled by default.
+//!
+//! ## Apache Arrow Support
+//! With the `arrow` feature enabled, a `ParallelVec` of primitive numeric fields can be
+//! converted to and from an Arrow `RecordBatch`, with one `PrimitiveArray` per field.
+//! `ParallelVec::into_record_batch` shares the buffer of the vector with the arrays
+//! without copying any values. This requires Rust 1.85 or newer. This is disabled by
+//! default.
+//!
+//! ## `bumpalo` Support
+//! With the `bumpalo` feature enabled, a `ParallelVec` can be allocated from a
+//! `bumpalo::Bump` arena via `BumpParallelVec`. This is disabled by default.
+//!
+//! ## `bytemuck` Support
+//! With the `bytemuck` feature enabled, the fields of a `ParallelVec` of
+//! [`bytemuck`](https://crates.io/crates/bytemuck) `Pod` types can be viewed as bytes via
+//! `as_bytes` and `as_bytes_mut`, and the entire buffer via `allocation_bytes` and
+//! `allocation_bytes_mut`. This is useful for hashing, copying the buffer to a file or
+//! socket, or uploading it to a GPU. This is disabled by default.
+//!
+//! ## `csv` Support
+//! With the `csv` feature enabled, `ParallelVec::to_csv_writer` writes each element as a
+//! [`csv`](https://crates.io/crates/csv) record with one CSV field per field, using `serde`,
+//! and `ParallelVec::from_csv_reader` reads the records back. Column names can be provided
+//! with `ParallelVec::to_csv_writer_with_headers`. This implies the `std` and `serde`
+//! features, and requires Rust 1.73 or newer. This is disabled by default.
+//!
+//! ## `derive` Support
+//! With the `derive` feature enabled, `#[derive(ParallelVecParam)]` allows a struct to be
+//! stored in a `ParallelVec` directly, with one column per field, instead of restructuring
+//! it into a tuple. The `#[soa]` attribute goes further and generates a dedicated container
+//! for the struct, with methods named after its fields. This is disabled by default.
+//!
+//! ## `debug-validate`
+//! With the `debug-validate` feature enabled, debug builds check the internal invariants of
+//! every `ParallelVec` after it is mutated, and overwrite the memory of removed elements with
+//! a poison byte pattern. This helps catch misuse of the `unsafe` API, such as reading
+//! elements past the length of the vector, early. This is disabled by default.
+//!
+//! ## `ndarray` Support
+//! With the `ndarray` feature enabled, every field of a `ParallelVec`, `ParallelSlice`, or
+//! `ParallelSliceMut` can be viewed as a one dimensional [`ndarray`](https://crates.io/crates/ndarray)
+//! array via `as_array_views` and `as_array_views_mut`, so existing ndarray kernels can
+//! operate on the columns without copying them. This is disabled by default.
+//!
+//! ## `portable_simd` Support
+//! With the `portable_simd` feature enabled, every field of a `ParallelSlice` or
+//! `ParallelSliceMut` can be split into an unaligned prefix, a middle of
+//! `core::simd::Simd` vectors, and an unaligned suffix via `as_simd` and `as_simd_mut`,
+//! so SIMD kernels can be written directly over the columns. As `core::simd` is not yet
+//! stable, this requires a nightly compiler. This is disabled by default.
+//!
+//! ## `proptest` Support
+//! With the `proptest` feature enabled, `ParallelVec` implements `Arbitrary` from the
+//! [`proptest`](https://crates.io/crates/proptest) crate, and `parallel_vec` creates a
+//! strategy from a strategy for its elements and a size range, like
+//! `proptest::collection::vec`. This is disabled by default.
+//!
+//! ## `quickcheck` Support
+//! With the `quickcheck` feature enabled, `ParallelVec` implements `Arbitrary` from the
+//! [`quickcheck`](https://crates.io/crates/quickcheck) crate. Failing inputs are shrunk by
+//! first removing rows, then by shrinking the fields of the remaining rows. This is disabled
+//! by default.
+//!
+//! ## `rayon` Support
+//! With the `rayon` feature enabled, `ParallelVec`, `ParallelSlice`, and `ParallelSliceMut`
+//! implement [`rayon`](https://crates.io/crates/rayon)'s `IntoParallelIterator`, so
+//! `par_iter`, `par_iter_mut`, and `into_par_iter` can be used to process the elements
+//! on multiple threads. Parallel iterators can also be collected into a `ParallelVec`, or
+//! used to extend one with `par_extend`. `par_chunks_mut` splits the fields into disjoint
+//! mutable chunks for data-parallel updates, and `par_apply_columns` runs a separate closure
+//! over each field concurrently. `ParallelSliceMut` also gains `par_sort_by`,
+//! `par_sort_by_key`, `par_sort_unstable_by`, and `par_sort_unstable_by_key`, which sort
+//! the elements on multiple threads, keeping the fields of every element together. This
+//! implies the `std` feature, and requires Rust 1.80 or newer. This is disabled by default.
+//!
+//! ## `rkyv` Support
+//! With the `rkyv` feature enabled, `ParallelVec` can be archived with
+//! [`rkyv`](https://crates.io/crates/rkyv) for zero-copy deserialization. The archived form,
+//! `ArchivedParallelVec`, keeps the columnar layout, so a validated archive can be read in
+//! place, for example from a memory mapped file, without copying any data. This is supported
+//! for tuples of up to 13 fields, and requires Rust 1.81 or newer. This is disabled by default.
 //!
 //!  ## `serde` Support
 //! `ParallelVec` can be serialized if it's parameters can be serialized. This is disabled by
 //! default. Use the `serde` feature to enable support for serialization and deserialization.
+//!
+//! A `ParallelVec<(A, B)>` is serialized as a sequence of rows, exactly like a `Vec<(A, B)>`,
+//! so the two can be used interchangeably in serialized data. To serialize it column by
+//! column instead, like a `(Vec<A>, Vec<B>)`, wrap it in `Columnar`.
+//!
+//! ## `shm` Support
+//! With the `shm` feature enabled on Unix platforms, `ShmStorage` allocates the buffer of a
+//! `ParallelVec` from a named POSIX shared memory segment, so one writer process can share
+//! a large table with many reader processes. `ParallelVec::publish` makes the current
+//! length visible and returns a `ShmHandle` describing the vector, which readers open with
+//! `ShmReader` to view the published elements as a `ParallelSlice` without copying them.
+//! This implies the `std` feature. This is disabled by default.
+//!
+//! ## `wgpu` Support
+//! With the `wgpu` feature enabled, `ParallelVec::gpu_columns` describes where each `Pod`
+//! field lives within a GPU buffer holding the entire buffer of the vector, as laid out by
+//! `ParallelVec::memory_layout`. The whole vector can be uploaded into a single GPU
+//! buffer, and each field bound as its own vertex buffer stream. This implies the
+//! `bytemuck` feature, and requires Rust 1.87 or newer. This is disabled by default.
+//!
+//! ## `zerocopy` Support
+//! With the `zerocopy` feature enabled, the fields of a `ParallelVec` can be viewed as bytes
+//! via `as_column_bytes` and `as_column_bytes_mut` when they implement the corresponding
+//! [`zerocopy`](https://crates.io/crates/zerocopy) traits, and `ParallelVec::from_column_bytes`
+//! copies a vector out of one byte buffer per field, checking that the buffers hold the same
+//! number of whole elements. This is disabled by default.
 
 extern crate alloc;
 
+use alloc::alloc::{handle_alloc_error, Layout};
+
 #[cfg(any(test, feature = "std"))]
 #[macro_use]
 extern crate std;
+#[cfg(all(test, feature = "derive"))]
+extern crate self as parallel_vec;
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+mod arbitrary;
+mod array;
+#[cfg(feature = "arrow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+mod arrow;
+#[cfg(feature = "bumpalo")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bumpalo")))]
+mod bump;
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+mod bytemuck;
+mod chunked;
+mod concurrent;
+#[cfg(feature = "csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+mod csv;
+mod deque;
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+mod derive;
+mod dynamic;
+mod gather;
+/// Strategies for growing the capacity of a [`ParallelVec`].
+pub mod growth;
+mod index;
 /// A collection of iterators types for [`ParallelVec`].
 pub mod iter;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod keyed;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod locked;
+mod macros;
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+mod ndarray;
+mod ordered;
 /// Implementations for [`ParallelParam`].
 pub mod param;
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+mod proptest;
+#[cfg(feature = "quickcheck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "quickcheck")))]
+mod quickcheck;
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+mod rayon;
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+mod rkyv;
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 mod serde;
+mod shared;
+#[cfg(all(feature = "shm", unix))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "shm", unix))))]
+mod shm;
+#[cfg(feature = "portable_simd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "portable_simd")))]
+mod simd;
 mod slice;
+mod slotmap;
+mod small;
+mod storage;
+mod table;
+mod tracked;
 mod vec;
+#[cfg(feature = "wgpu")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wgpu")))]
+mod wgpu;
+#[cfg(feature = "zerocopy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zerocopy")))]
+mod zerocopy;
+
+#[cfg(feature = "arrow")]
+pub use crate::arrow::ArrowColumn;
+#[cfg(feature = "bumpalo")]
+pub use crate::bump::*;
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub use crate::derive::__private;
+/// Derives [`ParallelParam`] for a struct, so that it can be stored in a [`ParallelVec`]
+/// with one field per column, like the equivalent tuple of its fields.
+///
+/// References, slices, and iterators of the vector use the tuples of the fields, in the
+/// order they are declared. [`ParallelColumn`] is also implemented for every field, so
+/// fields can be selected by index, e.g. with [`ParallelSliceMut::sort_by_column`].
+///
+/// The struct must have between 1 and 16 fields, cannot be `#[repr(packed)]`, and cannot
+/// implement [`Drop`], as its fields are stored and dropped separately.
+///
+/// ```rust
+/// use parallel_vec::{ParallelVec, ParallelVecParam};
+///
+/// #[derive(ParallelVecParam)]
+/// struct Particle {
+///     pos: [f32; 3],
+///     vel: [f32; 3],
+///     ttl: f32,
+/// }
+///
+/// let mut particles: ParallelVec<Particle> = ParallelVec::new();
+/// particles.push(Particle { pos: [0.0; 3], vel: [1.0, 0.0, 0.0], ttl: 2.0 });
+///
+/// for (pos, vel, _) in particles.iter_mut() {
+///     pos[0] += vel[0];
+/// }
+/// let (pos, _, ttl) = particles.as_slices();
+/// assert_eq!(pos, &[[1.0, 0.0, 0.0]]);
+/// assert_eq!(ttl, &[2.0]);
+/// ```
+#[cfg(feature = "derive")]
+pub use parallel_vec_derive::ParallelVecParam;
+
+/// Generates a dedicated container for a struct with named fields, with methods named
+/// after its fields.
+///
+/// For a struct `Particle`, this derives [`ParallelVecParam`] and generates:
+///
+///  - `ParticleVec`, a wrapper around a `ParallelVec<Particle>` with the common methods
+///    of a `Vec`, and a `field()` and `field_mut()` method returning the slice of each
+///    field. The underlying [`ParallelVec`] is available via `as_parallel_vec`.
+///  - `ParticleRef` and `ParticleRefMut`, structs of references to the fields of an
+///    element, returned by `get`, `get_mut`, and the iterators of the vector.
+///  - `ParticleIter` and `ParticleIterMut`, the iterators returned by `iter` and
+///    `iter_mut`.
+///
+/// The generated items have the same visibility as the struct. A field cannot share its
+/// name with one of the methods of the vector, such as `len`.
+///
+/// ```rust
+/// use parallel_vec::soa;
+///
+/// #[soa]
+/// struct Particle {
+///     pos: [f32; 3],
+///     vel: [f32; 3],
+///     ttl: f32,
+/// }
+///
+/// let mut particles = ParticleVec::new();
+/// particles.push(Particle { pos: [0.0; 3], vel: [1.0, 0.0, 0.0], ttl: 2.0 });
+///
+/// for particle in particles.iter_mut() {
+///     particle.pos[0] += particle.vel[0];
+///     *particle.ttl -= 1.0;
+/// }
+/// assert_eq!(particles.pos(), &[[1.0, 0.0, 0.0]]);
+/// assert_eq!(*particles.get(0).unwrap().ttl, 1.0);
+/// ```
+#[cfg(feature = "derive")]
+pub use parallel_vec_derive::soa;
 
+#[cfg(feature = "std")]
+pub use crate::keyed::KeyedParallelVec;
+#[cfg(feature = "std")]
+pub use crate::locked::{LockedParallelVec, ParallelReadGuard, ParallelWriteGuard};
+#[cfg(feature = "proptest")]
+pub use crate::proptest::{parallel_vec, ParallelVecStrategy, ParallelVecValueTree};
+#[cfg(feature = "rkyv")]
+pub use crate::rkyv::{ArchivedColumns, ArchivedParallelVec};
 #[cfg(feature = "serde")]
-pub use crate::serde::*;
+pub use crate::serde::Columnar;
+#[cfg(all(feature = "shm", unix))]
+pub use crate::shm::{ShmHandle, ShmReader, ShmStorage};
+#[cfg(feature = "wgpu")]
+pub use crate::wgpu::GpuColumn;
 
-pub use param::ParallelParam;
+pub use allocator_api2::alloc::{AllocError, Allocator, Global};
+pub use array::ArrayParallelVec;
+pub use chunked::{ChunkedIter, ChunkedIterMut, ChunkedParallelVec};
+pub use concurrent::ConcurrentParallelVec;
+pub use deque::ParallelVecDeque;
+pub use dynamic::{DynColumn, DynParallelVec};
+pub use growth::GrowthPolicy;
+pub use index::ParallelIndex;
+pub use ordered::{OrderedIter, OrderedParallelVec};
+pub use param::{Column, ParallelColumn, ParallelParam, ParallelSelection};
+pub use shared::SharedParallelVec;
 pub use slice::{ParallelSlice, ParallelSliceMut};
+pub use slotmap::{ParallelKey, ParallelSlotMap};
+pub use small::SmallParallelVec;
+pub use storage::{FixedStorage, Storage};
+pub use table::Table;
+pub use tracked::{ChangedSince, TrackedParallelVec};
 pub use vec::ParallelVec;
 
+/// The size in bytes of a cache line on the target architecture.
+///
+/// On x86_64, aarch64, and powerpc64, this is 128 bytes, as their prefetchers
+/// may fetch pairs of 64 byte cache lines at once. Pass this to
+/// [`ParallelVec::set_column_alignment`] to avoid false sharing between threads
+/// writing to different fields of the same vector.
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "powerpc64"
+))]
+pub const CACHE_LINE_SIZE: usize = 128;
+
+/// The size in bytes of a cache line on the target architecture.
+///
+/// Pass this to [`ParallelVec::set_column_alignment`] to avoid false sharing
+/// between threads writing to different fields of the same vector.
+#[cfg(not(any(
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "powerpc64"
+)))]
+pub const CACHE_LINE_SIZE: usize = 64;
+
+/// Error when attempting to allocate memory for a [`ParallelVec`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum TryReserveError {
+    /// The computed capacity exceeded the maximum supported size for the
+    /// collection (usually `isize::MAX` bytes).
+    CapacityOverflow,
+    /// The memory allocator returned an error.
+    AllocError {
+        /// The layout of the allocation request that failed.
+        layout: Layout,
+    },
+}
+
 /// Error when attempting to convert types to [`ParallelVec`].
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub enum ParallelVecConversionError {
@@ -88,17 +444,137 @@ pub enum ParallelVecConversionError {
     UnevenLengths,
 }
 
+/// Error when attempting to build a [`ParallelVec`] from a byte buffer per field, with
+/// [`ParallelVec::from_column_bytes`].
+#[cfg(feature = "zerocopy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zerocopy")))]
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum ParallelVecFromBytesError {
+    /// The provided buffers did not hold the same number of elements.
+    UnevenLengths,
+    /// The length of a provided buffer was not a multiple of the size of its field.
+    IncompleteElement,
+}
+
+/// Error when attempting to view a byte buffer as a [`ParallelSlice`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum ParallelSliceFromBytesError {
+    /// The provided length was greater than the provided capacity.
+    LengthExceedsCapacity,
+    /// The size of the layout for the provided capacity overflowed.
+    CapacityOverflow,
+    /// The buffer was smaller than the layout for the provided capacity.
+    BufferTooSmall {
+        /// The minimum size of the buffer, in bytes.
+        required: usize,
+    },
+    /// The buffer was not aligned to the alignment of the layout.
+    Misaligned {
+        /// The required alignment of the buffer, in bytes.
+        align: usize,
+    },
+}
+
+/// Error when pushing an element onto a full [`ArrayParallelVec`].
+///
+/// Holds the element that could not be pushed.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct CapacityError<Param>(pub Param);
+
+/// Error when adding an element to a [`KeyedParallelVec`] that already contains an
+/// element with the same key.
+///
+/// Holds the value that could not be added.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct DuplicateKeyError<T>(pub T);
+
 #[inline(always)]
 pub(crate) fn assert_in_bounds(idx: usize, len: usize) {
     assert!(idx < len, "Index out of bounds: {} (len: {})", idx, len);
 }
 
+/// Resolves `range` to the indices it covers in a slice of length `len`.
+pub(crate) fn slice_range<R: core::ops::RangeBounds<usize>>(
+    range: R,
+    len: usize,
+) -> core::ops::Range<usize> {
+    use core::ops::Bound;
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start
+            .checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end
+            .checked_add(1)
+            .expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end,
+        "slice index starts at {} but ends at {}",
+        start,
+        end
+    );
+    assert!(
+        end <= len,
+        "range end index {} out of range for slice of length {}",
+        end,
+        len
+    );
+    start..end
+}
+
+/// Resolves `range` to the indices it covers in a slice of length `len`, or returns
+/// `None` if it is reversed or out of bounds.
+pub(crate) fn try_slice_range<R: core::ops::RangeBounds<usize>>(
+    range: R,
+    len: usize,
+) -> Option<core::ops::Range<usize>> {
+    use core::ops::Bound;
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end.checked_add(1)?,
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => len,
+    };
+    (start <= end && end <= len).then_some(start..end)
+}
+
 #[inline(always)]
-pub(crate) fn assert_in_bounds_inclusive(idx: usize, len: usize) {
-    assert!(idx <= len, "Index out of bounds: {} (len: {})", idx, len);
+pub(crate) fn assert_bitmask_len(bitmask: &[u64], len: usize) {
+    assert!(
+        bitmask.len().saturating_mul(64) >= len,
+        "bitmask has fewer bits than elements: {} < {}",
+        bitmask.len().saturating_mul(64),
+        len
+    );
+}
+
+/// Gets the bit for `idx` in a packed bitmask, least significant bit first.
+#[inline(always)]
+pub(crate) fn bitmask_get(bitmask: &[u64], idx: usize) -> bool {
+    (bitmask[idx / 64] >> (idx % 64)) & 1 != 0
 }
 
 #[inline(always)]
 pub(crate) fn out_of_bounds(idx: usize, len: usize) {
     panic!("Index out of bounds: {} (len: {})", idx, len);
 }
+
+#[cold]
+pub(crate) fn handle_reserve_error(err: TryReserveError) -> ! {
+    match err {
+        TryReserveError::CapacityOverflow => panic!("capacity overflow"),
+        TryReserveError::AllocError { layout } => handle_alloc_error(layout),
+    }
+}
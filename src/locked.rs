@@ -0,0 +1,276 @@
+use crate::{
+    param::indices_are_disjoint, ParallelParam, ParallelSelection, ParallelSlice, ParallelSliceMut,
+    ParallelVec,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::marker::PhantomData;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// A [`ParallelVec`] that guards each field with its own [`RwLock`], so that threads
+/// can lock only the fields they use.
+///
+/// Fields are locked with [`read`] and [`write`], selecting any set of them with a
+/// [`ParallelSelection`] of [`Column`] markers. Threads that lock different fields
+/// never wait for each other, even when both write. Sets of fields are always locked in
+/// ascending order of their indices, so locking several fields can not deadlock with
+/// another thread locking an overlapping set.
+///
+/// Changing the number of elements requires exclusive access to the container, which
+/// guarantees that no field is locked.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::{Column, LockedParallelVec, ParallelVec};
+///
+/// let particles = LockedParallelVec::from(ParallelVec::from(vec![
+///     (0.0f32, 1.0f32, 10u32),
+///     (5.0, -1.0, 20),
+/// ]));
+/// std::thread::scope(|scope| {
+///     // Physics only touches the positions and velocities...
+///     scope.spawn(|| {
+///         let mut motion = particles.write::<(Column<0>, Column<1>)>();
+///         for (position, velocity) in motion.as_parallel_slice_mut().iter_mut() {
+///             *position += *velocity;
+///         }
+///     });
+///     // ...so it does not block systems that only touch the health.
+///     scope.spawn(|| {
+///         let mut health = particles.write::<(Column<2>,)>();
+///         health.as_slices_mut().0[1] -= 5;
+///     });
+/// });
+/// assert_eq!(particles.read::<(Column<0>,)>().as_slices().0, &[1.0, 4.0]);
+/// assert_eq!(particles.read::<(Column<2>,)>().as_slices().0, &[10, 15]);
+/// ```
+///
+/// [`read`]: Self::read
+/// [`write`]: Self::write
+/// [`Column`]: crate::Column
+pub struct LockedParallelVec<Param: ParallelParam> {
+    vec: ParallelVec<Param>,
+    /// One lock for every field of `Param`.
+    locks: Box<[RwLock<()>]>,
+}
+
+// SAFE: Shared references move no elements between threads, and give out shared
+// references to fields under a read lock, and mutable references to fields under a
+// write lock.
+unsafe impl<Param: ParallelParam + Send + Sync> Sync for LockedParallelVec<Param> {}
+
+impl<Param: ParallelParam> LockedParallelVec<Param> {
+    /// Constructs a new, empty `LockedParallelVec`.
+    pub fn new() -> Self {
+        Self::from(ParallelVec::new())
+    }
+
+    /// Returns the number of elements in the container.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if the container contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Locks the fields selected by `S` for reading, blocking the current thread until
+    /// none of them is locked for writing.
+    ///
+    /// # Panics
+    /// Panics if any of the locks is poisoned by a thread that panicked while holding
+    /// it for writing.
+    pub fn read<S: ParallelSelection<Param>>(&self) -> ParallelReadGuard<'_, Param, S> {
+        let guards = lock_order(S::INDICES)
+            .into_iter()
+            .map(|idx| self.locks[idx].read().expect("a field lock was poisoned"))
+            .collect();
+        ParallelReadGuard {
+            storage: S::select(self.vec.storage),
+            len: self.vec.len,
+            _guards: guards,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Locks the fields selected by `S` for writing, blocking the current thread until
+    /// none of them is locked.
+    ///
+    /// # Panics
+    /// Panics if a field is selected more than once, or if any of the locks is poisoned
+    /// by a thread that panicked while holding it for writing.
+    pub fn write<S: ParallelSelection<Param>>(&self) -> ParallelWriteGuard<'_, Param, S> {
+        assert!(
+            indices_are_disjoint(S::INDICES, &[]),
+            "a field was selected more than once"
+        );
+        let guards = lock_order(S::INDICES)
+            .into_iter()
+            .map(|idx| self.locks[idx].write().expect("a field lock was poisoned"))
+            .collect();
+        ParallelWriteGuard {
+            storage: S::select(self.vec.storage),
+            len: self.vec.len,
+            _guards: guards,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets mutable access to the underlying [`ParallelVec`], to add or remove elements.
+    ///
+    /// No locking is needed, as no field can be locked while the container is borrowed
+    /// mutably.
+    pub fn get_mut(&mut self) -> &mut ParallelVec<Param> {
+        &mut self.vec
+    }
+
+    /// Converts the container into the underlying [`ParallelVec`].
+    pub fn into_inner(self) -> ParallelVec<Param> {
+        self.vec
+    }
+}
+
+impl<Param: ParallelParam> Default for LockedParallelVec<Param> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param: ParallelParam> From<ParallelVec<Param>> for LockedParallelVec<Param> {
+    fn from(vec: ParallelVec<Param>) -> Self {
+        let fields = Param::layout_for_capacity(0).offsets.as_ref().len();
+        Self {
+            vec,
+            locks: (0..fields).map(|_| RwLock::new(())).collect(),
+        }
+    }
+}
+
+/// Sorts and deduplicates the indices of the selected fields, in the order their locks
+/// must be taken.
+fn lock_order(indices: &[usize]) -> Vec<usize> {
+    let mut indices = indices.to_vec();
+    indices.sort_unstable();
+    indices.dedup();
+    indices
+}
+
+/// A read lock on a set of fields of a [`LockedParallelVec`], created by
+/// [`LockedParallelVec::read`]. The fields are unlocked when the guard is dropped.
+pub struct ParallelReadGuard<'a, Param: ParallelParam, S: ParallelSelection<Param>> {
+    storage: <S::Output as ParallelParam>::Storage,
+    len: usize,
+    _guards: Vec<RwLockReadGuard<'a, ()>>,
+    _marker: PhantomData<&'a Param>,
+}
+
+impl<'a, Param: ParallelParam, S: ParallelSelection<Param>> ParallelReadGuard<'a, Param, S> {
+    /// Gets the locked fields as a [`ParallelSlice`].
+    pub fn as_parallel_slice(&self) -> ParallelSlice<'_, S::Output> {
+        // SAFE: The fields are locked for reading while `self` is borrowed.
+        unsafe { ParallelSlice::from_raw_parts(self.storage, self.len) }
+    }
+
+    /// Gets the locked fields as slices.
+    pub fn as_slices(&self) -> <S::Output as ParallelParam>::Slices<'_> {
+        // SAFE: The fields are locked for reading while `self` is borrowed.
+        unsafe { S::Output::as_slices(S::Output::as_ptr(self.storage), self.len) }
+    }
+}
+
+/// A write lock on a set of fields of a [`LockedParallelVec`], created by
+/// [`LockedParallelVec::write`]. The fields are unlocked when the guard is dropped.
+pub struct ParallelWriteGuard<'a, Param: ParallelParam, S: ParallelSelection<Param>> {
+    storage: <S::Output as ParallelParam>::Storage,
+    len: usize,
+    _guards: Vec<RwLockWriteGuard<'a, ()>>,
+    _marker: PhantomData<&'a Param>,
+}
+
+impl<'a, Param: ParallelParam, S: ParallelSelection<Param>> ParallelWriteGuard<'a, Param, S> {
+    /// Gets the locked fields as a [`ParallelSlice`].
+    pub fn as_parallel_slice(&self) -> ParallelSlice<'_, S::Output> {
+        // SAFE: The fields are locked for writing while `self` is borrowed.
+        unsafe { ParallelSlice::from_raw_parts(self.storage, self.len) }
+    }
+
+    /// Gets the locked fields as a [`ParallelSliceMut`].
+    pub fn as_parallel_slice_mut(&mut self) -> ParallelSliceMut<'_, S::Output> {
+        // SAFE: The fields are locked for writing, and are distinct, while `self` is
+        // borrowed mutably.
+        unsafe { ParallelSliceMut::from_raw_parts(self.storage, self.len) }
+    }
+
+    /// Gets the locked fields as slices.
+    pub fn as_slices(&self) -> <S::Output as ParallelParam>::Slices<'_> {
+        // SAFE: The fields are locked for writing while `self` is borrowed.
+        unsafe { S::Output::as_slices(S::Output::as_ptr(self.storage), self.len) }
+    }
+
+    /// Gets the locked fields as mutable slices.
+    pub fn as_slices_mut(&mut self) -> <S::Output as ParallelParam>::SlicesMut<'_> {
+        // SAFE: The fields are locked for writing, and are distinct, while `self` is
+        // borrowed mutably.
+        unsafe { S::Output::as_slices_mut(S::Output::as_ptr(self.storage), self.len) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LockedParallelVec;
+    use crate::{Column, ParallelVec};
+    use alloc::string::String;
+
+    #[test]
+    fn test_disjoint_writes() {
+        let vec = LockedParallelVec::from(ParallelVec::from(vec![
+            (1u32, String::from("a"), 0.5f32),
+            (2, String::from("b"), 1.5),
+        ]));
+        let mut names = vec.write::<(Column<1>,)>();
+        let mut numbers = vec.write::<(Column<2>, Column<0>)>();
+        names.as_slices_mut().0[0].push('!');
+        for (value, id) in numbers.as_parallel_slice_mut().iter_mut() {
+            *value *= *id as f32;
+        }
+        assert_eq!(names.as_parallel_slice().len(), 2);
+        drop(names);
+        drop(numbers);
+
+        let names = vec.read::<(Column<1>,)>();
+        let again = vec.read::<(Column<1>, Column<1>)>();
+        assert_eq!(
+            names.as_slices().0,
+            &[String::from("a!"), String::from("b")]
+        );
+        assert_eq!(again.as_slices().1[1], "b");
+        assert_eq!(vec.read::<(Column<2>,)>().as_slices().0, &[0.5, 3.0]);
+    }
+
+    #[test]
+    fn test_threads() {
+        let mut vec = LockedParallelVec::new();
+        vec.get_mut().extend((0..100u32).map(|idx| (idx, 0u64)));
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    let mut sums = vec.write::<(Column<1>,)>();
+                    let ids = vec.read::<(Column<0>,)>();
+                    for (sum, id) in sums.as_slices_mut().0.iter_mut().zip(ids.as_slices().0) {
+                        *sum += u64::from(*id);
+                    }
+                });
+            }
+        });
+        let vec = vec.into_inner();
+        assert_eq!(vec.as_slices().1[10], 40);
+        assert_eq!(vec.len(), 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "a field was selected more than once")]
+    fn test_write_overlapping() {
+        let vec = LockedParallelVec::from(ParallelVec::from(vec![(1u8, 2u8)]));
+        vec.write::<(Column<0>, Column<0>)>();
+    }
+}
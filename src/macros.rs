@@ -0,0 +1,43 @@
+/// Creates a [`ParallelVec`] containing the arguments, like `vec!`.
+///
+/// `parallel_vec!` allows a [`ParallelVec`] to be defined with the same syntax as
+/// array expressions. There are two forms of this macro:
+///
+/// - Create a [`ParallelVec`] containing a given list of elements. The vector is
+///   allocated once with exactly enough capacity for the elements:
+///
+/// ```rust
+/// use parallel_vec::parallel_vec;
+///
+/// let vec = parallel_vec![(1, 'a'), (2, 'b'), (3, 'c')];
+/// assert_eq!(vec.as_slices().0, &[1, 2, 3]);
+/// assert_eq!(vec.as_slices().1, &['a', 'b', 'c']);
+/// ```
+///
+/// - Create a [`ParallelVec`] from a given element and length:
+///
+/// ```rust
+/// use parallel_vec::parallel_vec;
+///
+/// let vec = parallel_vec![(0u32, 1.0f32); 3];
+/// assert_eq!(vec.as_slices().0, &[0, 0, 0]);
+/// assert_eq!(vec.as_slices().1, &[1.0, 1.0, 1.0]);
+/// ```
+///
+/// The second form uses [`ParallelVec::from_elem`], and so requires the element to
+/// implement [`Clone`].
+///
+/// [`ParallelVec`]: crate::ParallelVec
+/// [`ParallelVec::from_elem`]: crate::ParallelVec::from_elem
+#[macro_export]
+macro_rules! parallel_vec {
+    () => {
+        $crate::ParallelVec::new()
+    };
+    ($elem:expr; $n:expr) => {
+        $crate::ParallelVec::from_elem($elem, $n)
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::ParallelVec::from([$($x),+])
+    };
+}
@@ -0,0 +1,102 @@
+use crate::{ParallelSlice, ParallelSliceMut};
+use ndarray::{ArrayView1, ArrayViewMut1};
+
+macro_rules! impl_ndarray {
+    ($($ts:ident $vs:ident),*) => {
+        impl<'a, $($ts),*> ParallelSlice<'a, ($($ts,)*)> {
+            /// Gets an [`ArrayView1`] of every field.
+            ///
+            /// This does not copy any of the values.
+            pub fn as_array_views(&self) -> ($(ArrayView1<'_, $ts>,)*) {
+                let ($($vs,)*) = self.as_slices();
+                ($(ArrayView1::from($vs),)*)
+            }
+        }
+
+        impl<'a, $($ts),*> ParallelSliceMut<'a, ($($ts,)*)> {
+            /// Gets an [`ArrayView1`] of every field.
+            ///
+            /// This does not copy any of the values.
+            pub fn as_array_views(&self) -> ($(ArrayView1<'_, $ts>,)*) {
+                let ($($vs,)*) = self.as_slices();
+                ($(ArrayView1::from($vs),)*)
+            }
+
+            /// Gets an [`ArrayViewMut1`] of every field.
+            ///
+            /// This does not copy any of the values.
+            pub fn as_array_views_mut(&mut self) -> ($(ArrayViewMut1<'_, $ts>,)*) {
+                let ($($vs,)*) = self.as_slices_mut();
+                ($(ArrayViewMut1::from($vs),)*)
+            }
+        }
+    };
+}
+
+impl_ndarray!(T1 V1);
+impl_ndarray!(T1 V1, T2 V2);
+impl_ndarray!(T1 V1, T2 V2, T3 V3);
+impl_ndarray!(T1 V1, T2 V2, T3 V3, T4 V4);
+impl_ndarray!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5);
+impl_ndarray!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6);
+impl_ndarray!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7);
+impl_ndarray!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8);
+impl_ndarray!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9);
+impl_ndarray!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10);
+impl_ndarray!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11);
+impl_ndarray!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12
+);
+impl_ndarray!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13
+);
+impl_ndarray!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14
+);
+impl_ndarray!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14, T15 V15
+);
+impl_ndarray!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14, T15 V15, T16 V16
+);
+
+#[cfg(test)]
+mod test {
+    use crate::ParallelVec;
+
+    #[test]
+    fn test_array_views() {
+        let vec = ParallelVec::from(vec![(1.0f64, 2u32), (3.0, 4), (5.0, 6)]);
+        let (xs, ids) = vec.as_array_views();
+        assert_eq!(xs.sum(), 9.0);
+        assert_eq!(xs.dot(&xs), 35.0);
+        assert_eq!(ids.as_slice(), Some(&[2, 4, 6][..]));
+
+        let tail = vec.get(1..).unwrap();
+        let (xs, _) = tail.as_array_views();
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs.as_ptr(), vec.as_slices().0[1..].as_ptr());
+    }
+
+    #[test]
+    fn test_array_views_mut() {
+        let mut vec = ParallelVec::from(vec![(1.0f64, 2.0f64), (3.0, 4.0)]);
+        let mut slice = vec.as_parallel_slice_mut();
+        let (mut xs, ys) = slice.as_array_views_mut();
+        xs.scaled_add(2.0, &ys);
+        xs *= 0.5;
+        assert_eq!(vec.as_slices().0, &[2.5, 5.5]);
+    }
+
+    #[test]
+    fn test_array_views_empty() {
+        let vec: ParallelVec<(f32, u8)> = ParallelVec::new();
+        let (xs, ids) = vec.as_array_views();
+        assert!(xs.is_empty());
+        assert!(ids.is_empty());
+    }
+}
@@ -0,0 +1,425 @@
+use crate::{iter::Iter, ParallelColumn, ParallelSlice, ParallelVec};
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+/// A [`ParallelVec`] that keeps a sorted index over the `N`th field of its elements,
+/// so they can be visited in order of that key without sorting the container.
+///
+/// The elements stay in the order they were added. The index holds their indices sorted
+/// by key, with equal keys in the order they were indexed, and is updated incrementally
+/// by every operation that adds, removes or moves elements. This allows ranges of keys
+/// to be visited with [`range_by_key`] in logarithmic time plus the length of the range.
+/// The key field can only be changed through [`update`], which re-sorts the element.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::OrderedParallelVec;
+///
+/// // Sprites ordered by their depth, in field 1.
+/// let mut sprites = OrderedParallelVec::<_, 1>::new();
+/// sprites.push(("sky", 0u32));
+/// sprites.push(("player", 5));
+/// sprites.push(("tree", 3));
+///
+/// let names: Vec<_> = sprites.iter_sorted().map(|(_, (name, _))| *name).collect();
+/// assert_eq!(names, ["sky", "tree", "player"]);
+/// let foreground: Vec<_> = sprites.range_by_key(3..).map(|(idx, _)| idx).collect();
+/// assert_eq!(foreground, [2, 1]);
+/// ```
+///
+/// [`range_by_key`]: Self::range_by_key
+/// [`update`]: Self::update
+pub struct OrderedParallelVec<Param: ParallelColumn<N>, const N: usize> {
+    vec: ParallelVec<Param>,
+    /// The indices of every element, sorted by key.
+    order: Vec<usize>,
+}
+
+impl<Param, const N: usize> OrderedParallelVec<Param, N>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Ord,
+{
+    /// Constructs a new, empty `OrderedParallelVec`.
+    pub fn new() -> Self {
+        Self {
+            vec: ParallelVec::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Constructs a new, empty `OrderedParallelVec` with the capacity for `capacity`
+    /// elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: ParallelVec::with_capacity(capacity),
+            order: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of elements in the container.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if the container contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    fn keys(&self) -> &[Param::Column] {
+        Param::column(self.vec.as_slices())
+    }
+
+    /// Returns the position in the index at which the element at `index` is inserted,
+    /// after every element with a lesser or equal key.
+    fn insert_position(&self, index: usize) -> usize {
+        let keys = self.keys();
+        let key = &keys[index];
+        self.order.partition_point(|&idx| keys[idx] <= *key)
+    }
+
+    /// Returns the position in the index of the element at `index`.
+    fn position(&self, index: usize) -> usize {
+        let keys = self.keys();
+        let key = &keys[index];
+        let start = self.order.partition_point(|&idx| keys[idx] < *key);
+        match self.order[start..].iter().position(|&idx| idx == index) {
+            Some(offset) => start + offset,
+            None => unreachable!(),
+        }
+    }
+
+    /// Appends an element to the back of the container, and returns its index.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn push(&mut self, value: Param) -> usize {
+        let index = self.vec.len();
+        self.vec.push(value);
+        let position = self.insert_position(index);
+        self.order.insert(position, index);
+        index
+    }
+
+    /// Removes the last element from the container and returns it, or `None` if it is
+    /// empty.
+    pub fn pop(&mut self) -> Option<Param> {
+        let last = self.vec.len().checked_sub(1)?;
+        let position = self.position(last);
+        self.order.remove(position);
+        self.vec.pop()
+    }
+
+    /// Removes the element at `index` and returns it, shifting all elements after it to
+    /// the left.
+    ///
+    /// Every element after it is re-indexed, so this takes linear time.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Param {
+        crate::assert_in_bounds(index, self.len());
+        let position = self.position(index);
+        self.order.remove(position);
+        for idx in self.order.iter_mut().filter(|idx| **idx > index) {
+            *idx -= 1;
+        }
+        match self.vec.remove(index) {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+
+    /// Removes the element at `index` and returns it, moving the last element into its
+    /// place.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> Param {
+        crate::assert_in_bounds(index, self.len());
+        let position = self.position(index);
+        self.order.remove(position);
+        let last = self.vec.len() - 1;
+        if index != last {
+            let moved = self.position(last);
+            self.order[moved] = index;
+        }
+        self.vec.swap_remove(index)
+    }
+
+    /// Shortens the container to `len` elements, dropping the rest.
+    pub fn truncate(&mut self, len: usize) {
+        self.order.retain(|&idx| idx < len);
+        self.vec.truncate(len);
+    }
+
+    /// Removes every element from the container.
+    pub fn clear(&mut self) {
+        self.order.clear();
+        self.vec.clear();
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Param::Ref<'_>> {
+        self.vec.get(index)
+    }
+
+    /// Calls `f` with a mutable reference to the element at `index`, and returns its
+    /// result, or `None` if it is out of bounds.
+    ///
+    /// If `f` changes the key of the element, it is moved to its new position in the
+    /// index, even if `f` panics.
+    pub fn update<R>(&mut self, index: usize, f: impl FnOnce(Param::RefMut<'_>) -> R) -> Option<R> {
+        if index >= self.len() {
+            return None;
+        }
+        let position = self.position(index);
+        let guard = UpdateGuard {
+            ordered: self,
+            index,
+            position,
+        };
+        let result = f(guard.ordered.vec.get_mut(index)?);
+        drop(guard);
+        Some(result)
+    }
+
+    /// Returns the indices of every element, sorted by key.
+    pub fn sorted_indices(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns an iterator over the indices of, and references to, every element in
+    /// order of their keys.
+    pub fn iter_sorted(&self) -> OrderedIter<'_, Param> {
+        self.range_by_key::<Param::Column, _>(..)
+    }
+
+    /// Returns an iterator over the indices of, and references to, the elements with a
+    /// key within `range`, in order of their keys.
+    pub fn range_by_key<Q, R>(&self, range: R) -> OrderedIter<'_, Param>
+    where
+        Param::Column: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let keys = self.keys();
+        let key = |idx: &usize| keys[*idx].borrow();
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.order.partition_point(|idx| key(idx) < start),
+            Bound::Excluded(start) => self.order.partition_point(|idx| key(idx) <= start),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.order.partition_point(|idx| key(idx) <= end),
+            Bound::Excluded(end) => self.order.partition_point(|idx| key(idx) < end),
+            Bound::Unbounded => self.order.len(),
+        };
+        OrderedIter {
+            indices: self.order[start..end.max(start)].iter(),
+            storage: self.vec.storage,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the elements, in the order they were added, as a [`ParallelSlice`].
+    pub fn as_parallel_slice(&self) -> ParallelSlice<'_, Param> {
+        self.vec.as_parallel_slice()
+    }
+
+    /// Gets the fields of every element as slices.
+    pub fn as_slices(&self) -> Param::Slices<'_> {
+        self.vec.as_slices()
+    }
+
+    /// Returns an iterator over references to the elements, in the order they were
+    /// added.
+    pub fn iter(&self) -> Iter<'_, Param> {
+        self.vec.iter()
+    }
+
+    /// Converts the container into a [`ParallelVec`], discarding the index.
+    pub fn into_parallel_vec(self) -> ParallelVec<Param> {
+        self.vec
+    }
+}
+
+impl<Param, const N: usize> Default for OrderedParallelVec<Param, N>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param, const N: usize> From<ParallelVec<Param>> for OrderedParallelVec<Param, N>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Ord,
+{
+    /// Builds the index over the elements of `vec`, without moving them.
+    fn from(vec: ParallelVec<Param>) -> Self {
+        let keys = Param::column(vec.as_slices());
+        let mut order: Vec<usize> = (0..vec.len()).collect();
+        order.sort_by(|a, b| keys[*a].cmp(&keys[*b]));
+        Self { vec, order }
+    }
+}
+
+impl<Param, const N: usize> Extend<Param> for OrderedParallelVec<Param, N>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Ord,
+{
+    fn extend<I: IntoIterator<Item = Param>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+/// Moves an element to its position in the index when dropped, so that the index stays
+/// sorted even if the function updating it panics.
+struct UpdateGuard<'a, Param, const N: usize>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Ord,
+{
+    ordered: &'a mut OrderedParallelVec<Param, N>,
+    index: usize,
+    /// The position of the element in the index before it was updated.
+    position: usize,
+}
+
+impl<'a, Param, const N: usize> Drop for UpdateGuard<'a, Param, N>
+where
+    Param: ParallelColumn<N>,
+    Param::Column: Ord,
+{
+    fn drop(&mut self) {
+        self.ordered.order.remove(self.position);
+        let position = self.ordered.insert_position(self.index);
+        self.ordered.order.insert(position, self.index);
+    }
+}
+
+/// An iterator over the elements of an [`OrderedParallelVec`] in order of their keys,
+/// created by [`OrderedParallelVec::range_by_key`] and
+/// [`OrderedParallelVec::iter_sorted`].
+pub struct OrderedIter<'a, Param: crate::ParallelParam> {
+    indices: core::slice::Iter<'a, usize>,
+    storage: Param::Storage,
+    _marker: PhantomData<&'a Param>,
+}
+
+impl<'a, Param: crate::ParallelParam> Iterator for OrderedIter<'a, Param> {
+    type Item = (usize, Param::Ref<'a>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = *self.indices.next()?;
+        // SAFE: The index holds only indices of elements, and the container is borrowed
+        // for 'a.
+        let value = unsafe { Param::as_ref(Param::ptr_at(self.storage, index)) };
+        Some((index, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<'a, Param: crate::ParallelParam> DoubleEndedIterator for OrderedIter<'a, Param> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = *self.indices.next_back()?;
+        // SAFE: The index holds only indices of elements, and the container is borrowed
+        // for 'a.
+        let value = unsafe { Param::as_ref(Param::ptr_at(self.storage, index)) };
+        Some((index, value))
+    }
+}
+
+impl<'a, Param: crate::ParallelParam> ExactSizeIterator for OrderedIter<'a, Param> {}
+
+#[cfg(test)]
+mod test {
+    use super::OrderedParallelVec;
+    use crate::ParallelVec;
+    use alloc::{string::String, vec::Vec};
+    use core::ops::Bound;
+
+    fn sorted(vec: &OrderedParallelVec<(String, u32), 1>) -> Vec<u32> {
+        vec.iter_sorted().map(|(_, (_, key))| *key).collect()
+    }
+
+    fn names<'a>(iter: impl Iterator<Item = (usize, (&'a String, &'a u32))>) -> Vec<&'a str> {
+        iter.map(|(_, (name, _))| name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_incremental() {
+        let mut vec = OrderedParallelVec::new();
+        for (name, key) in [("a", 5), ("b", 1), ("c", 5), ("d", 3), ("e", 9)] {
+            vec.push((String::from(name), key));
+        }
+        assert_eq!(sorted(&vec), [1, 3, 5, 5, 9]);
+        assert_eq!(names(vec.iter_sorted()), ["b", "d", "a", "c", "e"]);
+        assert_eq!(names(vec.range_by_key(3..=5)), ["d", "a", "c"]);
+        assert_eq!(names(vec.range_by_key(2..5)), ["d"]);
+        assert_eq!(
+            names(vec.range_by_key((Bound::Excluded(5), Bound::Included(5)))).len(),
+            0
+        );
+        assert_eq!(names(vec.range_by_key(..).rev()), ["e", "c", "a", "d", "b"]);
+
+        assert_eq!(vec.swap_remove(0).0, "a");
+        assert_eq!(names(vec.iter_sorted()), ["b", "d", "c", "e"]);
+        assert_eq!(vec.sorted_indices(), [1, 3, 2, 0]);
+        assert_eq!(vec.remove(1).0, "b");
+        assert_eq!(vec.sorted_indices(), [2, 1, 0]);
+        assert_eq!(vec.pop().unwrap().0, "d");
+        assert_eq!(names(vec.iter_sorted()), ["c", "e"]);
+
+        vec.update(1, |(_, key)| *key = 0);
+        assert_eq!(names(vec.iter_sorted()), ["c", "e"]);
+        assert_eq!(vec.update(0, |(_, key)| *key = 20), Some(()));
+        assert_eq!(vec.update(2, |_| ()), None);
+        assert_eq!(names(vec.iter_sorted()), ["c", "e"]);
+        vec.update(1, |(_, key)| *key = 30);
+        assert_eq!(names(vec.iter_sorted()), ["e", "c"]);
+        vec.truncate(1);
+        assert_eq!(sorted(&vec), [20]);
+    }
+
+    #[test]
+    fn test_update_panics() {
+        let mut vec = OrderedParallelVec::<_, 1>::new();
+        vec.extend([("a", 1u32), ("b", 2), ("c", 3)]);
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            vec.update(0, |(_, key)| {
+                *key = 10;
+                panic!("update failed");
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec.sorted_indices(), [1, 2, 0]);
+        assert_eq!(vec.swap_remove(0).0, "a");
+        assert_eq!(vec.sorted_indices(), [1, 0]);
+    }
+
+    #[test]
+    fn test_from() {
+        let vec = ParallelVec::from(alloc::vec![(3u8, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+        let mut ordered = OrderedParallelVec::<_, 0>::from(vec);
+        assert_eq!(ordered.sorted_indices(), [1, 3, 2, 0]);
+        ordered.extend([(0, 'e'), (2, 'f')]);
+        let values: Vec<char> = ordered.range_by_key(1..3).map(|(_, (_, v))| *v).collect();
+        assert_eq!(values, ['b', 'd', 'c', 'f']);
+        assert_eq!(ordered.iter_sorted().len(), 6);
+    }
+}
@@ -1,65 +1,205 @@
-use super::{ParallelVec, ParallelVecConversionError};
-use alloc::{
-    alloc::{alloc, dealloc, Layout},
-    vec::Vec,
+use super::{
+    handle_reserve_error, ParallelVec, ParallelVecConversionError, Storage, TryReserveError,
 };
-use core::ptr::NonNull;
+use alloc::{alloc::Layout, vec::Vec};
+use core::{marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
 
 /// This trait contains the basic operations for creating variadic
 /// parallel vector implementations.
 ///
 /// This trait is sealed and cannot be implemented outside of
-/// `parallel_vec`.
+/// `parallel_vec`, other than for structs via `#[derive(ParallelVecParam)]`
+/// with the `derive` feature.
 ///
 /// This trait has blanket implementations of all tuples of up
-/// to size 12 of all types that are `'static`.
+/// to size 16.
+///
+/// Every pointer handed out by this trait is derived from the base pointer
+/// of its allocation via pointer offsets, and never from integer casts, so
+/// the implementations are compatible with strict provenance.
 ///
 /// # Safety
 /// None of the associated functions can panic.
 pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// A set of [`NonNull`] pointers of the parameter.
     /// This is the main backing storage pointers for [`ParallelVec`].
-    type Storage: Copy + Eq;
-    /// A set of pointers of the parameter.
+    type Storage: Copy;
+    /// A set of [`NonNull`] pointers of the parameter, used for pointer arithmetic.
+    ///
+    /// All offsets are computed on the pointers themselves, so they keep the
+    /// provenance of the allocation they were derived from.
     type Ptr: Copy;
-    /// A set of memory offsets of the parameter.
-    type Offsets;
+    /// A set of read-only pointers of the parameter.
+    type ConstPtr: Copy;
+    /// A set of memory offsets of the parameter, one per field, in bytes.
+    type Offsets: AsRef<[usize]> + Copy;
     /// A set of immutable references of the parameter.
-    type Ref<'a>;
+    type Ref<'a>
+    where
+        Self: 'a;
     /// A set of mutable references of the parameter.
-    type RefMut<'a>;
+    type RefMut<'a>
+    where
+        Self: 'a;
     /// A set of [`Vec<T>`]s of the parameter.
     type Vecs;
     /// A set of mutable slice references of the parameter.
-    type Slices<'a>;
+    type Slices<'a>
+    where
+        Self: 'a;
     /// A set of mutable slice references of the parameter.
-    type SlicesMut<'a>;
+    type SlicesMut<'a>
+    where
+        Self: 'a;
+    /// A set of mutable slice references of possibly uninitialized values of the
+    /// parameter.
+    type UninitSlicesMut<'a>
+    where
+        Self: 'a;
     /// A set of iterators of immutable references of the parameter.
-    type Iters<'a>;
+    type Iters<'a>
+    where
+        Self: 'a;
     /// A set of iterators of mutable references of the parameter.
-    type ItersMut<'a>;
+    type ItersMut<'a>
+    where
+        Self: 'a;
+    /// A set of fixed-size arrays of possibly uninitialized values of the
+    /// parameter, one per field.
+    type Arrays<const N: usize>;
 
     /// Creates a set of dangling pointers for the given types.
     fn dangling() -> Self::Storage;
 
-    /// Converts a set of [`NonNull`]s into their associated
-    /// pointer types.
+    /// Converts the storage pointers into a set of pointers to the first element.
     fn as_ptr(storage: Self::Storage) -> Self::Ptr;
 
-    /// Allocates a buffer for a given capacity.
+    /// Converts a set of [`NonNull`]s into their associated read-only
+    /// pointer types.
+    fn as_const_ptr(storage: Self::Storage) -> Self::ConstPtr;
+
+    /// Allocates a buffer for a given capacity from `alloc`, with every field
+    /// aligned to at least `column_align` bytes.
+    ///
+    /// Panics if the capacity overflows, and calls [`handle_alloc_error`] if
+    /// the allocation fails.
+    ///
+    /// # Safety
+    /// Capacity should be non-zero, and `column_align` must be a power of two.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    unsafe fn alloc<A: Storage>(capacity: usize, column_align: usize, alloc: &A) -> Self::Storage {
+        match Self::try_alloc(capacity, column_align, alloc) {
+            Ok(storage) => storage,
+            Err(err) => handle_reserve_error(err),
+        }
+    }
+
+    /// Allocates a buffer for a given capacity from `alloc`, with every field
+    /// aligned to at least `column_align` bytes, returning an error if the capacity
+    /// overflows or the allocator fails.
+    ///
+    /// If every field is zero-sized, nothing is allocated and a set of dangling
+    /// pointers is returned.
+    ///
+    /// # Safety
+    /// Capacity should be non-zero, and `column_align` must be a power of two.
+    unsafe fn try_alloc<A: Storage>(
+        capacity: usize,
+        column_align: usize,
+        alloc: &A,
+    ) -> Result<Self::Storage, TryReserveError> {
+        let layout = Self::try_layout_for_capacity_aligned(capacity, column_align)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if layout.layout.size() == 0 {
+            return Ok(Self::dangling());
+        }
+        let bytes = alloc
+            .allocate(layout.layout)
+            .map_err(|_| TryReserveError::AllocError {
+                layout: layout.layout,
+            })?;
+        Ok(Self::from_base(bytes, &layout.offsets))
+    }
+
+    /// Resizes a buffer allocated from [`alloc`] from `old_capacity` to `new_capacity`,
+    /// moving the first `len` elements of every field to their new offsets.
+    ///
+    /// This will attempt to resize the allocation in place, and only copy the
+    /// individual fields when their offsets within the buffer change. If an error is
+    /// returned, `storage` is left unchanged and is still valid for `old_capacity`.
     ///
     /// # Safety
-    /// Capacity should be non-zero.
-    unsafe fn alloc(capacity: usize) -> Self::Storage;
+    ///  - `storage` must have been allocated from [`alloc`] with `alloc` alongside the
+    ///    provided `old_capacity` and `column_align`.
+    ///  - Both `old_capacity` and `new_capacity` must be non-zero.
+    ///  - `len` must be less than or equal to both `old_capacity` and `new_capacity`.
+    ///
+    /// [`alloc`]: Self::alloc
+    unsafe fn try_realloc<A: Storage>(
+        storage: Self::Storage,
+        old_capacity: usize,
+        new_capacity: usize,
+        len: usize,
+        column_align: usize,
+        alloc: &A,
+    ) -> Result<Self::Storage, TryReserveError> {
+        let old_layout = Self::layout_for_capacity_aligned(old_capacity, column_align);
+        let new_layout = Self::try_layout_for_capacity_aligned(new_capacity, column_align)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if old_layout.layout.size() == 0 {
+            return Self::try_alloc(new_capacity, column_align, alloc);
+        }
+        if new_layout.layout.size() == 0 {
+            Self::dealloc(&mut { storage }, old_capacity, column_align, alloc);
+            return Ok(Self::dangling());
+        }
+        let bytes = realloc_fields(
+            Self::base(storage),
+            (old_layout.layout, old_layout.offsets.as_ref()),
+            (new_layout.layout, new_layout.offsets.as_ref()),
+            Self::field_sizes(len).as_ref(),
+            alloc,
+        )?;
+        Ok(Self::from_base(bytes, &new_layout.offsets))
+    }
 
     /// Deallocates a buffer allocated from [`alloc`].
     ///
     /// # Safety
-    /// `storage` must have been allocated from [`alloc`] alongside
-    /// the provided `capacity`.
+    /// `storage` must have been allocated from [`alloc`] with `alloc` alongside
+    /// the provided `capacity` and `column_align`.
     ///
     /// [`alloc`]: Self::alloc
-    unsafe fn dealloc(storage: &mut Self::Storage, capacity: usize);
+    unsafe fn dealloc<A: Storage>(
+        storage: &mut Self::Storage,
+        capacity: usize,
+        column_align: usize,
+        alloc: &A,
+    ) {
+        let layout = Self::layout_for_capacity_aligned(capacity, column_align);
+        if layout.layout.size() > 0 {
+            alloc.deallocate(Self::base(*storage), layout.layout);
+        }
+    }
+
+    /// Splits a single buffer into the storage pointers for each field,
+    /// using the byte `offsets` of each field from the start of the buffer.
+    ///
+    /// Zero-sized fields are given dangling pointers instead.
+    ///
+    /// # Safety
+    /// `base` must be a valid, non-null pointer, and `base + offset` must be
+    /// non-null and correctly aligned for each field.
+    unsafe fn from_base(base: NonNull<u8>, offsets: &Self::Offsets) -> Self::Storage;
+
+    /// Gets the pointer to the start of the buffer backing `storage`.
+    ///
+    /// This is the pointer to the first field.
+    fn base(storage: Self::Storage) -> NonNull<u8>;
+
+    /// Gets the size in bytes of `len` elements of each field.
+    fn field_sizes(len: usize) -> Self::Offsets;
 
     /// Gets the pointer at a given index.
     ///
@@ -72,8 +212,47 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
         Self::add(Self::as_ptr(storage), idx)
     }
 
-    /// Creates a layout for a [`ParallelVec`] for a given `capacity`
-    fn layout_for_capacity(capacity: usize) -> MemoryLayout<Self>;
+    /// Creates a layout for a [`ParallelVec`] for a given `capacity`.
+    ///
+    /// Panics if the size of the layout overflows.
+    fn layout_for_capacity(capacity: usize) -> MemoryLayout<Self> {
+        match Self::try_layout_for_capacity(capacity) {
+            Some(layout) => layout,
+            None => handle_reserve_error(TryReserveError::CapacityOverflow),
+        }
+    }
+
+    /// Creates a layout for a [`ParallelVec`] for a given `capacity`.
+    ///
+    /// Returns `None` if the size of the layout overflows.
+    fn try_layout_for_capacity(capacity: usize) -> Option<MemoryLayout<Self>> {
+        Self::try_layout_for_capacity_aligned(capacity, 1)
+    }
+
+    /// Creates a layout for a [`ParallelVec`] for a given `capacity`, where the start
+    /// of every field is aligned to at least `column_align` bytes.
+    ///
+    /// Panics if the size of the layout overflows, or if `column_align` is not a power
+    /// of two.
+    fn layout_for_capacity_aligned(capacity: usize, column_align: usize) -> MemoryLayout<Self> {
+        match Self::try_layout_for_capacity_aligned(capacity, column_align) {
+            Some(layout) => layout,
+            None => handle_reserve_error(TryReserveError::CapacityOverflow),
+        }
+    }
+
+    /// Creates a layout for a [`ParallelVec`] for a given `capacity`, where the start
+    /// of every field is aligned to at least `column_align` bytes.
+    ///
+    /// The size of the layout is padded to a multiple of `column_align`, so every
+    /// field occupies its own `column_align` sized blocks.
+    ///
+    /// Returns `None` if the size of the layout overflows, or if `column_align` is not
+    /// a power of two.
+    fn try_layout_for_capacity_aligned(
+        capacity: usize,
+        column_align: usize,
+    ) -> Option<MemoryLayout<Self>>;
 
     /// Gets the legnth for the associated `Vec`s.
     ///
@@ -87,6 +266,37 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// The provided `Vec`s must be correctly allocated.
     unsafe fn get_vec_ptrs(vecs: &mut Self::Vecs) -> Self::Ptr;
 
+    /// Creates empty associated `Vec`s, each with at least the provided capacity.
+    fn vecs_with_capacity(capacity: usize) -> Self::Vecs;
+
+    /// Sets the length of all of the associated `Vec`s.
+    ///
+    /// # Safety
+    /// The first `len` elements of every `Vec` must be initialized, and `len`
+    /// must not exceed the capacity of any of the `Vec`s.
+    unsafe fn set_vec_len(vecs: &mut Self::Vecs, len: usize);
+
+    /// Creates a set of uninitialized arrays.
+    fn uninit_arrays<const N: usize>() -> Self::Arrays<N>;
+
+    /// Gets pointers to the first element of each of the arrays.
+    ///
+    /// The returned pointers are only valid for reads.
+    fn arrays_storage<const N: usize>(arrays: &Self::Arrays<N>) -> Self::Storage;
+
+    /// Gets pointers to the first element of each of the arrays, which are valid
+    /// for both reads and writes.
+    fn arrays_storage_mut<const N: usize>(arrays: &mut Self::Arrays<N>) -> Self::Storage;
+
+    /// Gets the length for the associated mutable slices.
+    ///
+    /// Returns `None` if not all of the slices share the same
+    /// length.
+    fn get_slices_len(slices: &Self::SlicesMut<'_>) -> Option<usize>;
+
+    /// Gets the underlying pointers for the associated mutable slices.
+    fn get_slices_ptrs(slices: &mut Self::SlicesMut<'_>) -> Self::Ptr;
+
     /// Adds `offset` to all of the pointers in `base`.
     ///
     /// # Safety
@@ -127,6 +337,14 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// for the allocation that `ptr` points to.
     unsafe fn as_slices_mut<'a>(ptr: Self::Ptr, len: usize) -> Self::SlicesMut<'a>;
 
+    /// Creates a set of mutable slices of possibly uninitialized values from `ptr`
+    /// and a provided length.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid, non-null pointer. `len` must be approriately set
+    /// for the allocation that `ptr` points to.
+    unsafe fn as_uninit_slices_mut<'a>(ptr: Self::Ptr, len: usize) -> Self::UninitSlicesMut<'a>;
+
     /// Creates a set of iterators from slices.
     #[allow(clippy::needless_lifetimes)]
     fn iters<'a>(slices: Self::Slices<'a>) -> Self::Iters<'a>;
@@ -144,6 +362,12 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// `ptr` must be a valid, non-null pointer.
     unsafe fn as_ref<'a>(ptr: Self::Ptr) -> Self::Ref<'a>;
 
+    /// Gets a set of pointers to each field of `value`.
+    ///
+    /// The returned pointers are only valid for reads, and only for as long as
+    /// `value` is borrowed.
+    fn field_ptrs(value: &Self) -> Self::Ptr;
+
     /// Converts `ptr` into the storage type.
     ///
     /// # Safety
@@ -168,30 +392,291 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// `ptr` must be a valid, non-null pointer.
     unsafe fn write(ptr: Self::Ptr, value: Self);
 
+    /// Moves `len` rows from the array of tuples at `src` into the columns at `dst`,
+    /// one column at a time.
+    ///
+    /// # Safety
+    /// `src` must be valid for reads of `len` initialized rows, which must not be used
+    /// again afterwards, and `dst` must be valid for writes of `len` elements.
+    unsafe fn write_rows(dst: Self::Ptr, src: *const Self, len: usize);
+
     /// Swaps the values pointed to by the provided pointers.
     ///
     /// # Safety
     /// Both `a` and `b` must be valid for all of it's consitutent member pointers.
     unsafe fn swap(a: Self::Ptr, other: Self::Ptr);
 
+    /// Swaps `size` elements between the contiguous memory pointed to by `a` and `b`.
+    ///
+    /// # Safety
+    ///  - `a` and `b` must be a valid, non-null pointer for the associated types.
+    ///  - `size` must be approriately set for the allocation that both `a` and `b`
+    ///    point to.
+    ///  - `a..a + size` must not overlap with the memory range of `b..b + size`.
+    unsafe fn swap_nonoverlapping(a: Self::Ptr, b: Self::Ptr, size: usize);
+
     /// Drops the values pointed to by the pointers.
     ///
     /// # Safety
     /// The caller must ensure that the values pointed to by the pointers have
     /// not already been dropped prior.
     unsafe fn drop(ptr: Self::Ptr);
+
+    /// Drops `len` contiguous values starting at `ptr`.
+    ///
+    /// Like [`core::ptr::drop_in_place`] on a slice, if dropping one of the values
+    /// panics, the remaining values of the same field are still dropped.
+    ///
+    /// # Safety
+    /// The caller must ensure that the values pointed to by the pointers have
+    /// not already been dropped prior, and that `len` is approriately set for
+    /// the allocation that `ptr` points to.
+    unsafe fn drop_slice(ptr: Self::Ptr, len: usize);
 }
 
 /// Memory layout information for creating a [`ParallelVec`].
 ///
-/// Users will not need to deal with this type directly, as there
-/// is no way to instantiate a copy of this struct safely.
+/// Most users will not need to deal with this type directly. It describes
+/// where each field lives within a buffer, which is useful when the buffer
+/// is shared with another reader, such as another process mapping the same
+/// shared memory segment. See [`ParallelSlice::from_bytes`].
+///
+/// [`ParallelSlice::from_bytes`]: crate::ParallelSlice::from_bytes
 pub struct MemoryLayout<Param: ParallelParam> {
-    layout: Layout,
-    offsets: Param::Offsets,
+    pub(crate) layout: Layout,
+    pub(crate) offsets: Param::Offsets,
+}
+
+impl<Param: ParallelParam> MemoryLayout<Param> {
+    /// The layout of the entire buffer.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// The offset in bytes of each field from the start of the buffer.
+    pub fn offsets(&self) -> &[usize] {
+        self.offsets.as_ref()
+    }
+}
+
+/// Selects the field at index `N` of a [`ParallelParam`], e.g. the first
+/// field of `(T1, T2)` is `ParallelColumn<0>` with a `Column` of `T1`.
+///
+/// This trait has blanket implementations for every field of all tuples of up
+/// to size 16, and is used to operate on a single field by its index, such as
+/// with [`ParallelSliceMut::sort_by_column`].
+///
+/// [`ParallelSliceMut::sort_by_column`]: crate::ParallelSliceMut::sort_by_column
+pub trait ParallelColumn<const N: usize>: ParallelParam {
+    /// The type of the field.
+    type Column;
+
+    /// Gets the slice of the field from the slices of every field.
+    fn column<'a>(slices: Self::Slices<'a>) -> &'a [Self::Column]
+    where
+        Self: 'a;
+
+    /// Gets the pointer to the field from the pointers to every field.
+    fn column_storage(storage: Self::Storage) -> NonNull<Self::Column>;
+}
+
+/// A marker for the field at index `N`, used to select a set of fields with
+/// [`ParallelSelection`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Column<const N: usize>;
+
+/// A set of fields of `Param`, selected by their indices.
+///
+/// This trait is implemented for tuples of up to 16 [`Column`] markers, where
+/// every index is a field of `Param`. For example, `(Column<0>, Column<2>)`
+/// selects the first and third fields of `(A, B, C)`, with an `Output` of
+/// `(A, C)`. It is used to view only some of the fields of a slice, such as with
+/// [`ParallelSliceMut::select_mut`].
+///
+/// [`ParallelSliceMut::select_mut`]: crate::ParallelSliceMut::select_mut
+pub trait ParallelSelection<Param: ParallelParam> {
+    /// The parameter made of the selected fields.
+    type Output: ParallelParam;
+    /// The indices of the selected fields, in order.
+    ///
+    /// As this is a constant, mutable views can reject selections of the same field
+    /// more than once at compile time.
+    const INDICES: &'static [usize];
+
+    /// Gets the pointers to the selected fields from the pointers to every field.
+    fn select(storage: Param::Storage) -> <Self::Output as ParallelParam>::Storage;
+}
+
+/// Returns `true` if no index is used by both `a` and `b`, or more than once by either.
+pub(crate) const fn indices_are_disjoint(a: &[usize], b: &[usize]) -> bool {
+    let len = a.len() + b.len();
+    let mut i = 0;
+    while i < len {
+        let mut j = i + 1;
+        while j < len {
+            if index_of_either(a, b, i) == index_of_either(a, b, j) {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Gets the `idx`th index of `a` followed by `b`.
+const fn index_of_either(a: &[usize], b: &[usize], idx: usize) -> usize {
+    if idx < a.len() {
+        a[idx]
+    } else {
+        b[idx - a.len()]
+    }
+}
+
+/// Fails to compile when instantiated with a selection `S` that selects a field more
+/// than once, by evaluating [`AssertDistinct::OK`].
+pub(crate) struct AssertDistinct<Param, S>(PhantomData<(Param, S)>);
+
+impl<Param: ParallelParam, S: ParallelSelection<Param>> AssertDistinct<Param, S> {
+    pub(crate) const OK: () = assert!(
+        indices_are_disjoint(S::INDICES, &[]),
+        "a field was selected more than once"
+    );
+}
+
+/// Fails to compile when instantiated with selections `A` and `B` that select a field
+/// more than once between them, by evaluating [`AssertDisjoint::OK`].
+pub(crate) struct AssertDisjoint<Param, A, B>(PhantomData<(Param, A, B)>);
+
+impl<Param, A, B> AssertDisjoint<Param, A, B>
+where
+    Param: ParallelParam,
+    A: ParallelSelection<Param>,
+    B: ParallelSelection<Param>,
+{
+    pub(crate) const OK: () = assert!(
+        indices_are_disjoint(A::INDICES, B::INDICES),
+        "a field was selected more than once"
+    );
+}
+
+/// Computes the layout of a buffer holding `capacity` values of each field described
+/// by `fields`, with every field aligned to at least `column_align` bytes. The offset of
+/// each field in bytes is written to `offsets`.
+///
+/// Returns `None` if the size of the layout overflows, or if `column_align` is not a
+/// power of two.
+pub(crate) fn try_fields_layout(
+    fields: impl IntoIterator<Item = Layout>,
+    capacity: usize,
+    column_align: usize,
+    offsets: &mut [usize],
+) -> Option<Layout> {
+    // `Layout::align_to` only checks the larger of the two alignments, so an invalid
+    // `column_align` below the alignment of a field would otherwise go unnoticed.
+    if !column_align.is_power_of_two() {
+        return None;
+    }
+    let mut layout = Layout::new::<()>();
+    for (field, offset) in fields.into_iter().zip(offsets) {
+        let size = field.pad_to_align().size().checked_mul(capacity)?;
+        let array = Layout::from_size_align(size, field.align())
+            .ok()?
+            .align_to(column_align)
+            .ok()?;
+        (layout, *offset) = layout.extend(array).ok()?;
+    }
+    // Pad the last field as well, so that no field shares a `column_align` sized
+    // block with another field or with memory outside of the buffer.
+    let size = layout.size().checked_add(column_align - 1)? & !(column_align - 1);
+    Layout::from_size_align(size, layout.align()).ok()
+}
+
+/// Resizes a buffer from the `old` layout and field offsets to the `new` ones, moving
+/// the initialized region of each field, `sizes` bytes long, to its new offset.
+///
+/// This will attempt to resize the allocation in place. If an error is returned, the
+/// buffer is left unchanged.
+///
+/// # Safety
+/// `bytes` must have been allocated from `alloc` with the `old` layout, and neither
+/// layout may be zero-sized. Each field must fit within both layouts.
+pub(crate) unsafe fn realloc_fields<A: Storage>(
+    bytes: NonNull<u8>,
+    old: (Layout, &[usize]),
+    new: (Layout, &[usize]),
+    sizes: &[usize],
+    alloc: &A,
+) -> Result<NonNull<u8>, TryReserveError> {
+    let (old_layout, old_offsets) = old;
+    let (new_layout, new_offsets) = new;
+    let error = TryReserveError::AllocError { layout: new_layout };
+    if new_layout.size() < old_layout.size() {
+        // Shrinking moves every field towards the start of the buffer. Do this
+        // before reallocating, as the tail of the buffer will be truncated.
+        move_fields(bytes.as_ptr(), old_offsets, new_offsets, sizes);
+        match alloc.shrink(bytes, old_layout, new_layout) {
+            Ok(new_bytes) => Ok(new_bytes),
+            Err(_) => {
+                move_fields(bytes.as_ptr(), new_offsets, old_offsets, sizes);
+                Err(error)
+            }
+        }
+    } else {
+        let new_bytes = alloc
+            .grow(bytes, old_layout, new_layout)
+            .map_err(|_| error)?;
+        move_fields(new_bytes.as_ptr(), old_offsets, new_offsets, sizes);
+        Ok(new_bytes)
+    }
+}
+
+/// Moves the fields of a buffer from the `src` offsets to the `dst` offsets.
+/// `sizes` is the size in bytes of the initialized region of each field.
+///
+/// # Safety
+/// `base` must be valid for reads and writes for every region described by the
+/// provided offsets and sizes. All of the fields must move in the same direction.
+unsafe fn move_fields(base: *mut u8, src: &[usize], dst: &[usize], sizes: &[usize]) {
+    let towards_end = src.iter().zip(dst).any(|(src, dst)| dst > src);
+    let mut move_field = |idx: usize| {
+        if src[idx] != dst[idx] {
+            base.add(src[idx]).copy_to(base.add(dst[idx]), sizes[idx]);
+        }
+    };
+    // Fields are laid out in order, so moving them towards the end of the buffer must
+    // start from the last field to avoid overwriting a field that has yet to move.
+    if towards_end {
+        (0..sizes.len()).rev().for_each(&mut move_field);
+    } else {
+        (0..sizes.len()).for_each(&mut move_field);
+    }
+}
+
+/// Moves one field of `len` rows, `stride` bytes apart and starting at `rows`, into
+/// the column at `dst`.
+///
+/// # Safety
+/// `field` must point to the field within the first row, `rows` must be valid for
+/// reads of all `len` rows, and `dst` must be valid for writes of `len` elements.
+#[inline(always)]
+unsafe fn write_column<T>(
+    dst: NonNull<T>,
+    rows: *const u8,
+    field: *const T,
+    stride: usize,
+    len: usize,
+) {
+    let offset = field.cast::<u8>().offset_from(rows) as usize;
+    for idx in 0..len {
+        let value = rows.add(idx * stride + offset).cast::<T>().read();
+        dst.as_ptr().add(idx).write(value);
+    }
 }
 
-mod private {
+pub(crate) mod private {
+    /// Prevents [`ParallelParam`](super::ParallelParam) from being implemented outside of
+    /// this crate, other than by `#[derive(ParallelVecParam)]`.
     pub trait Sealed {}
 
     macro_rules! impl_seal {
@@ -200,6 +685,7 @@ mod private {
         }
     }
 
+    impl_seal!(T1);
     impl_seal!(T1, T2);
     impl_seal!(T1, T2, T3);
     impl_seal!(T1, T2, T3, T4);
@@ -211,27 +697,34 @@ mod private {
     impl_seal!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
     impl_seal!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
     impl_seal!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+    impl_seal!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13);
+    impl_seal!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14);
+    impl_seal!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15);
+    impl_seal!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16);
 }
 
 macro_rules! skip_first {
-    ($first:ident, $second: ident) => {
+    ($first:ident, $second: tt) => {
         $second
     };
 }
 
 macro_rules! impl_parallel_vec_param {
-    ($t1: ident, $v1: ident, $($ts:ident, $vs:ident),*) => {
-        unsafe impl<$t1: 'static $(, $ts: 'static)*> ParallelParam for ($t1 $(, $ts)*) {
-            type Storage = (NonNull<$t1> $(, NonNull<$ts>)*);
-            type Ref<'a> = (&'a $t1, $(&'a $ts,)*);
-            type RefMut<'a> = (&'a mut $t1, $(&'a mut $ts,)*);
-            type Slices<'a> = (&'a [$t1] $(, &'a [$ts])*);
-            type SlicesMut<'a> = (&'a mut [$t1] $(, &'a mut [$ts])*);
-            type Vecs = (Vec<$t1> $(, Vec<$ts>)*);
-            type Ptr = (*mut $t1 $(, *mut $ts)*);
-            type Offsets = (usize $(, skip_first!($ts, usize))*);
-            type Iters<'a> = (core::slice::Iter<'a, $t1> $(, core::slice::Iter<'a, $ts>)*);
-            type ItersMut<'a>= (core::slice::IterMut<'a, $t1> $(, core::slice::IterMut<'a, $ts>)*);
+    ($t1: ident, $v1: ident $(, $ts:ident, $vs:ident)*) => {
+        unsafe impl<$t1 $(, $ts)*> ParallelParam for ($t1, $($ts,)*) {
+            type Storage = (NonNull<$t1>, $(NonNull<$ts>,)*);
+            type Ref<'a> = (&'a $t1, $(&'a $ts,)*) where Self: 'a;
+            type RefMut<'a> = (&'a mut $t1, $(&'a mut $ts,)*) where Self: 'a;
+            type Slices<'a> = (&'a [$t1], $(&'a [$ts],)*) where Self: 'a;
+            type SlicesMut<'a> = (&'a mut [$t1], $(&'a mut [$ts],)*) where Self: 'a;
+            type UninitSlicesMut<'a> = (&'a mut [MaybeUninit<$t1>], $(&'a mut [MaybeUninit<$ts>],)*) where Self: 'a;
+            type Vecs = (Vec<$t1>, $(Vec<$ts>,)*);
+            type Ptr = (NonNull<$t1>, $(NonNull<$ts>,)*);
+            type ConstPtr = (*const $t1, $(*const $ts,)*);
+            type Offsets = [usize; 1 $(+ skip_first!($ts, 1))*];
+            type Iters<'a> = (core::slice::Iter<'a, $t1>, $(core::slice::Iter<'a, $ts>,)*) where Self: 'a;
+            type ItersMut<'a> = (core::slice::IterMut<'a, $t1>, $(core::slice::IterMut<'a, $ts>,)*) where Self: 'a;
+            type Arrays<const N: usize> = (MaybeUninit<[$t1; N]>, $(MaybeUninit<[$ts; N]>,)*);
 
             #[inline(always)]
             fn dangling() -> Self::Storage {
@@ -240,57 +733,79 @@ macro_rules! impl_parallel_vec_param {
 
             #[inline(always)]
             fn as_ptr(storage: Self::Storage) -> Self::Ptr {
-                let ($t1$(, $ts)*) = storage;
-                ($t1.as_ptr() $(, $ts.as_ptr())*)
+                storage
+            }
+
+            #[inline(always)]
+            fn as_const_ptr(storage: Self::Storage) -> Self::ConstPtr {
+                let ($t1, $($ts),*) = storage;
+                ($t1.as_ptr().cast_const(), $($ts.as_ptr().cast_const(),)*)
             }
 
-            unsafe fn alloc(capacity: usize) -> Self::Storage {
-                let layout = Self::layout_for_capacity(capacity);
-                let bytes = alloc(layout.layout);
-                let (_ $(, $ts)*) = layout.offsets;
+            #[inline(always)]
+            unsafe fn from_base(base: NonNull<u8>, offsets: &Self::Offsets) -> Self::Storage {
+                let [_ $(, $ts)*] = *offsets;
                 (
-                    NonNull::new_unchecked(bytes.cast::<$t1>())
-                    $(, NonNull::new_unchecked(bytes.add($ts).cast::<$ts>()))*
+                    base.cast::<$t1>(),
+                    $(if core::mem::size_of::<$ts>() == 0 {
+                        NonNull::dangling()
+                    } else {
+                        NonNull::new_unchecked(base.as_ptr().add($ts).cast::<$ts>())
+                    },)*
                 )
             }
 
-            unsafe fn dealloc(storage: &mut Self::Storage, capacity: usize) {
-                if capacity > 0 {
-                    let layout = Self::layout_for_capacity(capacity);
-                    dealloc(storage.0.as_ptr().cast::<u8>(), layout.layout);
-                }
+            #[inline(always)]
+            fn base(storage: Self::Storage) -> NonNull<u8> {
+                storage.0.cast::<u8>()
             }
 
-            fn layout_for_capacity(capacity: usize) -> MemoryLayout<Self> {
-                let layout = Layout::array::<$t1>(capacity).unwrap();
-                $(let (layout, $ts) = layout.extend(Layout::array::<$ts>(capacity).unwrap()).unwrap();)*
-                MemoryLayout {
-                    layout,
-                    offsets: (0, $($ts),*)
-                }
+            #[inline(always)]
+            fn field_sizes(len: usize) -> Self::Offsets {
+                [
+                    core::mem::size_of::<$t1>() * len
+                    $(, core::mem::size_of::<$ts>() * len)*
+                ]
+            }
+
+            fn try_layout_for_capacity_aligned(
+                capacity: usize,
+                column_align: usize,
+            ) -> Option<MemoryLayout<Self>> {
+                let mut offsets = [0; 1 $(+ skip_first!($ts, 1))*];
+                let layout = try_fields_layout(
+                    [Layout::new::<$t1>() $(, Layout::new::<$ts>())*],
+                    capacity,
+                    column_align,
+                    &mut offsets,
+                )?;
+                Some(MemoryLayout { layout, offsets })
             }
 
             #[inline(always)]
             unsafe fn add(base: Self::Ptr, offset: usize) -> Self::Ptr {
                 let ($t1, $($ts),*) = base;
-                ($t1.add(offset), $($ts.add(offset)),*)
+                (
+                    NonNull::new_unchecked($t1.as_ptr().add(offset)),
+                    $(NonNull::new_unchecked($ts.as_ptr().add(offset)),)*
+                )
             }
 
             #[inline(always)]
             unsafe fn copy_to(src: Self::Ptr, dst: Self::Ptr, len: usize) {
                 let ($t1, $($ts),*) = src;
                 let ($v1, $($vs),*) = dst;
-                $t1.copy_to($v1, len);
-                $($ts.copy_to($vs, len);)*
+                $t1.as_ptr().copy_to($v1.as_ptr(), len);
+                $($ts.as_ptr().copy_to($vs.as_ptr(), len);)*
             }
 
             #[inline(always)]
             unsafe fn copy_to_nonoverlapping(src: Self::Ptr, dst: Self::Ptr, len: usize) {
                 let ($t1, $($ts),*) = src;
                 let ($v1, $($vs),*) = dst;
-                $t1.copy_to_nonoverlapping($v1, len);
+                $t1.as_ptr().copy_to_nonoverlapping($v1.as_ptr(), len);
                 $(
-                    $ts.copy_to_nonoverlapping($vs, len);
+                    $ts.as_ptr().copy_to_nonoverlapping($vs.as_ptr(), len);
                 )*
             }
 
@@ -298,10 +813,8 @@ macro_rules! impl_parallel_vec_param {
             unsafe fn as_slices<'a>(ptr: Self::Ptr, len: usize) -> Self::Slices<'a> {
                 let ($t1, $($ts),*) = ptr;
                 (
-                    core::slice::from_raw_parts($t1, len)
-                    $(
-                        , core::slice::from_raw_parts($ts, len)
-                    )*
+                    core::slice::from_raw_parts($t1.as_ptr(), len),
+                    $(core::slice::from_raw_parts($ts.as_ptr(), len),)*
                 )
             }
 
@@ -309,23 +822,33 @@ macro_rules! impl_parallel_vec_param {
             unsafe fn as_slices_mut<'a>(ptr: Self::Ptr, len: usize) -> Self::SlicesMut<'a> {
                 let ($t1, $($ts),*) = ptr;
                 (
-                    core::slice::from_raw_parts_mut($t1, len)
-                    $(
-                        , core::slice::from_raw_parts_mut($ts, len)
-                    )*
+                    core::slice::from_raw_parts_mut($t1.as_ptr(), len),
+                    $(core::slice::from_raw_parts_mut($ts.as_ptr(), len),)*
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn as_uninit_slices_mut<'a>(
+                ptr: Self::Ptr,
+                len: usize,
+            ) -> Self::UninitSlicesMut<'a> {
+                let ($t1, $($ts),*) = ptr;
+                (
+                    core::slice::from_raw_parts_mut($t1.cast::<MaybeUninit<$t1>>().as_ptr(), len),
+                    $(core::slice::from_raw_parts_mut($ts.cast::<MaybeUninit<$ts>>().as_ptr(), len),)*
                 )
             }
 
             #[inline(always)]
             fn iters<'a>(slices: Self::Slices<'a>) -> Self::Iters<'a> {
                 let ($t1, $($ts),*) = slices;
-                ($t1.iter() $(, $ts.iter())*)
+                ($t1.iter(), $($ts.iter(),)*)
             }
 
             #[inline(always)]
             fn iters_mut<'a>(slices: Self::SlicesMut<'a>) -> Self::ItersMut<'a> {
                 let ($t1, $($ts),*) = slices;
-                ($t1.iter_mut() $(, $ts.iter_mut())*)
+                ($t1.iter_mut(), $($ts.iter_mut(),)*)
             }
 
             #[inline(always)]
@@ -337,52 +860,84 @@ macro_rules! impl_parallel_vec_param {
 
             #[inline(always)]
             unsafe fn as_storage<'a>(ptr: Self::Ptr) -> Self::Storage {
-                let ($t1 $(, $ts)*) = ptr;
-                (
-                    NonNull::new_unchecked($t1)
-                    $(, NonNull::new_unchecked($ts))*
-                )
+                ptr
             }
 
             #[inline(always)]
             unsafe fn as_ref<'a>(ptr: Self::Ptr) -> Self::Ref<'a> {
                 let ($t1, $($ts),*) = ptr;
-                (&*$t1 $(, &*$ts)*)
+                (&*$t1.as_ptr(), $(&*$ts.as_ptr(),)*)
+            }
+
+            #[inline(always)]
+            fn field_ptrs(value: &Self) -> Self::Ptr {
+                let ($t1, $($ts,)*) = value;
+                (NonNull::from($t1), $(NonNull::from($ts),)*)
             }
 
             #[inline(always)]
             unsafe fn as_mut<'a>(ptr: Self::Ptr) -> Self::RefMut<'a> {
                 let ($t1, $($ts),*) = ptr;
-                (&mut *$t1 $(, &mut *$ts)*)
+                (&mut *$t1.as_ptr(), $(&mut *$ts.as_ptr(),)*)
             }
 
             #[inline(always)]
             unsafe fn read(ptr: Self::Ptr) -> Self {
                 let ($t1, $($ts),*) = ptr;
-                ($t1.read() $(, $ts.read())*)
+                ($t1.as_ptr().read(), $($ts.as_ptr().read(),)*)
             }
 
             #[inline(always)]
             unsafe fn write(ptr: Self::Ptr, value: Self) {
                 let ($t1, $($ts),*) = ptr;
                 let ($v1, $($vs),*) = value;
-                $t1.write($v1);
-                $($ts.write($vs);)*
+                $t1.as_ptr().write($v1);
+                $($ts.as_ptr().write($vs);)*
+            }
+
+            #[inline(always)]
+            unsafe fn write_rows(dst: Self::Ptr, src: *const Self, len: usize) {
+                if len == 0 {
+                    return;
+                }
+                // Every field sits at the same offset within each row, so the offsets
+                // are taken from the first row, and the fields are read through `src`.
+                let base = src.cast::<u8>();
+                let ($v1, $($vs,)*) = &*src;
+                let stride = core::mem::size_of::<Self>();
+                let ($t1, $($ts),*) = dst;
+                write_column($t1, base, $v1, stride, len);
+                $(write_column($ts, base, $vs, stride, len);)*
             }
 
             #[inline(always)]
             unsafe fn swap(a: Self::Ptr, b: Self::Ptr) {
                 let ($v1, $($vs),*) = a;
                 let ($t1, $($ts),*) = b;
-                core::ptr::swap($t1, $v1);
-                $(core::ptr::swap($ts, $vs);)*
+                core::ptr::swap($t1.as_ptr(), $v1.as_ptr());
+                $(core::ptr::swap($ts.as_ptr(), $vs.as_ptr());)*
+            }
+
+            #[inline(always)]
+            unsafe fn swap_nonoverlapping(a: Self::Ptr, b: Self::Ptr, len: usize) {
+                let ($v1, $($vs),*) = a;
+                let ($t1, $($ts),*) = b;
+                core::ptr::swap_nonoverlapping($t1.as_ptr(), $v1.as_ptr(), len);
+                $(core::ptr::swap_nonoverlapping($ts.as_ptr(), $vs.as_ptr(), len);)*
             }
 
             #[inline(always)]
             unsafe fn drop(ptr: Self::Ptr) {
                 let ($t1, $($ts),*) = ptr;
-                core::ptr::drop_in_place($t1);
-                $(core::ptr::drop_in_place($ts);)*
+                core::ptr::drop_in_place($t1.as_ptr());
+                $(core::ptr::drop_in_place($ts.as_ptr());)*
+            }
+
+            #[inline(always)]
+            unsafe fn drop_slice(ptr: Self::Ptr, len: usize) {
+                let ($t1, $($ts),*) = ptr;
+                core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut($t1.as_ptr(), len));
+                $(core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut($ts.as_ptr(), len));)*
             }
 
             fn get_vec_len(vecs: &Self::Vecs) -> Option<usize> {
@@ -398,22 +953,75 @@ macro_rules! impl_parallel_vec_param {
 
             unsafe fn get_vec_ptrs(vecs: &mut Self::Vecs) -> Self::Ptr {
                 let ($t1, $($ts),*) = vecs;
-                ($t1.as_mut_ptr() $(, $ts.as_mut_ptr())*)
+                (
+                    NonNull::new_unchecked($t1.as_mut_ptr()),
+                    $(NonNull::new_unchecked($ts.as_mut_ptr()),)*
+                )
+            }
+
+            fn vecs_with_capacity(capacity: usize) -> Self::Vecs {
+                (Vec::with_capacity(capacity), $(Vec::<$ts>::with_capacity(capacity),)*)
+            }
+
+            unsafe fn set_vec_len(vecs: &mut Self::Vecs, len: usize) {
+                let ($t1, $($ts),*) = vecs;
+                $t1.set_len(len);
+                $($ts.set_len(len);)*
+            }
+
+            #[inline(always)]
+            fn uninit_arrays<const N: usize>() -> Self::Arrays<N> {
+                (MaybeUninit::uninit(), $(MaybeUninit::<[$ts; N]>::uninit(),)*)
+            }
+
+            #[inline(always)]
+            fn arrays_storage<const N: usize>(arrays: &Self::Arrays<N>) -> Self::Storage {
+                let ($t1, $($ts),*) = arrays;
+                (NonNull::from($t1).cast(), $(NonNull::from($ts).cast::<$ts>(),)*)
+            }
+
+            #[inline(always)]
+            fn arrays_storage_mut<const N: usize>(arrays: &mut Self::Arrays<N>) -> Self::Storage {
+                let ($t1, $($ts),*) = arrays;
+                (NonNull::from($t1).cast(), $(NonNull::from($ts).cast::<$ts>(),)*)
+            }
+
+            fn get_slices_len(slices: &Self::SlicesMut<'_>) -> Option<usize> {
+                let ($t1, $($ts),*) = slices;
+                let len = $t1.len();
+                $(
+                    if $ts.len() != len {
+                        return None;
+                    }
+                )*
+                Some(len)
+            }
+
+            fn get_slices_ptrs(slices: &mut Self::SlicesMut<'_>) -> Self::Ptr {
+                let ($t1, $($ts),*) = slices;
+                (
+                    NonNull::from(&mut **$t1).cast::<$t1>(),
+                    $(NonNull::from(&mut **$ts).cast::<$ts>(),)*
+                )
             }
         }
 
-        impl<$t1: 'static $(, $ts: 'static)*> TryFrom<(Vec<$t1> $(, Vec<$ts>)*)> for ParallelVec<($t1 $(, $ts)*)> {
+        impl<$t1 $(, $ts)*> TryFrom<(Vec<$t1>, $(Vec<$ts>,)*)> for ParallelVec<($t1, $($ts,)*)> {
             type Error = ParallelVecConversionError;
-            fn try_from(mut vecs: (Vec<$t1> $(, Vec<$ts>)*)) -> Result<Self, Self::Error> {
-                let len = <($t1 $(, $ts)*) as ParallelParam>::get_vec_len(&vecs);
+            fn try_from(mut vecs: (Vec<$t1>, $(Vec<$ts>,)*)) -> Result<Self, Self::Error> {
+                let len = <($t1, $($ts,)*) as ParallelParam>::get_vec_len(&vecs);
                 if let Some(len) = len {
-                    let parallel_vec = Self::with_capacity(len);
+                    let mut parallel_vec = Self::with_capacity(len);
                     // SAFE: This is a move. Nothing should be dropped here.
                     unsafe {
-                        let src = <($t1 $(, $ts)*) as ParallelParam>::get_vec_ptrs(&mut vecs);
-                        let dst = <($t1 $(, $ts)*) as ParallelParam>::as_ptr(parallel_vec.storage);
-                        <($t1 $(, $ts)*) as ParallelParam>::copy_to_nonoverlapping(src, dst, len);
-                        core::mem::forget(vecs);
+                        let src = <($t1, $($ts,)*) as ParallelParam>::get_vec_ptrs(&mut vecs);
+                        let dst = <($t1, $($ts,)*) as ParallelParam>::as_ptr(parallel_vec.storage);
+                        <($t1, $($ts,)*) as ParallelParam>::copy_to_nonoverlapping(src, dst, len);
+                        parallel_vec.len = len;
+                        // The values have been moved out, only free the buffers of the `Vec`s.
+                        let ($t1, $($ts,)*) = &mut vecs;
+                        $t1.set_len(0);
+                        $($ts.set_len(0);)*
                     }
                     Ok(parallel_vec)
                 } else {
@@ -421,9 +1029,57 @@ macro_rules! impl_parallel_vec_param {
                 }
             }
         }
+
+        impl<'a, $t1: Clone $(, $ts: Clone)*> TryFrom<(&'a [$t1], $(&'a [$ts],)*)>
+            for ParallelVec<($t1, $($ts,)*)>
+        {
+            type Error = ParallelVecConversionError;
+            fn try_from(slices: (&'a [$t1], $(&'a [$ts],)*)) -> Result<Self, Self::Error> {
+                Self::try_from_slices(slices)
+            }
+        }
+
+        impl<$t1: Clone $(, $ts: Clone)*> ParallelVec<($t1, $($ts,)*)> {
+            /// Creates a [`ParallelVec`] from clones of the values in one slice per field.
+            ///
+            /// The vector is allocated once, and the rows are cloned in order. This is
+            /// equivalent to converting the slices with `TryFrom`.
+            ///
+            /// If cloning a value panics, the rows that were already cloned are dropped.
+            ///
+            /// # Errors
+            /// Returns [`ParallelVecConversionError::UnevenLengths`] if the slices are not
+            /// all the same length.
+            pub fn try_from_slices(
+                slices: (&[$t1], $(&[$ts],)*),
+            ) -> Result<Self, ParallelVecConversionError> {
+                let ($v1, $($vs,)*) = slices;
+                let len = $v1.len();
+                if $($vs.len() != len ||)* false {
+                    return Err(ParallelVecConversionError::UnevenLengths);
+                }
+                let mut parallel_vec = Self::with_capacity(len);
+                let dst = <($t1, $($ts,)*) as ParallelParam>::as_ptr(parallel_vec.storage);
+                for idx in 0..len {
+                    let value = ($v1[idx].clone(), $($vs[idx].clone(),)*);
+                    // SAFE: The vector has the capacity for every row.
+                    unsafe {
+                        <($t1, $($ts,)*) as ParallelParam>::write(
+                            <($t1, $($ts,)*) as ParallelParam>::add(dst, idx),
+                            value,
+                        );
+                    }
+                    // Only count the row once it has been written, so a panicking clone
+                    // leaves the vector with only fully initialized rows to drop.
+                    parallel_vec.len += 1;
+                }
+                Ok(parallel_vec)
+            }
+        }
     }
 }
 
+impl_parallel_vec_param!(T1, V1);
 impl_parallel_vec_param!(T1, V1, T2, V2);
 impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3);
 impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4);
@@ -431,14 +1087,134 @@ impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4, T5, V5);
 impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6);
 impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7);
 impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7, T8, V8);
-impl_parallel_vec_param!(T1, V1, T2, V2, T3, T4, V3, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9);
+impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9);
 impl_parallel_vec_param!(
-    T1, V1, T2, V2, T3, T4, V3, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10
+    T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10
 );
 impl_parallel_vec_param!(
-    T1, V1, T2, V2, T3, T4, V3, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10, T11, V11
+    T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10, T11, V11
 );
 impl_parallel_vec_param!(
-    T1, V1, T2, V2, T3, T4, V3, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10, T11, V11,
+    T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10, T11, V11,
     T12, V12
 );
+impl_parallel_vec_param!(
+    T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10, T11, V11,
+    T12, V12, T13, V13
+);
+impl_parallel_vec_param!(
+    T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10, T11, V11,
+    T12, V12, T13, V13, T14, V14
+);
+impl_parallel_vec_param!(
+    T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10, T11, V11,
+    T12, V12, T13, V13, T14, V14, T15, V15
+);
+impl_parallel_vec_param!(
+    T1, V1, T2, V2, T3, V3, T4, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10, T11, V11,
+    T12, V12, T13, V13, T14, V14, T15, V15, T16, V16
+);
+
+macro_rules! impl_parallel_column {
+    (@impl [$($ts:ident),*] $idx:tt $t:ident) => {
+        impl<$($ts),*> ParallelColumn<$idx> for ($($ts,)*) {
+            type Column = $t;
+
+            #[inline(always)]
+            fn column<'a>(slices: Self::Slices<'a>) -> &'a [$t]
+            where
+                Self: 'a,
+            {
+                slices.$idx
+            }
+
+            #[inline(always)]
+            fn column_storage(storage: Self::Storage) -> NonNull<$t> {
+                storage.$idx
+            }
+        }
+    };
+    ($ts:tt $($idx:tt $t:ident),*) => {
+        $(impl_parallel_column!(@impl $ts $idx $t);)*
+    };
+}
+
+impl_parallel_column!([T1] 0 T1);
+impl_parallel_column!([T1, T2] 0 T1, 1 T2);
+impl_parallel_column!([T1, T2, T3] 0 T1, 1 T2, 2 T3);
+impl_parallel_column!([T1, T2, T3, T4] 0 T1, 1 T2, 2 T3, 3 T4);
+impl_parallel_column!([T1, T2, T3, T4, T5] 0 T1, 1 T2, 2 T3, 3 T4, 4 T5);
+impl_parallel_column!([T1, T2, T3, T4, T5, T6] 0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6);
+impl_parallel_column!([T1, T2, T3, T4, T5, T6, T7] 0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8]
+    0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9]
+    0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10]
+    0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11]
+    0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10, 10 T11
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12]
+    0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10, 10 T11, 11 T12
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13]
+    0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10, 10 T11, 11 T12, 12 T13
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14]
+    0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10, 10 T11, 11 T12, 12 T13, 13 T14
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15]
+    0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10, 10 T11, 11 T12, 12 T13, 13 T14,
+    14 T15
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16]
+    0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10, 10 T11, 11 T12, 12 T13, 13 T14,
+    14 T15, 15 T16
+);
+
+macro_rules! impl_parallel_selection {
+    ($($is:ident),*) => {
+        impl<Param, $(const $is: usize),*> ParallelSelection<Param> for ($(Column<$is>,)*)
+        where
+            Param: ParallelParam $(+ ParallelColumn<$is>)*,
+        {
+            type Output = ($(<Param as ParallelColumn<$is>>::Column,)*);
+            const INDICES: &'static [usize] = &[$($is),*];
+
+            #[inline(always)]
+            fn select(storage: Param::Storage) -> <Self::Output as ParallelParam>::Storage {
+                ($(<Param as ParallelColumn<$is>>::column_storage(storage),)*)
+            }
+        }
+    };
+}
+
+impl_parallel_selection!(I1);
+impl_parallel_selection!(I1, I2);
+impl_parallel_selection!(I1, I2, I3);
+impl_parallel_selection!(I1, I2, I3, I4);
+impl_parallel_selection!(I1, I2, I3, I4, I5);
+impl_parallel_selection!(I1, I2, I3, I4, I5, I6);
+impl_parallel_selection!(I1, I2, I3, I4, I5, I6, I7);
+impl_parallel_selection!(I1, I2, I3, I4, I5, I6, I7, I8);
+impl_parallel_selection!(I1, I2, I3, I4, I5, I6, I7, I8, I9);
+impl_parallel_selection!(I1, I2, I3, I4, I5, I6, I7, I8, I9, I10);
+impl_parallel_selection!(I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11);
+impl_parallel_selection!(I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12);
+impl_parallel_selection!(I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13);
+impl_parallel_selection!(I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14);
+impl_parallel_selection!(I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14, I15);
+impl_parallel_selection!(I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14, I15, I16);
@@ -0,0 +1,180 @@
+use crate::{ParallelParam, ParallelVec};
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use proptest::{
+    arbitrary::{any_with, Arbitrary},
+    collection::{vec, SizeRange, VecStrategy, VecValueTree},
+    strategy::{statics, NewTree, Strategy, ValueTree},
+    test_runner::TestRunner,
+};
+
+/// Creates a strategy to generate [`ParallelVec`]s containing elements drawn from
+/// `element` and with a size range given by `size`.
+///
+/// Like [`proptest::collection::vec`], values are shrunk by first removing elements,
+/// then by shrinking the individual elements.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::parallel_vec;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn len_is_preserved(vec in parallel_vec(any::<(u32, bool)>(), 0..100)) {
+///         let (ids, flags) = vec.as_slices();
+///         prop_assert_eq!(ids.len(), vec.len());
+///         prop_assert_eq!(flags.len(), vec.len());
+///     }
+/// }
+/// # len_is_preserved();
+/// ```
+pub fn parallel_vec<T>(element: T, size: impl Into<SizeRange>) -> ParallelVecStrategy<T>
+where
+    T: Strategy,
+    T::Value: ParallelParam,
+{
+    ParallelVecStrategy(statics::Map::new(vec(element, size), VecToParallelVec))
+}
+
+/// Strategy to create [`ParallelVec`]s with a length in a certain range.
+///
+/// Created by [`parallel_vec`].
+#[derive(Clone)]
+#[must_use = "strategies do nothing unless used"]
+pub struct ParallelVecStrategy<T: Strategy>(statics::Map<VecStrategy<T>, VecToParallelVec>);
+
+/// [`ValueTree`] corresponding to [`ParallelVecStrategy`].
+#[derive(Clone)]
+pub struct ParallelVecValueTree<T: ValueTree>(statics::Map<VecValueTree<T>, VecToParallelVec>);
+
+impl<T: Strategy + Debug> Debug for ParallelVecStrategy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ParallelVecStrategy").field(&self.0).finish()
+    }
+}
+
+impl<T: ValueTree + Debug> Debug for ParallelVecValueTree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ParallelVecValueTree")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<T> Strategy for ParallelVecStrategy<T>
+where
+    T: Strategy,
+    T::Value: ParallelParam,
+    ParallelVec<T::Value>: Debug,
+{
+    type Tree = ParallelVecValueTree<T::Tree>;
+    type Value = ParallelVec<T::Value>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        self.0.new_tree(runner).map(ParallelVecValueTree)
+    }
+}
+
+impl<T> ValueTree for ParallelVecValueTree<T>
+where
+    T: ValueTree,
+    T::Value: ParallelParam,
+    ParallelVec<T::Value>: Debug,
+{
+    type Value = ParallelVec<T::Value>;
+
+    fn current(&self) -> Self::Value {
+        self.0.current()
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.0.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.0.complicate()
+    }
+}
+
+impl<Param> Arbitrary for ParallelVec<Param>
+where
+    Param: ParallelParam + Arbitrary,
+    ParallelVec<Param>: Debug,
+{
+    type Parameters = (SizeRange, Param::Parameters);
+    type Strategy = ParallelVecStrategy<Param::Strategy>;
+
+    fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+        parallel_vec(any_with::<Param>(params), size)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct VecToParallelVec;
+
+impl<Param> statics::MapFn<Vec<Param>> for VecToParallelVec
+where
+    Param: ParallelParam,
+    ParallelVec<Param>: Debug,
+{
+    type Output = ParallelVec<Param>;
+
+    fn apply(&self, vec: Vec<Param>) -> ParallelVec<Param> {
+        ParallelVec::from(vec)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parallel_vec;
+    use crate::ParallelVec;
+    use proptest::{
+        prelude::*,
+        strategy::ValueTree,
+        test_runner::{TestCaseError, TestRunner},
+    };
+
+    proptest! {
+        #[test]
+        fn test_parallel_vec_len(vec in parallel_vec(any::<(u8, u32)>(), 3..10)) {
+            prop_assert!((3..10).contains(&vec.len()));
+            let (a, b) = vec.as_slices();
+            prop_assert_eq!(a.len(), vec.len());
+            prop_assert_eq!(b.len(), vec.len());
+        }
+
+        #[test]
+        fn test_arbitrary(vec in any::<ParallelVec<(i16, bool)>>()) {
+            let rows: std::vec::Vec<(i16, bool)> = vec.iter().map(|(a, b)| (*a, *b)).collect();
+            prop_assert!(vec == rows[..]);
+        }
+    }
+
+    #[test]
+    fn test_shrinks_to_minimal_failure() {
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&parallel_vec((0u32..1000,), 0..50), |vec| {
+            if vec.iter().any(|(value,)| *value >= 500) {
+                Err(TestCaseError::fail("too large"))
+            } else {
+                Ok(())
+            }
+        });
+        match result {
+            Err(proptest::test_runner::TestError::Fail(_, vec)) => {
+                assert!(vec == [(500,)][..]);
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_value_tree_simplifies() {
+        let mut runner = TestRunner::deterministic();
+        let mut tree = parallel_vec(any::<(u8, u8)>(), 5..10)
+            .new_tree(&mut runner)
+            .unwrap();
+        while tree.simplify() {}
+        assert_eq!(tree.current().len(), 5);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{ParallelParam, ParallelVec};
+use alloc::{boxed::Box, vec::Vec};
+use quickcheck::{Arbitrary, Gen};
+
+impl<Param> Arbitrary for ParallelVec<Param>
+where
+    Param: ParallelParam + Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Vec::<Param>::arbitrary(g).into()
+    }
+
+    /// Shrinks the vector the same way as the equivalent `Vec`: first by removing rows,
+    /// then by shrinking the individual rows one field at a time.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let rows: Vec<Param> = self.clone().into_iter().collect();
+        Box::new(rows.shrink().map(ParallelVec::from))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ParallelVec;
+    use quickcheck::{quickcheck, Arbitrary, Gen};
+    use std::vec::Vec;
+
+    quickcheck! {
+        fn test_arbitrary_rows_match_slices(vec: ParallelVec<(u8, i32)>) -> bool {
+            let (a, b) = vec.as_slices();
+            a.len() == vec.len() && b.len() == vec.len()
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_respects_size() {
+        let mut g = Gen::new(10);
+        for _ in 0..100 {
+            assert!(ParallelVec::<(u8, bool)>::arbitrary(&mut g).len() < 10);
+        }
+    }
+
+    #[test]
+    fn test_shrink_rows_then_columns() {
+        let vec = ParallelVec::from(vec![(1u8, 2u8), (3, 4)]);
+        let shrunk: Vec<ParallelVec<(u8, u8)>> = vec.shrink().collect();
+        // Removing rows comes first, starting from the empty vector.
+        assert!(shrunk[0].is_empty());
+        assert!(shrunk[1] == [(3, 4)][..]);
+        assert!(shrunk[2] == [(1, 2)][..]);
+        // Followed by shrinking each field of each row.
+        assert!(shrunk[3..].iter().all(|vec| vec.len() == 2));
+        assert!(shrunk.iter().any(|vec| *vec == [(0, 2), (3, 4)][..]));
+        assert!(shrunk.iter().any(|vec| *vec == [(1, 0), (3, 4)][..]));
+        assert!(shrunk.iter().any(|vec| *vec == [(1, 2), (0, 4)][..]));
+    }
+
+    #[test]
+    fn test_shrink_empty() {
+        let vec: ParallelVec<(u8, u8)> = ParallelVec::new();
+        assert_eq!(vec.shrink().count(), 0);
+    }
+}
@@ -0,0 +1,1112 @@
+use crate::iter::{IntoIter, Iter, IterMut};
+use crate::{GrowthPolicy, ParallelParam, ParallelSlice, ParallelSliceMut, ParallelVec, Storage};
+use allocator_api2::alloc::Global;
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{
+    FromParallelIterator, IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+    ParallelExtend, ParallelIterator,
+};
+use rayon::slice::ParallelSliceMut as _;
+use std::collections::LinkedList;
+use std::vec::Vec;
+
+/// A parallel iterator over immutable references to values in a [`ParallelSlice`].
+///
+/// Created by calling `par_iter` on a [`ParallelSlice`], [`ParallelSliceMut`], or
+/// [`ParallelVec`].
+pub struct ParIter<'a, Param: ParallelParam> {
+    ptr: Param::Ptr,
+    len: usize,
+    _marker: PhantomData<&'a Param>,
+}
+
+// SAFE: The iterator only gives out shared references to the elements.
+unsafe impl<'a, Param: ParallelParam + Sync> Send for ParIter<'a, Param> {}
+
+/// A parallel iterator over mutable references to values in a [`ParallelSliceMut`].
+///
+/// Created by calling `par_iter_mut` on a [`ParallelSliceMut`] or [`ParallelVec`].
+pub struct ParIterMut<'a, Param: ParallelParam> {
+    ptr: Param::Ptr,
+    len: usize,
+    _marker: PhantomData<&'a mut Param>,
+}
+
+// SAFE: The iterator uniquely borrows the elements.
+unsafe impl<'a, Param: ParallelParam + Send> Send for ParIterMut<'a, Param> {}
+
+/// A parallel iterator over values from a [`ParallelVec`].
+///
+/// Created by calling `into_par_iter` on a [`ParallelVec`].
+pub struct IntoParIter<Param: ParallelParam, A: Storage = Global> {
+    iter: IntoIter<Param, A>,
+}
+
+/// A parallel iterator over a [`ParallelSliceMut`] in non-overlapping mutable chunks of
+/// `chunk_size` elements, starting at the beginning of the slice. Each chunk is a set of
+/// mutable slices, one for each field.
+///
+/// When the length of the slice is not evenly divided by the chunk size, the last chunk
+/// will be shorter.
+///
+/// Created by calling [`ParallelSliceMut::par_chunks_mut`].
+pub struct ParChunksMut<'a, Param: ParallelParam> {
+    ptr: Param::Ptr,
+    len: usize,
+    chunk_size: usize,
+    _marker: PhantomData<&'a mut Param>,
+}
+
+// SAFE: The iterator uniquely borrows the elements.
+unsafe impl<'a, Param: ParallelParam + Send> Send for ParChunksMut<'a, Param> {}
+
+struct IterProducer<'a, Param: ParallelParam> {
+    ptr: Param::Ptr,
+    len: usize,
+    _marker: PhantomData<&'a Param>,
+}
+
+// SAFE: The producer only gives out shared references to the elements.
+unsafe impl<'a, Param: ParallelParam + Sync> Send for IterProducer<'a, Param> {}
+
+struct IterMutProducer<'a, Param: ParallelParam> {
+    ptr: Param::Ptr,
+    len: usize,
+    _marker: PhantomData<&'a mut Param>,
+}
+
+// SAFE: The producer uniquely borrows the elements.
+unsafe impl<'a, Param: ParallelParam + Send> Send for IterMutProducer<'a, Param> {}
+
+struct ChunksMutProducer<'a, Param: ParallelParam> {
+    ptr: Param::Ptr,
+    len: usize,
+    chunk_size: usize,
+    _marker: PhantomData<&'a mut Param>,
+}
+
+// SAFE: The producer uniquely borrows the elements.
+unsafe impl<'a, Param: ParallelParam + Send> Send for ChunksMutProducer<'a, Param> {}
+
+/// The sequential iterator of a [`ChunksMutProducer`].
+struct ChunksMut<'a, Param: ParallelParam> {
+    ptr: Param::Ptr,
+    len: usize,
+    chunk_size: usize,
+    _marker: PhantomData<&'a mut Param>,
+}
+
+impl<'a, Param: ParallelParam + 'a> Iterator for ChunksMut<'a, Param> {
+    type Item = Param::SlicesMut<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let len = self.chunk_size.min(self.len);
+        // SAFE: The iterator uniquely borrows the `len` elements starting at `ptr`, and
+        // every chunk is only given out once.
+        unsafe {
+            let chunk = Param::as_slices_mut(self.ptr, len);
+            self.ptr = Param::add(self.ptr, len);
+            self.len -= len;
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = (self.len + self.chunk_size - 1) / self.chunk_size;
+        (chunks, Some(chunks))
+    }
+}
+
+impl<'a, Param: ParallelParam + 'a> ExactSizeIterator for ChunksMut<'a, Param> {}
+
+impl<'a, Param: ParallelParam + 'a> DoubleEndedIterator for ChunksMut<'a, Param> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let len = match self.len % self.chunk_size {
+            0 => self.chunk_size,
+            remainder => remainder,
+        };
+        self.len -= len;
+        // SAFE: The iterator uniquely borrows the elements, and every chunk is only given
+        // out once.
+        unsafe { Some(Param::as_slices_mut(Param::add(self.ptr, self.len), len)) }
+    }
+}
+
+/// Moves the elements out of a range of a buffer. Any elements that are not consumed
+/// are dropped when the producer is dropped.
+struct DrainProducer<'a, Param: ParallelParam> {
+    ptr: Param::Ptr,
+    len: usize,
+    _marker: PhantomData<&'a mut Param>,
+}
+
+// SAFE: The producer owns the elements.
+unsafe impl<'a, Param: ParallelParam + Send> Send for DrainProducer<'a, Param> {}
+
+impl<'a, Param: ParallelParam> Drop for DrainProducer<'a, Param> {
+    fn drop(&mut self) {
+        // SAFE: The producer owns the `len` elements starting at `ptr`.
+        unsafe { Param::drop_slice(self.ptr, self.len) };
+    }
+}
+
+/// The sequential iterator of a [`DrainProducer`].
+struct DrainIter<'a, Param: ParallelParam> {
+    ptr: Param::Ptr,
+    remaining: usize,
+    _marker: PhantomData<&'a mut Param>,
+}
+
+impl<'a, Param: ParallelParam> Iterator for DrainIter<'a, Param> {
+    type Item = Param;
+    fn next(&mut self) -> Option<Param> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFE: The iterator owns the `remaining` elements starting at `ptr`.
+        unsafe {
+            let value = Param::read(self.ptr);
+            self.ptr = Param::add(self.ptr, 1);
+            self.remaining -= 1;
+            Some(value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, Param: ParallelParam> ExactSizeIterator for DrainIter<'a, Param> {}
+
+impl<'a, Param: ParallelParam> DoubleEndedIterator for DrainIter<'a, Param> {
+    fn next_back(&mut self) -> Option<Param> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        // SAFE: The iterator owns the `remaining` elements starting at `ptr`.
+        unsafe { Some(Param::read(Param::add(self.ptr, self.remaining))) }
+    }
+}
+
+impl<'a, Param: ParallelParam> Drop for DrainIter<'a, Param> {
+    fn drop(&mut self) {
+        // SAFE: The iterator owns the `remaining` elements starting at `ptr`.
+        unsafe { Param::drop_slice(self.ptr, self.remaining) };
+    }
+}
+
+impl<'a, Param> Producer for IterProducer<'a, Param>
+where
+    Param: ParallelParam + Sync + 'a,
+    Param::Ref<'a>: Send,
+{
+    type Item = Param::Ref<'a>;
+    type IntoIter = Iter<'a, Param>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::<'a, Param> {
+            ptr: self.ptr,
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        // SAFE: `index` is at most `len`, so the pointer stays in bounds.
+        let right = unsafe { Param::add(self.ptr, index) };
+        (
+            Self {
+                ptr: self.ptr,
+                len: index,
+                _marker: PhantomData,
+            },
+            Self {
+                ptr: right,
+                len: self.len - index,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<'a, Param> Producer for IterMutProducer<'a, Param>
+where
+    Param: ParallelParam + Send + 'a,
+    Param::RefMut<'a>: Send,
+{
+    type Item = Param::RefMut<'a>;
+    type IntoIter = IterMut<'a, Param>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::<'a, Param> {
+            ptr: self.ptr,
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        // SAFE: `index` is at most `len`, so the pointer stays in bounds.
+        let right = unsafe { Param::add(self.ptr, index) };
+        (
+            Self {
+                ptr: self.ptr,
+                len: index,
+                _marker: PhantomData,
+            },
+            Self {
+                ptr: right,
+                len: self.len - index,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<'a, Param> Producer for ChunksMutProducer<'a, Param>
+where
+    Param: ParallelParam + Send + 'a,
+    Param::SlicesMut<'a>: Send,
+{
+    type Item = Param::SlicesMut<'a>;
+    type IntoIter = ChunksMut<'a, Param>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ChunksMut::<'a, Param> {
+            ptr: self.ptr,
+            len: self.len,
+            chunk_size: self.chunk_size,
+            _marker: PhantomData,
+        }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = (index * self.chunk_size).min(self.len);
+        // SAFE: `mid` is at most `len`, so the pointer stays in bounds.
+        let right = unsafe { Param::add(self.ptr, mid) };
+        (
+            Self {
+                ptr: self.ptr,
+                len: mid,
+                chunk_size: self.chunk_size,
+                _marker: PhantomData,
+            },
+            Self {
+                ptr: right,
+                len: self.len - mid,
+                chunk_size: self.chunk_size,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<'a, Param: ParallelParam + Send + 'a> Producer for DrainProducer<'a, Param> {
+    type Item = Param;
+    type IntoIter = DrainIter<'a, Param>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let this = ManuallyDrop::new(self);
+        DrainIter {
+            ptr: this.ptr,
+            remaining: this.len,
+            _marker: PhantomData,
+        }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let this = ManuallyDrop::new(self);
+        // SAFE: `index` is at most `len`, so the pointer stays in bounds.
+        let right = unsafe { Param::add(this.ptr, index) };
+        (
+            Self {
+                ptr: this.ptr,
+                len: index,
+                _marker: PhantomData,
+            },
+            Self {
+                ptr: right,
+                len: this.len - index,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<'a, Param> ParallelIterator for ParIter<'a, Param>
+where
+    Param: ParallelParam + Sync + 'a,
+    Param::Ref<'a>: Send,
+{
+    type Item = Param::Ref<'a>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'a, Param> IndexedParallelIterator for ParIter<'a, Param>
+where
+    Param: ParallelParam + Sync + 'a,
+    Param::Ref<'a>: Send,
+{
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(IterProducer::<'a, Param> {
+            ptr: self.ptr,
+            len: self.len,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'a, Param> ParallelIterator for ParIterMut<'a, Param>
+where
+    Param: ParallelParam + Send + 'a,
+    Param::RefMut<'a>: Send,
+{
+    type Item = Param::RefMut<'a>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'a, Param> IndexedParallelIterator for ParIterMut<'a, Param>
+where
+    Param: ParallelParam + Send + 'a,
+    Param::RefMut<'a>: Send,
+{
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(IterMutProducer::<'a, Param> {
+            ptr: self.ptr,
+            len: self.len,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'a, Param> ParallelIterator for ParChunksMut<'a, Param>
+where
+    Param: ParallelParam + Send + 'a,
+    Param::SlicesMut<'a>: Send,
+{
+    type Item = Param::SlicesMut<'a>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(IndexedParallelIterator::len(self))
+    }
+}
+
+impl<'a, Param> IndexedParallelIterator for ParChunksMut<'a, Param>
+where
+    Param: ParallelParam + Send + 'a,
+    Param::SlicesMut<'a>: Send,
+{
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        (self.len + self.chunk_size - 1) / self.chunk_size
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(ChunksMutProducer::<'a, Param> {
+            ptr: self.ptr,
+            len: self.len,
+            chunk_size: self.chunk_size,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<Param: ParallelParam + Send, A: Storage + Send> ParallelIterator for IntoParIter<Param, A> {
+    type Item = Param;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl<Param: ParallelParam + Send, A: Storage + Send> IndexedParallelIterator
+    for IntoParIter<Param, A>
+{
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(mut self, callback: CB) -> CB::Output {
+        let len = self.iter.len();
+        // SAFE: The elements are moved into the producer, which drops any that are not
+        // consumed. `self.iter` no longer owns them, and only frees the buffer once the
+        // producer is done.
+        unsafe {
+            let ptr = Param::ptr_at(self.iter.storage, self.iter.idx);
+            self.iter.len = self.iter.idx;
+            callback.callback(DrainProducer {
+                ptr,
+                len,
+                _marker: PhantomData,
+            })
+        }
+    }
+}
+
+impl<'a, Param> IntoParallelIterator for &'a ParallelSlice<'_, Param>
+where
+    Param: ParallelParam + Sync + 'a,
+    Param::Ref<'a>: Send,
+{
+    type Item = Param::Ref<'a>;
+    type Iter = ParIter<'a, Param>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, Param> IntoParallelIterator for &'a ParallelSliceMut<'_, Param>
+where
+    Param: ParallelParam + Sync + 'a,
+    Param::Ref<'a>: Send,
+{
+    type Item = Param::Ref<'a>;
+    type Iter = ParIter<'a, Param>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, Param> IntoParallelIterator for &'a mut ParallelSliceMut<'_, Param>
+where
+    Param: ParallelParam + Send + 'a,
+    Param::RefMut<'a>: Send,
+{
+    type Item = Param::RefMut<'a>;
+    type Iter = ParIterMut<'a, Param>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, Param, Growth, A> IntoParallelIterator for &'a ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + Sync + 'a,
+    Param::Ref<'a>: Send,
+    Growth: GrowthPolicy,
+    A: Storage,
+{
+    type Item = Param::Ref<'a>;
+    type Iter = ParIter<'a, Param>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, Param, Growth, A> IntoParallelIterator for &'a mut ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + Send + 'a,
+    Param::RefMut<'a>: Send,
+    Growth: GrowthPolicy,
+    A: Storage,
+{
+    type Item = Param::RefMut<'a>;
+    type Iter = ParIterMut<'a, Param>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Param, Growth, A> IntoParallelIterator for ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + Send,
+    Growth: GrowthPolicy,
+    A: Storage + Send,
+{
+    type Item = Param;
+    type Iter = IntoParIter<Param, A>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        IntoParIter {
+            iter: self.into_iter(),
+        }
+    }
+}
+
+impl<Param, Growth, A> ParallelExtend<Param> for ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + Send,
+    Growth: GrowthPolicy,
+    A: Storage,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = Param>,
+    {
+        // Every thread collects into its own buffer, and the buffers are then moved into
+        // the vector one column at a time.
+        let buffers = par_iter
+            .into_par_iter()
+            .fold(ParallelVec::<Param>::new, |mut buffer, item| {
+                buffer.push(item);
+                buffer
+            })
+            .map(|buffer| {
+                let mut list = LinkedList::new();
+                list.push_back(buffer);
+                list
+            })
+            .reduce(LinkedList::new, |mut left, mut right| {
+                left.append(&mut right);
+                left
+            });
+        self.reserve(buffers.iter().map(|buffer| buffer.len).sum());
+        for mut buffer in buffers {
+            self.append(&mut buffer);
+        }
+    }
+}
+
+impl<Param, Growth, A> FromParallelIterator<Param> for ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + Send,
+    Growth: GrowthPolicy + Default,
+    A: Storage + Default,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = Param>,
+    {
+        let mut vec = Self::with_capacity_and_growth_policy_in(0, Growth::default(), A::default());
+        vec.par_extend(par_iter);
+        vec
+    }
+}
+
+impl<'a, Param: ParallelParam + Send> ParallelSliceMut<'a, Param> {
+    /// Returns a parallel iterator over `chunk_size` elements of the slice at a time, as
+    /// a set of mutable slices, one for each field. The chunks are mutable and do not
+    /// overlap, so each one can be updated on a different thread.
+    ///
+    /// If `chunk_size` does not divide the length of the slice, then the last chunk will
+    /// not have length `chunk_size`.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut vec: ParallelVec<(f32, f32)> = (0..100).map(|i| (i as f32, 1.0)).collect();
+    /// vec.as_parallel_slice_mut().par_chunks_mut(16).for_each(|(positions, velocities)| {
+    ///     for (position, velocity) in positions.iter_mut().zip(velocities.iter()) {
+    ///         *position += *velocity;
+    ///     }
+    /// });
+    /// assert_eq!(vec.index(99), (&100.0, &1.0));
+    /// ```
+    pub fn par_chunks_mut(&mut self, chunk_size: usize) -> ParChunksMut<'_, Param> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        ParChunksMut {
+            ptr: Param::as_ptr(self.storage),
+            len: self.len,
+            chunk_size,
+            _marker: PhantomData,
+        }
+    }
+}
+
+macro_rules! impl_par_apply_columns {
+    ($($ts:ident $vs:ident $fs:ident $rs:ident $idx:tt),*) => {
+        impl<'a, $($ts: Send),*> ParallelSliceMut<'a, ($($ts,)*)> {
+            /// Runs a separate closure over each field concurrently, one task per field,
+            /// and returns the results of every closure.
+            ///
+            /// This is useful when the transforms of each field are independent of each
+            /// other, and avoids destructuring [`as_slices_mut`] and spawning the tasks
+            /// manually.
+            ///
+            /// [`as_slices_mut`]: Self::as_slices_mut
+            pub fn par_apply_columns<$($fs, $rs),*>(&mut self, fns: ($($fs,)*)) -> ($($rs,)*)
+            where
+                $($fs: FnOnce(&mut [$ts]) -> $rs + Send, $rs: Send,)*
+            {
+                let ($($vs,)*) = self.as_slices_mut();
+                let mut results = ($(None::<$rs>,)*);
+                rayon::scope(|scope| {
+                    $(
+                        let result = &mut results.$idx;
+                        let f = fns.$idx;
+                        scope.spawn(move |_| *result = Some(f($vs)));
+                    )*
+                });
+                // Every task has completed, and a panic in any of them would have been
+                // propagated by the scope.
+                ($(results.$idx.unwrap(),)*)
+            }
+        }
+    };
+}
+
+impl_par_apply_columns!(T1 V1 F1 R1 0);
+impl_par_apply_columns!(T1 V1 F1 R1 0, T2 V2 F2 R2 1);
+impl_par_apply_columns!(T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2);
+impl_par_apply_columns!(T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4
+);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4, T6 V6 F6 R6 5
+);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4, T6 V6 F6 R6 5,
+    T7 V7 F7 R7 6
+);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4, T6 V6 F6 R6 5,
+    T7 V7 F7 R7 6, T8 V8 F8 R8 7
+);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4, T6 V6 F6 R6 5,
+    T7 V7 F7 R7 6, T8 V8 F8 R8 7, T9 V9 F9 R9 8
+);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4, T6 V6 F6 R6 5,
+    T7 V7 F7 R7 6, T8 V8 F8 R8 7, T9 V9 F9 R9 8, T10 V10 F10 R10 9
+);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4, T6 V6 F6 R6 5,
+    T7 V7 F7 R7 6, T8 V8 F8 R8 7, T9 V9 F9 R9 8, T10 V10 F10 R10 9, T11 V11 F11 R11 10
+);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4, T6 V6 F6 R6 5,
+    T7 V7 F7 R7 6, T8 V8 F8 R8 7, T9 V9 F9 R9 8, T10 V10 F10 R10 9, T11 V11 F11 R11 10,
+    T12 V12 F12 R12 11
+);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4, T6 V6 F6 R6 5,
+    T7 V7 F7 R7 6, T8 V8 F8 R8 7, T9 V9 F9 R9 8, T10 V10 F10 R10 9, T11 V11 F11 R11 10,
+    T12 V12 F12 R12 11, T13 V13 F13 R13 12
+);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4, T6 V6 F6 R6 5,
+    T7 V7 F7 R7 6, T8 V8 F8 R8 7, T9 V9 F9 R9 8, T10 V10 F10 R10 9, T11 V11 F11 R11 10,
+    T12 V12 F12 R12 11, T13 V13 F13 R13 12, T14 V14 F14 R14 13
+);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4, T6 V6 F6 R6 5,
+    T7 V7 F7 R7 6, T8 V8 F8 R8 7, T9 V9 F9 R9 8, T10 V10 F10 R10 9, T11 V11 F11 R11 10,
+    T12 V12 F12 R12 11, T13 V13 F13 R13 12, T14 V14 F14 R14 13, T15 V15 F15 R15 14
+);
+impl_par_apply_columns!(
+    T1 V1 F1 R1 0, T2 V2 F2 R2 1, T3 V3 F3 R3 2, T4 V4 F4 R4 3, T5 V5 F5 R5 4, T6 V6 F6 R6 5,
+    T7 V7 F7 R7 6, T8 V8 F8 R8 7, T9 V9 F9 R9 8, T10 V10 F10 R10 9, T11 V11 F11 R11 10,
+    T12 V12 F12 R12 11, T13 V13 F13 R13 12, T14 V14 F14 R14 13, T15 V15 F15 R15 14,
+    T16 V16 F16 R16 15
+);
+
+/// Wraps the pointers to a buffer so they can be shared between threads.
+#[derive(Clone, Copy)]
+struct SyncPtr<P>(P);
+
+// SAFE: Callers are responsible for only accessing disjoint elements mutably.
+unsafe impl<P> Send for SyncPtr<P> {}
+unsafe impl<P> Sync for SyncPtr<P> {}
+
+impl<P: Copy> SyncPtr<P> {
+    // Closures must capture the wrapper rather than the pointers inside of it.
+    fn get(self) -> P {
+        self.0
+    }
+}
+
+impl<'a, Param: ParallelParam + Send + Sync> ParallelSliceMut<'a, Param> {
+    /// Sorts the slice in parallel with a comparator function.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
+    /// buffer, and a temporary buffer the size of the slice to move the elements into place.
+    ///
+    /// This defers to rayon's [`par_sort_by`], so any properties it has will also hold for
+    /// this function.
+    ///
+    /// [`par_sort_by`]: rayon::slice::ParallelSliceMut::par_sort_by
+    pub fn par_sort_by<F>(&mut self, f: F)
+    where
+        F: Fn(Param::Ref<'_>, Param::Ref<'_>) -> Ordering + Sync,
+    {
+        let base = SyncPtr(Param::as_ptr(self.storage));
+        self.par_sort_via(|indices| {
+            indices.par_sort_by(|a, b| unsafe {
+                f(
+                    Param::as_ref(Param::add(base.get(), *a)),
+                    Param::as_ref(Param::add(base.get(), *b)),
+                )
+            });
+        });
+    }
+
+    /// Sorts the slice in parallel with a key extraction function.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
+    /// buffer, and a temporary buffer the size of the slice to move the elements into place.
+    ///
+    /// This defers to rayon's [`par_sort_by_key`], so any properties it has will also hold
+    /// for this function.
+    ///
+    /// [`par_sort_by_key`]: rayon::slice::ParallelSliceMut::par_sort_by_key
+    pub fn par_sort_by_key<K, F>(&mut self, f: F)
+    where
+        F: Fn(Param::Ref<'_>) -> K + Sync,
+        K: Ord,
+    {
+        let base = SyncPtr(Param::as_ptr(self.storage));
+        self.par_sort_via(|indices| {
+            indices
+                .par_sort_by_key(|idx| unsafe { f(Param::as_ref(Param::add(base.get(), *idx))) });
+        });
+    }
+
+    /// Sorts the slice in parallel with a comparator function, but might not preserve the
+    /// order of equal elements.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
+    /// buffer, and a temporary buffer the size of the slice to move the elements into place.
+    ///
+    /// This defers to rayon's [`par_sort_unstable_by`], so any properties it has will also
+    /// hold for this function.
+    ///
+    /// [`par_sort_unstable_by`]: rayon::slice::ParallelSliceMut::par_sort_unstable_by
+    pub fn par_sort_unstable_by<F>(&mut self, f: F)
+    where
+        F: Fn(Param::Ref<'_>, Param::Ref<'_>) -> Ordering + Sync,
+    {
+        let base = SyncPtr(Param::as_ptr(self.storage));
+        self.par_sort_via(|indices| {
+            indices.par_sort_unstable_by(|a, b| unsafe {
+                f(
+                    Param::as_ref(Param::add(base.get(), *a)),
+                    Param::as_ref(Param::add(base.get(), *b)),
+                )
+            });
+        });
+    }
+
+    /// Sorts the slice in parallel with a key extraction function, but might not preserve
+    /// the order of equal elements.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
+    /// buffer, and a temporary buffer the size of the slice to move the elements into place.
+    ///
+    /// This defers to rayon's [`par_sort_unstable_by_key`], so any properties it has will
+    /// also hold for this function.
+    ///
+    /// [`par_sort_unstable_by_key`]: rayon::slice::ParallelSliceMut::par_sort_unstable_by_key
+    pub fn par_sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        F: Fn(Param::Ref<'_>) -> K + Sync,
+        K: Ord,
+    {
+        let base = SyncPtr(Param::as_ptr(self.storage));
+        self.par_sort_via(|indices| {
+            indices.par_sort_unstable_by_key(|idx| unsafe {
+                f(Param::as_ref(Param::add(base.get(), *idx)))
+            });
+        });
+    }
+
+    /// Sorts the indices of the slice with `f`, then moves every element into place in
+    /// parallel.
+    fn par_sort_via<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Vec<usize>),
+    {
+        if self.len < 2 {
+            return;
+        }
+
+        let mut indices: Vec<usize> = (0..self.len).into_par_iter().collect();
+        f(&mut indices);
+
+        // The elements are gathered into a temporary buffer in sorted order, then moved back
+        // all at once. Nothing can panic after the indices have been sorted, so every element
+        // is moved exactly once. The temporary buffer has a length of zero, so dropping it
+        // only frees the buffer.
+        let sorted = ParallelVec::<Param>::with_capacity(self.len);
+        let src = SyncPtr(Param::as_ptr(self.storage));
+        let dst = SyncPtr(Param::as_ptr(sorted.storage));
+        // SAFE: Every index is in bounds and unique, so every element is read from once and
+        // every slot of the temporary buffer is written to once.
+        indices
+            .par_iter()
+            .enumerate()
+            .for_each(|(idx, src_idx)| unsafe {
+                Param::copy_to_nonoverlapping(
+                    Param::add(src.get(), *src_idx),
+                    Param::add(dst.get(), idx),
+                    1,
+                );
+            });
+        // SAFE: The temporary buffer holds all of the elements, and does not overlap the slice.
+        unsafe { Param::copy_to_nonoverlapping(dst.get(), src.get(), self.len) };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ParallelVec;
+    use rayon::prelude::*;
+    use std::string::{String, ToString};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_par_iter() {
+        let vec: ParallelVec<(u64, u32)> = (0..10_000).map(|i| (i, i as u32 * 2)).collect();
+        let sum: u64 = vec.par_iter().map(|(a, b)| *a + *b as u64).sum();
+        assert_eq!(sum, 3 * (0..10_000).sum::<u64>());
+        let rows: Vec<_> = vec.par_iter().map(|(a, b)| (*a, *b)).collect();
+        assert!(ParallelVec::from(rows) == vec);
+        assert_eq!(vec.get(10..20).unwrap().par_iter().len(), 10);
+    }
+
+    #[test]
+    fn test_par_iter_mut() {
+        let mut vec: ParallelVec<(f32, f32)> = (0..10_000).map(|i| (i as f32, 1.0)).collect();
+        vec.par_iter_mut().for_each(|(position, velocity)| {
+            *position += *velocity;
+        });
+        assert!(vec
+            .iter()
+            .enumerate()
+            .all(|(i, (p, _))| *p == i as f32 + 1.0));
+    }
+
+    #[test]
+    fn test_into_par_iter() {
+        let vec: ParallelVec<(u32, Arc<u32>)> = (0..1_000).map(|i| (i, Arc::new(i))).collect();
+        let values: Vec<u32> = vec.into_par_iter().map(|(a, b)| a + *b).collect();
+        assert_eq!(values, (0..1_000).map(|i| i * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_into_par_iter_drops_unconsumed() {
+        struct Counter<'a>(&'a AtomicUsize);
+        impl Drop for Counter<'_> {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let drops = AtomicUsize::new(0);
+        let vec: ParallelVec<(u32, Counter)> = (0..1_000).map(|i| (i, Counter(&drops))).collect();
+        let found = vec.into_par_iter().find_any(|(i, _)| *i == 500);
+        assert!(found.is_some());
+        drop(found);
+        assert_eq!(drops.load(Ordering::Relaxed), 1_000);
+
+        let vec: ParallelVec<(u32, Counter)> = (0..1_000).map(|i| (i, Counter(&drops))).collect();
+        let taken: Vec<_> = vec.into_par_iter().take(10).collect();
+        assert_eq!(taken.len(), 10);
+        drop(taken);
+        assert_eq!(drops.load(Ordering::Relaxed), 2_000);
+    }
+
+    #[test]
+    fn test_from_par_iter() {
+        let vec: ParallelVec<(u64, String)> = (0..10_000u64)
+            .into_par_iter()
+            .filter(|i| i % 3 == 0)
+            .map(|i| (i, i.to_string()))
+            .collect();
+        let expected: ParallelVec<(u64, String)> = (0..10_000u64)
+            .filter(|i| i % 3 == 0)
+            .map(|i| (i, i.to_string()))
+            .collect();
+        assert!(vec == expected);
+    }
+
+    #[test]
+    fn test_par_extend() {
+        let mut vec = ParallelVec::from(vec![(0u32, 0u8)]);
+        vec.par_extend((1..1_000u32).into_par_iter().map(|i| (i, i as u8)));
+        assert_eq!(vec.len(), 1_000);
+        assert!(vec
+            .iter()
+            .enumerate()
+            .all(|(i, (a, b))| *a == i as u32 && *b == i as u8));
+
+        vec.par_extend(Vec::<(u32, u8)>::new());
+        assert_eq!(vec.len(), 1_000);
+    }
+
+    #[test]
+    fn test_par_sort() {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let rows: Vec<(u16, String)> = (0..500)
+            .map(|i| (rng.gen_range(0..20), i.to_string()))
+            .collect();
+        let mut vec = ParallelVec::from(rows.clone());
+
+        // Stable sorts must keep equal keys in their original order.
+        let mut expected = rows.clone();
+        expected.sort_by_key(|(key, _)| *key);
+        vec.as_parallel_slice_mut().par_sort_by_key(|(key, _)| *key);
+        assert!(vec == ParallelVec::from(expected.clone()));
+
+        expected.sort_by(|a, b| b.cmp(a));
+        vec.as_parallel_slice_mut().par_sort_by(|a, b| b.cmp(&a));
+        assert!(vec == ParallelVec::from(expected.clone()));
+
+        expected.sort_unstable_by(|a, b| a.1.cmp(&b.1));
+        vec.as_parallel_slice_mut()
+            .par_sort_unstable_by(|a, b| a.1.cmp(b.1));
+        assert!(vec == ParallelVec::from(expected.clone()));
+
+        expected.sort_unstable_by_key(|(key, name)| (*key, name.clone()));
+        vec.as_parallel_slice_mut()
+            .par_sort_unstable_by_key(|(key, name)| (*key, name.clone()));
+        assert!(vec == ParallelVec::from(expected));
+    }
+
+    #[test]
+    fn test_par_sort_short() {
+        let mut vec: ParallelVec<(u8, u8)> = ParallelVec::new();
+        vec.as_parallel_slice_mut().par_sort_by_key(|(a, _)| *a);
+        vec.push((1, 2));
+        vec.as_parallel_slice_mut().par_sort_by_key(|(a, _)| *a);
+        assert_eq!(vec.index(0), (&1, &2));
+    }
+
+    #[test]
+    fn test_par_chunks_mut() {
+        let mut vec: ParallelVec<(u32, u64)> = (0..1_000).map(|i| (i, 0)).collect();
+        let mut slice = vec.as_parallel_slice_mut();
+        let chunks = slice.par_chunks_mut(64);
+        assert_eq!(chunks.len(), 16);
+        let lens: Vec<usize> = slice
+            .par_chunks_mut(64)
+            .map(|(a, b)| {
+                assert_eq!(a.len(), b.len());
+                for (a, b) in a.iter().zip(b.iter_mut()) {
+                    *b = *a as u64 * 2;
+                }
+                a.len()
+            })
+            .collect();
+        assert_eq!(lens.len(), 16);
+        assert_eq!(lens[15], 1_000 - 15 * 64);
+        assert!(vec.iter().all(|(a, b)| *b == *a as u64 * 2));
+
+        let lens: Vec<usize> = vec
+            .as_parallel_slice_mut()
+            .par_chunks_mut(64)
+            .rev()
+            .map(|(a, _)| a.len())
+            .collect();
+        assert_eq!(lens[0], 1_000 - 15 * 64);
+        assert_eq!(vec.get_mut(0..0).unwrap().par_chunks_mut(3).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_par_chunks_mut_zero() {
+        let mut vec = ParallelVec::from(vec![(1, 2)]);
+        vec.as_parallel_slice_mut().par_chunks_mut(0);
+    }
+
+    #[test]
+    fn test_par_apply_columns() {
+        let mut vec: ParallelVec<(u32, f64, String)> =
+            (0..100).map(|i| (i, i as f64, i.to_string())).collect();
+        let (sum, (), longest) = vec.as_parallel_slice_mut().par_apply_columns((
+            |a: &mut [u32]| a.iter().sum::<u32>(),
+            |b: &mut [f64]| b.iter_mut().for_each(|b| *b *= 0.5),
+            |c: &mut [String]| c.iter().map(String::len).max(),
+        ));
+        assert_eq!(sum, (0..100).sum::<u32>());
+        assert_eq!(longest, Some(2));
+        assert!(vec.iter().all(|(a, b, _)| *b == *a as f64 * 0.5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_par_apply_columns_panic() {
+        let mut vec = ParallelVec::from(vec![(1, 2)]);
+        vec.as_parallel_slice_mut()
+            .par_apply_columns((|_: &mut [i32]| (), |_: &mut [i32]| panic!()));
+    }
+}
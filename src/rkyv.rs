@@ -0,0 +1,276 @@
+use crate::{GrowthPolicy, ParallelVec, Storage};
+use core::fmt::{Display, Formatter};
+use rkyv::{
+    bytecheck::{CheckBytes, Verify},
+    munge::munge,
+    rancor::{fail, Fallible, Source},
+    ser::{Allocator as SerializeAllocator, Writer},
+    tuple::*,
+    vec::{ArchivedVec, VecResolver},
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+/// An archived [`ParallelVec`].
+///
+/// Like `ParallelVec`, the archived form stores each field in its own column, so a
+/// validated archive can be accessed in place, for example straight out of a memory
+/// mapped file, without deserializing or copying any of the columns.
+///
+/// `Columns` is a tuple of [`ArchivedVec`]s, one per field. Validating an archive
+/// checks that all of the columns have the same length.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::{ArchivedParallelVec, ParallelVec};
+/// use rkyv::rancor::Error;
+///
+/// let vec: ParallelVec<(u32, f32)> = ParallelVec::from(vec![(1, 0.5), (2, 1.5)]);
+/// let bytes = rkyv::to_bytes::<Error>(&vec).unwrap();
+///
+/// let archived = rkyv::access::<rkyv::Archived<ParallelVec<(u32, f32)>>, Error>(&bytes).unwrap();
+/// assert_eq!(archived.len(), 2);
+/// let (ids, weights) = archived.as_slices();
+/// assert_eq!(ids, &[1, 2]);
+/// assert_eq!(weights, &[0.5, 1.5]);
+///
+/// let deserialized: ParallelVec<(u32, f32)> = rkyv::deserialize::<_, Error>(archived).unwrap();
+/// assert!(deserialized == vec);
+/// ```
+#[derive(Portable, CheckBytes)]
+#[rkyv(crate = ::rkyv)]
+#[bytecheck(crate = ::rkyv::bytecheck, verify)]
+#[repr(transparent)]
+pub struct ArchivedParallelVec<Columns> {
+    columns: Columns,
+}
+
+impl<Columns: ArchivedColumns> ArchivedParallelVec<Columns> {
+    /// Returns the number of elements in the archived vector.
+    pub fn len(&self) -> usize {
+        self.columns.lens().as_ref()[0]
+    }
+
+    /// Returns true if the archived vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the columns of the archived vector.
+    pub fn columns(&self) -> &Columns {
+        &self.columns
+    }
+
+    /// Gets the individual slices for every sub-slice.
+    pub fn as_slices(&self) -> Columns::Slices<'_> {
+        self.columns.as_slices()
+    }
+
+    /// Returns a immutable reference to the element at `index`, if available, or
+    /// [`None`] if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Columns::Ref<'_>> {
+        self.columns.get(index)
+    }
+}
+
+unsafe impl<Columns, C> Verify<C> for ArchivedParallelVec<Columns>
+where
+    Columns: ArchivedColumns,
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        let lens = self.columns.lens();
+        let lens = lens.as_ref();
+        if lens.iter().any(|len| *len != lens[0]) {
+            fail!(UnevenColumns);
+        }
+        Ok(())
+    }
+}
+
+/// The archived columns of an [`ArchivedParallelVec`].
+///
+/// This trait is sealed and cannot be implemented outside of
+/// `parallel_vec`.
+pub trait ArchivedColumns: private::Sealed {
+    /// The length of each column.
+    type Lens: AsRef<[usize]>;
+    /// A set of immutable slice references of the archived fields.
+    type Slices<'a>
+    where
+        Self: 'a;
+    /// A set of immutable references of the archived fields.
+    type Ref<'a>
+    where
+        Self: 'a;
+
+    /// Gets the length of each column.
+    fn lens(&self) -> Self::Lens;
+
+    /// Gets the individual slices for every column.
+    fn as_slices(&self) -> Self::Slices<'_>;
+
+    /// Gets the archived fields at `index`, or [`None`] if it is out of bounds.
+    fn get(&self, index: usize) -> Option<Self::Ref<'_>>;
+}
+
+/// Error when the columns of an archived [`ParallelVec`] have different lengths.
+#[derive(Debug)]
+struct UnevenColumns;
+
+impl Display for UnevenColumns {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("columns have uneven lengths")
+    }
+}
+
+impl core::error::Error for UnevenColumns {}
+
+macro_rules! vec_resolver {
+    ($t:ident) => {
+        VecResolver
+    };
+}
+
+macro_rules! impl_archive {
+    ($tuple:ident $n:literal, $($ts:ident $vs:ident $idx:tt),*) => {
+        impl<$($ts),*> private::Sealed for $tuple<$(ArchivedVec<$ts>),*> {}
+
+        impl<$($ts),*> ArchivedColumns for $tuple<$(ArchivedVec<$ts>),*> {
+            type Lens = [usize; $n];
+            type Slices<'a> = ($(&'a [$ts],)*) where Self: 'a;
+            type Ref<'a> = ($(&'a $ts,)*) where Self: 'a;
+
+            fn lens(&self) -> Self::Lens {
+                [$(self.$idx.len()),*]
+            }
+
+            fn as_slices(&self) -> Self::Slices<'_> {
+                ($(self.$idx.as_slice(),)*)
+            }
+
+            fn get(&self, index: usize) -> Option<Self::Ref<'_>> {
+                Some(($(self.$idx.get(index)?,)*))
+            }
+        }
+
+        impl<$($ts: Archive,)* Growth: GrowthPolicy, A: Storage> Archive
+            for ParallelVec<($($ts,)*), Growth, A>
+        {
+            type Archived = ArchivedParallelVec<$tuple<$(ArchivedVec<$ts::Archived>),*>>;
+            type Resolver = ($(vec_resolver!($ts),)*);
+
+            fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+                munge!(let ArchivedParallelVec { columns } = out);
+                munge!(let $tuple($($vs),*) = columns);
+                $(ArchivedVec::resolve_from_len(self.len(), resolver.$idx, $vs);)*
+            }
+        }
+
+        impl<$($ts,)* Growth, A, S> Serialize<S> for ParallelVec<($($ts,)*), Growth, A>
+        where
+            $($ts: Serialize<S>,)*
+            Growth: GrowthPolicy,
+            A: Storage,
+            S: Fallible + SerializeAllocator + Writer + ?Sized,
+        {
+            fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+                let ($($vs,)*) = self.as_slices();
+                Ok(($(ArchivedVec::serialize_from_slice($vs, serializer)?,)*))
+            }
+        }
+
+        impl<$($ts,)* D> Deserialize<ParallelVec<($($ts,)*)>, D>
+            for ArchivedParallelVec<$tuple<$(ArchivedVec<$ts::Archived>),*>>
+        where
+            $($ts: Archive,)*
+            $(ArchivedVec<$ts::Archived>: Deserialize<alloc::vec::Vec<$ts>, D>,)*
+            D: Fallible + ?Sized,
+            D::Error: Source,
+        {
+            fn deserialize(&self, deserializer: &mut D) -> Result<ParallelVec<($($ts,)*)>, D::Error> {
+                let vecs = ($(self.columns.$idx.deserialize(deserializer)?,)*);
+                match ParallelVec::try_from(vecs) {
+                    Ok(vec) => Ok(vec),
+                    Err(_) => fail!(UnevenColumns),
+                }
+            }
+        }
+    };
+}
+
+impl_archive!(ArchivedTuple1 1, T1 V1 0);
+impl_archive!(ArchivedTuple2 2, T1 V1 0, T2 V2 1);
+impl_archive!(ArchivedTuple3 3, T1 V1 0, T2 V2 1, T3 V3 2);
+impl_archive!(ArchivedTuple4 4, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3);
+impl_archive!(ArchivedTuple5 5, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4);
+impl_archive!(ArchivedTuple6 6, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5);
+impl_archive!(ArchivedTuple7 7, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6);
+impl_archive!(
+    ArchivedTuple8 8, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7
+);
+impl_archive!(
+    ArchivedTuple9 9, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7,
+    T9 V9 8
+);
+impl_archive!(
+    ArchivedTuple10 10, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7,
+    T9 V9 8, T10 V10 9
+);
+impl_archive!(
+    ArchivedTuple11 11, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7,
+    T9 V9 8, T10 V10 9, T11 V11 10
+);
+impl_archive!(
+    ArchivedTuple12 12, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7,
+    T9 V9 8, T10 V10 9, T11 V11 10, T12 V12 11
+);
+impl_archive!(
+    ArchivedTuple13 13, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7,
+    T9 V9 8, T10 V10 9, T11 V11 10, T12 V12 11, T13 V13 12
+);
+
+mod private {
+    pub trait Sealed {}
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ParallelVec;
+    use rkyv::{rancor::Error, Archived};
+
+    type Vec3 = ParallelVec<(u32, u64, bool)>;
+
+    #[test]
+    fn test_rkyv_round_trip() {
+        let vec: Vec3 = ParallelVec::from(vec![(1, 2, true), (3, 4, false), (5, 6, true)]);
+        let bytes = rkyv::to_bytes::<Error>(&vec).unwrap();
+        let archived = rkyv::access::<Archived<Vec3>, Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), 3);
+        assert!(!archived.is_empty());
+        let (a, b, c) = archived.as_slices();
+        assert_eq!(a, &[1, 3, 5]);
+        assert_eq!(b, &[2, 4, 6]);
+        assert_eq!(c, &[true, false, true]);
+        assert_eq!(archived.get(1), Some((&3.into(), &4.into(), &false)));
+        assert_eq!(archived.get(3), None);
+
+        let deserialized: Vec3 = rkyv::deserialize::<_, Error>(archived).unwrap();
+        assert!(deserialized == vec);
+    }
+
+    #[test]
+    fn test_rkyv_empty() {
+        let vec: Vec3 = ParallelVec::new();
+        let bytes = rkyv::to_bytes::<Error>(&vec).unwrap();
+        let archived = rkyv::access::<Archived<Vec3>, Error>(&bytes).unwrap();
+        assert!(archived.is_empty());
+    }
+
+    #[test]
+    fn test_rkyv_rejects_uneven_columns() {
+        // The archive of a pair of `Vec`s has the same layout as an archived `ParallelVec`.
+        let bytes = rkyv::to_bytes::<Error>(&(vec![1u32, 2], vec![3u64])).unwrap();
+        assert!(rkyv::access::<Archived<ParallelVec<(u32, u64)>>, Error>(&bytes).is_err());
+    }
+}
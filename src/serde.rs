@@ -1,12 +1,19 @@
-use crate::{ParallelParam, ParallelVec};
-use alloc::vec::Vec;
+use crate::{GrowthPolicy, ParallelParam, ParallelVec, ParallelVecConversionError, Storage};
+use core::{fmt::Formatter, marker::PhantomData, mem::size_of};
 use serde::{
-    de::DeserializeOwned, ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
 };
 
-impl<'a, Param> Serialize for ParallelVec<Param>
+/// The maximum number of bytes to preallocate from an untrusted size hint.
+const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+
+impl<'a, Param, Growth, A> Serialize for ParallelVec<Param, Growth, A>
 where
     Param: ParallelParam + 'a,
+    Growth: GrowthPolicy,
+    A: Storage,
     Param::Ref<'a>: Serialize,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -14,31 +21,134 @@ where
         S: Serializer,
     {
         let mut seq = serializer.serialize_seq(Some(self.len()))?;
-        for item in self.iter() {
+        // SAFE: The items are only serialized, and do not escape this function.
+        for item in unsafe { self.iter_unbounded() } {
             seq.serialize_element(&item)?;
         }
         seq.end()
     }
 }
 
-impl<'de, Param> Deserialize<'de> for ParallelVec<Param>
+impl<'de, Param, Growth, A> Deserialize<'de> for ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + Deserialize<'de>,
+    Growth: GrowthPolicy + Default,
+    A: Storage + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ParallelVecVisitor(PhantomData))
+    }
+}
+
+struct ParallelVecVisitor<Param: ParallelParam, Growth: GrowthPolicy, A: Storage>(
+    PhantomData<ParallelVec<Param, Growth, A>>,
+);
+
+impl<'de, Param, Growth, A> Visitor<'de> for ParallelVecVisitor<Param, Growth, A>
+where
+    Param: ParallelParam + Deserialize<'de>,
+    Growth: GrowthPolicy + Default,
+    A: Storage + Default,
+{
+    type Value = ParallelVec<Param, Growth, A>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        // Don't trust the size hint blindly, it may come from untrusted input.
+        let max = MAX_PREALLOC_BYTES / size_of::<Param>().max(1);
+        let capacity = seq.size_hint().unwrap_or(0).min(max);
+        let mut vec = ParallelVec::with_capacity_and_growth_policy_in(
+            capacity,
+            Growth::default(),
+            A::default(),
+        );
+        while let Some(value) = seq.next_element()? {
+            vec.push(value);
+        }
+        Ok(vec)
+    }
+}
+
+/// A wrapper that serializes a [`ParallelVec`] column by column instead of row by row.
+///
+/// A `Columnar<ParallelVec<(A, B)>>` is serialized as a tuple of sequences, one per
+/// column, exactly like a `(Vec<A>, Vec<B>)` would be. Keeping the values of each column
+/// together typically compresses far better than interleaving them, and each column is
+/// deserialized in one pass before being moved into the `ParallelVec` in bulk.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::{Columnar, ParallelVec};
+/// use serde_test::{assert_tokens, Token};
+///
+/// let vec: ParallelVec<(u8, bool)> = ParallelVec::from(vec![(1, true), (2, false)]);
+/// assert_tokens(
+///     &Columnar(vec),
+///     &[
+///         Token::Tuple { len: 2 },
+///         Token::Seq { len: Some(2) },
+///         Token::U8(1),
+///         Token::U8(2),
+///         Token::SeqEnd,
+///         Token::Seq { len: Some(2) },
+///         Token::Bool(true),
+///         Token::Bool(false),
+///         Token::SeqEnd,
+///         Token::TupleEnd,
+///     ],
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Columnar<T>(pub T);
+
+impl<'a, Param, Growth, A> Serialize for Columnar<ParallelVec<Param, Growth, A>>
 where
-    Param: ParallelParam + DeserializeOwned,
+    Param: ParallelParam + 'a,
+    Growth: GrowthPolicy,
+    A: Storage,
+    Param::Slices<'a>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // SAFE: The slices are only serialized, and do not escape this function.
+        let slices = unsafe { Param::as_slices(Param::as_ptr(self.0.storage), self.0.len) };
+        slices.serialize(serializer)
+    }
+}
+
+impl<'de, Param> Deserialize<'de> for Columnar<ParallelVec<Param>>
+where
+    Param: ParallelParam,
+    Param::Vecs: Deserialize<'de>,
+    ParallelVec<Param>: TryFrom<Param::Vecs, Error = ParallelVecConversionError>,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        Ok(Self::from(<Vec<Param> as Deserialize<'de>>::deserialize(
-            deserializer,
-        )?))
+        let vecs = Param::Vecs::deserialize(deserializer)?;
+        match ParallelVec::try_from(vecs) {
+            Ok(vec) => Ok(Columnar(vec)),
+            Err(_) => Err(D::Error::custom("columns have uneven lengths")),
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::ParallelVec;
-    use serde_test::{assert_tokens, Token};
+    use crate::{Columnar, ParallelVec};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
 
     #[test]
     fn test_serde_empty() {
@@ -74,6 +184,50 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_serde_matches_vec() {
+        let values = vec![(1i32, 2u64), (3, 4)];
+        let vec = ParallelVec::from(values.clone());
+        let tokens = [
+            Token::Seq { len: Some(2) },
+            Token::Tuple { len: 2 },
+            Token::I32(1),
+            Token::U64(2),
+            Token::TupleEnd,
+            Token::Tuple { len: 2 },
+            Token::I32(3),
+            Token::U64(4),
+            Token::TupleEnd,
+            Token::SeqEnd,
+        ];
+        assert_tokens(&values, &tokens);
+        assert_tokens(&vec, &tokens);
+        // Like `Vec`, the length of the sequence is optional when deserializing.
+        let mut unsized_tokens = tokens;
+        unsized_tokens[0] = Token::Seq { len: None };
+        assert_de_tokens(&vec, &unsized_tokens);
+    }
+
+    #[test]
+    fn test_serde_borrowed() {
+        let vec: ParallelVec<(&str, u8)> = ParallelVec::from(vec![("a", 1), ("b", 2)]);
+        assert_de_tokens(
+            &vec,
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Tuple { len: 2 },
+                Token::BorrowedStr("a"),
+                Token::U8(1),
+                Token::TupleEnd,
+                Token::Tuple { len: 2 },
+                Token::BorrowedStr("b"),
+                Token::U8(2),
+                Token::TupleEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
+
     #[test]
     fn test_serde_3() {
         let vec: ParallelVec<(i32, u64, f32)> =
@@ -106,4 +260,57 @@ mod test {
             ],
         );
     }
+
+    #[test]
+    fn test_serde_columnar() {
+        let vec: ParallelVec<(i32, u64)> = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        let tokens = [
+            Token::Tuple { len: 2 },
+            Token::Seq { len: Some(3) },
+            Token::I32(1),
+            Token::I32(3),
+            Token::I32(5),
+            Token::SeqEnd,
+            Token::Seq { len: Some(3) },
+            Token::U64(2),
+            Token::U64(4),
+            Token::U64(6),
+            Token::SeqEnd,
+            Token::TupleEnd,
+        ];
+        assert_tokens(&(vec![1i32, 3, 5], vec![2u64, 4, 6]), &tokens);
+        assert_tokens(&Columnar(vec), &tokens);
+    }
+
+    #[test]
+    fn test_serde_columnar_empty() {
+        let vec: ParallelVec<(i32, u64)> = ParallelVec::new();
+        assert_tokens(
+            &Columnar(vec),
+            &[
+                Token::Tuple { len: 2 },
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_serde_columnar_uneven() {
+        assert_de_tokens_error::<Columnar<ParallelVec<(i32, u64)>>>(
+            &[
+                Token::Tuple { len: 2 },
+                Token::Seq { len: Some(1) },
+                Token::I32(1),
+                Token::SeqEnd,
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::TupleEnd,
+            ],
+            "columns have uneven lengths",
+        );
+    }
 }
@@ -0,0 +1,195 @@
+use crate::{growth::Doubling, GrowthPolicy, ParallelParam, ParallelVec, Storage};
+use alloc::sync::Arc;
+use allocator_api2::alloc::Global;
+use core::ops::Deref;
+
+/// A reference-counted, copy-on-write [`ParallelVec`], created by [`ParallelVec::freeze`].
+///
+/// Cloning a `SharedParallelVec` only increments the reference count, and every clone
+/// reads from the same allocation. It is [`Send`] and [`Sync`] whenever the elements
+/// are, so a read-only table can be shared across threads without copying or locking.
+///
+/// It dereferences to the shared [`ParallelVec`], which provides all of the read-only
+/// operations. [`make_mut`] gives mutable access with copy-on-write semantics, so
+/// [`snapshot`]s of the current state are cheap, and the elements are only copied the
+/// first time a shared handle is mutated.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::ParallelVec;
+///
+/// let table = ParallelVec::from(vec![(1u32, 0.5f32), (2, 0.25)]).freeze();
+/// let shared = table.clone();
+/// let sum = std::thread::spawn(move || shared.as_slices().1.iter().sum::<f32>())
+///     .join()
+///     .unwrap();
+/// assert_eq!(sum, 0.75);
+/// assert_eq!(table.get(1), Some((&2, &0.25)));
+/// ```
+///
+/// Keeping the previous frame of a simulation:
+/// ```rust
+/// use parallel_vec::{ParallelVec, SharedParallelVec};
+///
+/// let mut state = ParallelVec::from(vec![(0.0f32, 1.0f32), (5.0, -1.0)]).freeze();
+/// let previous = state.snapshot();
+/// for (position, velocity) in SharedParallelVec::make_mut(&mut state).iter_mut() {
+///     *position += *velocity;
+/// }
+/// assert_eq!(previous.as_slices().0, &[0.0, 5.0]);
+/// assert_eq!(state.as_slices().0, &[1.0, 4.0]);
+/// ```
+///
+/// [`make_mut`]: Self::make_mut
+/// [`snapshot`]: Self::snapshot
+pub struct SharedParallelVec<
+    Param: ParallelParam,
+    Growth: GrowthPolicy = Doubling,
+    A: Storage = Global,
+> {
+    inner: Arc<ParallelVec<Param, Growth, A>>,
+}
+
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> ParallelVec<Param, Growth, A> {
+    /// Converts the [`ParallelVec`] into an immutable [`SharedParallelVec`], which can be
+    /// cloned without copying the elements.
+    ///
+    /// This does not move the elements out of their allocation.
+    pub fn freeze(self) -> SharedParallelVec<Param, Growth, A> {
+        SharedParallelVec {
+            inner: Arc::new(self),
+        }
+    }
+}
+
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> SharedParallelVec<Param, Growth, A> {
+    /// Returns the inner [`ParallelVec`] if this is the only handle to it, or `this`
+    /// otherwise.
+    pub fn try_unwrap(this: Self) -> Result<ParallelVec<Param, Growth, A>, Self> {
+        Arc::try_unwrap(this.inner).map_err(|inner| Self { inner })
+    }
+
+    /// Creates another handle to the same elements, without copying them.
+    ///
+    /// This is the same as [`clone`], and is named for use with [`make_mut`].
+    ///
+    /// [`clone`]: Clone::clone
+    /// [`make_mut`]: Self::make_mut
+    pub fn snapshot(&self) -> Self {
+        self.clone()
+    }
+
+    /// Returns a mutable reference to the [`ParallelVec`] if this is the only handle to
+    /// it, or `None` otherwise.
+    pub fn get_mut(this: &mut Self) -> Option<&mut ParallelVec<Param, Growth, A>> {
+        Arc::get_mut(&mut this.inner)
+    }
+
+    /// Returns `true` if both handles share the same allocation.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.inner, &other.inner)
+    }
+}
+
+impl<Param, Growth, A> SharedParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + Clone,
+    Growth: GrowthPolicy + Clone,
+    A: Storage + Clone,
+{
+    /// Converts the handle back into a mutable [`ParallelVec`], cloning the elements
+    /// only if there are other handles to them.
+    pub fn thaw(this: Self) -> ParallelVec<Param, Growth, A> {
+        Self::try_unwrap(this).unwrap_or_else(|this| (*this.inner).clone())
+    }
+
+    /// Returns a mutable reference to the [`ParallelVec`], first cloning the elements
+    /// into a new allocation if there are other handles to them.
+    ///
+    /// The other handles keep the elements as they were before the call.
+    pub fn make_mut(this: &mut Self) -> &mut ParallelVec<Param, Growth, A> {
+        Arc::make_mut(&mut this.inner)
+    }
+}
+
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> Clone
+    for SharedParallelVec<Param, Growth, A>
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> Deref
+    for SharedParallelVec<Param, Growth, A>
+{
+    type Target = ParallelVec<Param, Growth, A>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> From<ParallelVec<Param, Growth, A>>
+    for SharedParallelVec<Param, Growth, A>
+{
+    fn from(vec: ParallelVec<Param, Growth, A>) -> Self {
+        vec.freeze()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SharedParallelVec;
+    use crate::ParallelVec;
+    use alloc::{string::String, vec};
+
+    #[test]
+    fn test_share() {
+        let table =
+            ParallelVec::from(vec![(1u32, String::from("a")), (2, String::from("b"))]).freeze();
+        let other = table.clone();
+        assert!(SharedParallelVec::ptr_eq(&table, &other));
+        assert_eq!(other.len(), 2);
+        assert_eq!(other.as_slices().0, &[1, 2]);
+        let handle = std::thread::spawn(move || other.iter().map(|(a, _)| *a).sum::<u32>());
+        assert_eq!(handle.join().unwrap(), 3);
+        assert_eq!(table.capacity(), 2);
+    }
+
+    #[test]
+    fn test_copy_on_write() {
+        let mut state = ParallelVec::from(vec![(1u32, String::from("a"))]).freeze();
+        let snapshot = state.snapshot();
+        assert!(SharedParallelVec::get_mut(&mut state).is_none());
+        SharedParallelVec::make_mut(&mut state).push((2, String::from("b")));
+        assert!(!SharedParallelVec::ptr_eq(&state, &snapshot));
+        assert_eq!(snapshot.len(), 1);
+
+        // The handle is unique after it has been copied, so it is mutated in place.
+        let before = state.as_ptrs();
+        *SharedParallelVec::make_mut(&mut state)
+            .get_mut(0)
+            .unwrap()
+            .0 = 10;
+        assert_eq!(before, state.as_ptrs());
+        assert!(SharedParallelVec::get_mut(&mut state).is_some());
+        assert_eq!(state.as_slices().0, &[10, 2]);
+        assert_eq!(snapshot.as_slices().0, &[1]);
+    }
+
+    #[test]
+    fn test_unwrap() {
+        let table = ParallelVec::from(vec![(1u32, String::from("a"))]).freeze();
+        let other = table.clone();
+        let other = SharedParallelVec::try_unwrap(other).unwrap_err();
+
+        let mut thawed = SharedParallelVec::thaw(other);
+        thawed.push((2, String::from("b")));
+        assert_eq!(table.len(), 1);
+        let vec = SharedParallelVec::try_unwrap(table).ok().unwrap();
+        assert_eq!(vec.as_slices().1, &[String::from("a")]);
+        assert_eq!(thawed.len(), 2);
+    }
+}
@@ -0,0 +1,464 @@
+use crate::{
+    storage::FixedStorage, GrowthPolicy, ParallelParam, ParallelSlice, ParallelVec, Storage,
+};
+use alloc::{alloc::Layout, string::String, vec::Vec};
+use allocator_api2::alloc::AllocError;
+use core::{
+    marker::PhantomData,
+    mem::size_of,
+    ptr::NonNull,
+    sync::atomic::{fence, AtomicUsize, Ordering},
+};
+use std::{ffi::CString, io};
+
+/// The state of the vector last published by the writer, at the start of every segment.
+#[repr(C)]
+struct Header {
+    /// Odd while the writer is publishing, like a sequence lock.
+    generation: AtomicUsize,
+    len: AtomicUsize,
+    capacity: AtomicUsize,
+    /// The offset of the buffer of the vector from the start of the segment.
+    offset: AtomicUsize,
+}
+
+/// A named shared memory segment mapped into this process.
+struct Segment {
+    ptr: NonNull<u8>,
+    len: usize,
+    name: String,
+    /// Whether the segment was created by this process, and is removed on drop.
+    owner: bool,
+}
+
+// SAFE: The mapping is only accessed through shared references to the header, and
+// through the storage or the reader that own the segment.
+unsafe impl Send for Segment {}
+unsafe impl Sync for Segment {}
+
+impl Segment {
+    fn create(name: &str, len: usize) -> io::Result<Self> {
+        let c_name = c_name(name)?;
+        let len = len
+            .checked_add(size_of::<Header>())
+            .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidInput))?;
+        let flags = libc::O_CREAT | libc::O_EXCL | libc::O_RDWR;
+        // SAFE: The name is nul terminated.
+        let fd = unsafe { libc::shm_open(c_name.as_ptr(), flags, MODE) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFE: `fd` is open, and is closed once it has been mapped.
+        let ptr = unsafe {
+            let ptr = match libc::ftruncate(fd, len as libc::off_t) {
+                0 => map(fd, len, libc::PROT_READ | libc::PROT_WRITE),
+                _ => Err(io::Error::last_os_error()),
+            };
+            libc::close(fd);
+            if ptr.is_err() {
+                libc::shm_unlink(c_name.as_ptr());
+            }
+            ptr?
+        };
+        Ok(Self {
+            ptr,
+            len,
+            name: String::from(name),
+            owner: true,
+        })
+    }
+
+    fn open(name: &str) -> io::Result<Self> {
+        let c_name = c_name(name)?;
+        // SAFE: The name is nul terminated.
+        let fd = unsafe { libc::shm_open(c_name.as_ptr(), libc::O_RDONLY, 0) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFE: `fd` is open, and is closed once it has been mapped.
+        let (ptr, len) = unsafe {
+            let mut stat = core::mem::zeroed::<libc::stat>();
+            let len = match libc::fstat(fd, &mut stat) {
+                0 => Ok(stat.st_size as usize),
+                _ => Err(io::Error::last_os_error()),
+            };
+            let ptr = len.and_then(|len| match len < size_of::<Header>() {
+                true => Err(io::Error::from(io::ErrorKind::InvalidData)),
+                false => map(fd, len, libc::PROT_READ).map(|ptr| (ptr, len)),
+            });
+            libc::close(fd);
+            ptr?
+        };
+        Ok(Self {
+            ptr,
+            len,
+            name: String::from(name),
+            owner: false,
+        })
+    }
+
+    fn header(&self) -> &Header {
+        // SAFE: Every segment starts with a zero-initialized header, and mappings are
+        // page aligned.
+        unsafe { &*self.ptr.as_ptr().cast::<Header>() }
+    }
+}
+
+impl Drop for Segment {
+    fn drop(&mut self) {
+        // SAFE: The mapping is no longer used, and the name was nul terminated when the
+        // segment was created.
+        unsafe {
+            libc::munmap(self.ptr.as_ptr().cast(), self.len);
+            if let (true, Ok(name)) = (self.owner, c_name(&self.name)) {
+                libc::shm_unlink(name.as_ptr());
+            }
+        }
+    }
+}
+
+#[cfg(target_vendor = "apple")]
+const MODE: libc::c_uint = 0o600;
+#[cfg(not(target_vendor = "apple"))]
+const MODE: libc::mode_t = 0o600;
+
+fn c_name(name: &str) -> io::Result<CString> {
+    CString::new(name).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+}
+
+/// Maps `len` bytes of `fd` as shared memory.
+///
+/// # Safety
+/// `fd` must be an open shared memory object of at least `len` bytes.
+unsafe fn map(fd: libc::c_int, len: usize, prot: libc::c_int) -> io::Result<NonNull<u8>> {
+    let ptr = libc::mmap(core::ptr::null_mut(), len, prot, libc::MAP_SHARED, fd, 0);
+    if ptr == libc::MAP_FAILED {
+        return Err(io::Error::last_os_error());
+    }
+    NonNull::new(ptr.cast::<u8>()).ok_or_else(io::Error::last_os_error)
+}
+
+/// A [`Storage`] backed by a named shared memory segment, which allows a [`ParallelVec`]
+/// to be shared with other processes.
+///
+/// The segment has room for a buffer of a fixed size, which the vector grows into in
+/// place. The state of the vector is made visible to readers with
+/// [`ParallelVec::publish`], which returns a [`ShmHandle`] that readers open with
+/// [`ShmReader::open`].
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::{ParallelVec, ShmReader, ShmStorage};
+///
+/// # let name = format!("/parallel_vec_doc_{}", std::process::id());
+/// let storage = ShmStorage::create(&name, 4096)?;
+/// let mut positions = ParallelVec::with_capacity_in(16, storage);
+/// positions.push((1u32, 0.5f32));
+/// positions.push((2, 0.25));
+/// let handle = positions.publish();
+///
+/// // In the reading process, with the handle sent over any IPC channel:
+/// // SAFE: The fields are plain data, and the writer does not modify them while they
+/// // are read.
+/// let reader = unsafe { ShmReader::<(u32, f32)>::open(&handle)? };
+/// assert_eq!(reader.as_parallel_slice().as_slices(), (&[1, 2][..], &[0.5, 0.25][..]));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct ShmStorage {
+    buffer: FixedStorage<'static>,
+    segment: Segment,
+}
+
+impl ShmStorage {
+    /// Creates a named shared memory segment with room for a buffer of `len` bytes, and a
+    /// storage that hands out that buffer.
+    ///
+    /// The segment is removed when the storage is dropped. Readers that have already
+    /// opened it keep their mapping.
+    ///
+    /// # Errors
+    /// Returns an error if `name` contains a nul byte, if a segment with the same name
+    /// already exists, or if the segment can not be created or mapped.
+    pub fn create(name: &str, len: usize) -> io::Result<Self> {
+        let segment = Segment::create(name, len)?;
+        // SAFE: The buffer follows the header within the mapping, which lives as long as
+        // the storage, and is only accessed through the storage.
+        let buffer = unsafe {
+            let ptr = segment.ptr.as_ptr().add(size_of::<Header>());
+            FixedStorage::from_raw_parts(NonNull::new_unchecked(ptr), len)
+        };
+        Ok(Self { buffer, segment })
+    }
+
+    /// Returns the name of the segment.
+    pub fn name(&self) -> &str {
+        &self.segment.name
+    }
+}
+
+// SAFE: Forwards to the fixed storage over the buffer of the segment.
+unsafe impl Storage for ShmStorage {
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        self.buffer.allocate(layout)
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.buffer.deallocate(ptr, layout)
+    }
+
+    #[inline]
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        self.buffer.grow(ptr, old_layout, new_layout)
+    }
+
+    #[inline]
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        self.buffer.shrink(ptr, old_layout, new_layout)
+    }
+}
+
+/// Describes a [`ParallelVec`] shared through a [`ShmStorage`], for opening it with a
+/// [`ShmReader`].
+///
+/// The handle is returned by [`ParallelVec::publish`]. It does not change as the vector
+/// grows, so it can be sent to readers once over any IPC channel, and rebuilt on their
+/// side with [`ShmHandle::new`]. The length and capacity of the vector are published
+/// through the segment itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShmHandle {
+    name: String,
+    column_align: usize,
+    field_sizes: Vec<usize>,
+}
+
+impl ShmHandle {
+    /// Creates a handle from its parts, as received from the writer.
+    pub fn new(name: String, column_align: usize, field_sizes: Vec<usize>) -> Self {
+        Self {
+            name,
+            column_align,
+            field_sizes,
+        }
+    }
+
+    /// The name of the shared memory segment.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The minimum alignment of every field within the buffer, in bytes.
+    pub fn column_align(&self) -> usize {
+        self.column_align
+    }
+
+    /// The size in bytes of each field of an element, used to check the type of the
+    /// reader.
+    pub fn field_sizes(&self) -> &[usize] {
+        &self.field_sizes
+    }
+}
+
+impl<Param: ParallelParam, Growth: GrowthPolicy> ParallelVec<Param, Growth, ShmStorage> {
+    /// Publishes the current length and capacity of the vector to its readers, and
+    /// returns the handle readers open it with.
+    ///
+    /// Readers only see elements up to the length at the last call. Elements that are
+    /// being read must not be modified, and the vector must not reallocate while it is
+    /// being read, as that moves the fields within the segment.
+    pub fn publish(&self) -> ShmHandle {
+        let segment = &self.alloc.segment;
+        let layout = Param::layout_for_capacity_aligned(self.capacity, self.column_align);
+        let offset = if layout.layout.size() == 0 {
+            0
+        } else {
+            // SAFE: A non-empty buffer is always allocated from within the segment.
+            unsafe {
+                Param::base(self.storage)
+                    .as_ptr()
+                    .offset_from(segment.ptr.as_ptr()) as usize
+            }
+        };
+        let header = segment.header();
+        let generation = header.generation.load(Ordering::Relaxed);
+        header
+            .generation
+            .store(generation.wrapping_add(1), Ordering::Relaxed);
+        fence(Ordering::Release);
+        header.len.store(self.len, Ordering::Relaxed);
+        header.capacity.store(self.capacity, Ordering::Relaxed);
+        header.offset.store(offset, Ordering::Relaxed);
+        header
+            .generation
+            .store(generation.wrapping_add(2), Ordering::Release);
+        ShmHandle {
+            name: String::from(self.alloc.name()),
+            column_align: self.column_align,
+            field_sizes: Param::field_sizes(1).as_ref().to_vec(),
+        }
+    }
+}
+
+/// A read-only view of a [`ParallelVec`] shared by another process through a
+/// [`ShmStorage`].
+///
+/// See [`ShmStorage`] for an example.
+pub struct ShmReader<Param: ParallelParam> {
+    segment: Segment,
+    column_align: usize,
+    _marker: PhantomData<fn() -> Param>,
+}
+
+// SAFE: The reader only hands out shared references to the elements.
+unsafe impl<Param: ParallelParam + Sync> Send for ShmReader<Param> {}
+unsafe impl<Param: ParallelParam + Sync> Sync for ShmReader<Param> {}
+
+impl<Param: ParallelParam> ShmReader<Param> {
+    /// Opens the shared memory segment described by `handle` for reading.
+    ///
+    /// # Errors
+    /// Returns an error if the fields of `Param` do not have the sizes described by the
+    /// handle, or if the segment can not be opened or mapped.
+    ///
+    /// # Safety
+    /// `Param` must be the type of the elements of the shared vector, and every value the
+    /// writer stores must be valid in this process, so the fields can not hold pointers or
+    /// references. The writer must not modify the elements that are being read, nor
+    /// reallocate the vector, while a slice returned by [`as_parallel_slice`] is alive.
+    ///
+    /// [`as_parallel_slice`]: Self::as_parallel_slice
+    pub unsafe fn open(handle: &ShmHandle) -> io::Result<Self> {
+        if handle.field_sizes() != Param::field_sizes(1).as_ref()
+            || !handle.column_align.is_power_of_two()
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the handle does not describe a vector of this type",
+            ));
+        }
+        Ok(Self {
+            segment: Segment::open(handle.name())?,
+            column_align: handle.column_align,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the number of elements last published by the writer.
+    pub fn len(&self) -> usize {
+        self.published().0
+    }
+
+    /// Returns `true` if the writer last published an empty vector.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the elements last published by the writer as a [`ParallelSlice`].
+    ///
+    /// # Panics
+    /// Panics if the published vector does not fit within the segment.
+    pub fn as_parallel_slice(&self) -> ParallelSlice<'_, Param> {
+        let (len, capacity, offset) = self.published();
+        let layout = Param::try_layout_for_capacity_aligned(capacity, self.column_align)
+            .filter(|layout| {
+                let base = self.segment.ptr.as_ptr().wrapping_add(offset);
+                len <= capacity
+                    && (layout.layout.size() == 0
+                        || (offset >= size_of::<Header>()
+                            && offset <= self.segment.len
+                            && self.segment.len - offset >= layout.layout.size()
+                            && base.align_offset(layout.layout.align()) == 0))
+            })
+            .expect("the published vector does not fit within the shared memory segment");
+        let storage = if layout.layout.size() == 0 {
+            Param::dangling()
+        } else {
+            // SAFE: The buffer was checked to lie within the segment, and to be aligned.
+            unsafe {
+                let base = NonNull::new_unchecked(self.segment.ptr.as_ptr().add(offset));
+                Param::from_base(base, &layout.offsets)
+            }
+        };
+        // SAFE: The writer published `len` initialized elements, which it does not modify
+        // while they are read, as required by `open`.
+        unsafe { ParallelSlice::from_raw_parts(storage, len) }
+    }
+
+    /// Reads the length, capacity, and buffer offset last published by the writer.
+    fn published(&self) -> (usize, usize, usize) {
+        let header = self.segment.header();
+        loop {
+            let generation = header.generation.load(Ordering::Acquire);
+            if generation & 1 == 0 {
+                let len = header.len.load(Ordering::Relaxed);
+                let capacity = header.capacity.load(Ordering::Relaxed);
+                let offset = header.offset.load(Ordering::Relaxed);
+                fence(Ordering::Acquire);
+                if header.generation.load(Ordering::Relaxed) == generation {
+                    return (len, capacity, offset);
+                }
+            }
+            core::hint::spin_loop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ShmReader, ShmStorage};
+    use crate::ParallelVec;
+    use std::{format, io::ErrorKind, string::String};
+
+    fn segment_name(test: &str) -> String {
+        format!("/parallel_vec_{}_{}", test, std::process::id())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_publish_and_read() {
+        let storage = ShmStorage::create(&segment_name("read"), 4096).unwrap();
+        let mut vec = ParallelVec::with_capacity_in(2, storage);
+        vec.push((1u32, 1.0f64, 1u8));
+        let handle = vec.publish();
+        let reader = unsafe { ShmReader::<(u32, f64, u8)>::open(&handle) }.unwrap();
+        assert_eq!(reader.len(), 1);
+        assert_eq!(reader.as_parallel_slice().as_slices().1, &[1.0]);
+
+        vec.extend((2..20).map(|idx| (idx, idx as f64, idx as u8)));
+        assert_eq!(reader.len(), 1);
+        assert_eq!(vec.publish(), handle);
+        let slice = reader.as_parallel_slice();
+        assert_eq!(slice.len(), 19);
+        assert_eq!(slice.as_slices().0, vec.as_slices().0);
+        assert_eq!(slice.as_slices().1, vec.as_slices().1);
+        assert_eq!(slice.as_slices().2, vec.as_slices().2);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_segment_lifetime() {
+        let name = segment_name("lifetime");
+        let vec = ParallelVec::<(u16,), _, _>::new_in(ShmStorage::create(&name, 64).unwrap());
+        let handle = vec.publish();
+        let err = ShmStorage::create(&name, 64).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        assert!(unsafe { ShmReader::<(u16,)>::open(&handle) }
+            .unwrap()
+            .is_empty());
+        assert!(unsafe { ShmReader::<(u32,)>::open(&handle) }.is_err());
+        drop(vec);
+        let err = unsafe { ShmReader::<(u16,)>::open(&handle) }.err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{ParallelSlice, ParallelSliceMut};
+use core::simd::{Simd, SimdElement};
+
+macro_rules! impl_simd {
+    ($($ts:ident $vs:ident),*) => {
+        impl<'a, $($ts: SimdElement),*> ParallelSlice<'a, ($($ts,)*)> {
+            /// Splits every field into an unaligned prefix, a middle of aligned
+            /// [`Simd`] vectors, and an unaligned suffix, as by [`slice::as_simd`].
+            ///
+            /// The split is computed separately for each field, so the prefixes
+            /// of different fields may have different lengths.
+            #[allow(clippy::type_complexity)]
+            pub fn as_simd<const LANES: usize>(&self) -> ($((&[$ts], &[Simd<$ts, LANES>], &[$ts]),)*)
+            where
+                $(Simd<$ts, LANES>: AsRef<[$ts; LANES]>,)*
+            {
+                let ($($vs,)*) = self.as_slices();
+                ($($vs.as_simd(),)*)
+            }
+        }
+
+        impl<'a, $($ts: SimdElement),*> ParallelSliceMut<'a, ($($ts,)*)> {
+            /// Splits every field into an unaligned prefix, a middle of aligned
+            /// [`Simd`] vectors, and an unaligned suffix, as by [`slice::as_simd`].
+            ///
+            /// The split is computed separately for each field, so the prefixes
+            /// of different fields may have different lengths.
+            #[allow(clippy::type_complexity)]
+            pub fn as_simd<const LANES: usize>(&self) -> ($((&[$ts], &[Simd<$ts, LANES>], &[$ts]),)*)
+            where
+                $(Simd<$ts, LANES>: AsRef<[$ts; LANES]>,)*
+            {
+                let ($($vs,)*) = self.as_slices();
+                ($($vs.as_simd(),)*)
+            }
+
+            /// Splits every field into a mutable unaligned prefix, a middle of
+            /// aligned [`Simd`] vectors, and an unaligned suffix, as by
+            /// [`slice::as_simd_mut`].
+            ///
+            /// The split is computed separately for each field, so the prefixes
+            /// of different fields may have different lengths.
+            #[allow(clippy::type_complexity)]
+            pub fn as_simd_mut<const LANES: usize>(
+                &mut self,
+            ) -> ($((&mut [$ts], &mut [Simd<$ts, LANES>], &mut [$ts]),)*)
+            where
+                $(Simd<$ts, LANES>: AsMut<[$ts; LANES]>,)*
+            {
+                let ($($vs,)*) = self.as_slices_mut();
+                ($($vs.as_simd_mut(),)*)
+            }
+        }
+    };
+}
+
+impl_simd!(T1 V1);
+impl_simd!(T1 V1, T2 V2);
+impl_simd!(T1 V1, T2 V2, T3 V3);
+impl_simd!(T1 V1, T2 V2, T3 V3, T4 V4);
+impl_simd!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5);
+impl_simd!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6);
+impl_simd!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7);
+impl_simd!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8);
+impl_simd!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9);
+impl_simd!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10);
+impl_simd!(T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11);
+impl_simd!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12
+);
+impl_simd!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13
+);
+impl_simd!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14
+);
+impl_simd!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14, T15 V15
+);
+impl_simd!(
+    T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14, T15 V15, T16 V16
+);
+
+#[cfg(test)]
+mod test {
+    use crate::ParallelVec;
+    use core::simd::{num::SimdFloat, Simd};
+
+    #[test]
+    fn test_as_simd() {
+        let vec: ParallelVec<(f32, u32)> = (0..37).map(|i| (i as f32, i)).collect();
+        let ((prefix, middle, suffix), (ids_prefix, ids_middle, ids_suffix)) = vec.as_simd::<4>();
+        assert_eq!(prefix.len() + middle.len() * 4 + suffix.len(), 37);
+        assert_eq!(
+            ids_prefix.len() + ids_middle.len() * 4 + ids_suffix.len(),
+            37
+        );
+
+        let sum = prefix.iter().sum::<f32>()
+            + middle.iter().copied().sum::<Simd<f32, 4>>().reduce_sum()
+            + suffix.iter().sum::<f32>();
+        assert_eq!(sum, (0..37).sum::<u32>() as f32);
+
+        let tail = vec.get(1..).unwrap();
+        let ((prefix, middle, suffix), _) = tail.as_simd::<4>();
+        assert_eq!(prefix.len() + middle.len() * 4 + suffix.len(), 36);
+    }
+
+    #[test]
+    fn test_as_simd_mut() {
+        let mut vec: ParallelVec<(f32, f32)> = (0..37).map(|i| (i as f32, 2.0)).collect();
+        let mut slice = vec.as_parallel_slice_mut();
+        let ((prefix, middle, suffix), _) = slice.as_simd_mut::<8>();
+        for value in prefix.iter_mut().chain(suffix.iter_mut()) {
+            *value *= 2.0;
+        }
+        for lanes in middle {
+            *lanes *= Simd::splat(2.0);
+        }
+        let (xs, ys) = vec.as_slices();
+        assert!(xs.iter().enumerate().all(|(i, &x)| x == i as f32 * 2.0));
+        assert!(ys.iter().all(|&y| y == 2.0));
+    }
+
+    #[test]
+    fn test_as_simd_empty() {
+        let vec: ParallelVec<(f64, u8)> = ParallelVec::new();
+        let ((prefix, middle, suffix), (ids_prefix, ids_middle, ids_suffix)) = vec.as_simd::<2>();
+        assert!(prefix.is_empty() && middle.is_empty() && suffix.is_empty());
+        assert!(ids_prefix.is_empty() && ids_middle.is_empty() && ids_suffix.is_empty());
+    }
+}
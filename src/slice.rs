@@ -1,12 +1,15 @@
+use crate::index::{self, ParallelIndex};
 use crate::iter::{Iter, IterMut};
-use crate::ParallelParam;
-use crate::{assert_in_bounds, assert_in_bounds_inclusive};
+use crate::param::{AssertDisjoint, AssertDistinct, ParallelColumn, ParallelSelection};
+use crate::{assert_in_bounds, slice_range, try_slice_range, ParallelSliceFromBytesError};
+use crate::{ParallelParam, ParallelVec};
 use alloc::vec::Vec;
 use core::{
     cmp::Ordering,
     hash::{Hash, Hasher},
     marker::PhantomData,
-    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo},
+    ops::{Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo},
+    ptr::NonNull,
 };
 
 /// A immutable dynamically-sized view into a contiguous heterogeneous sequence.
@@ -18,10 +21,30 @@ use core::{
 #[repr(C)]
 pub struct ParallelSlice<'a, Param: ParallelParam> {
     // Do not reorder these fields. These must be in the same order as
-    // ParallelVec for Deref and DerefMut to work properly.
-    len: usize,
-    storage: Param::Storage,
-    _marker: PhantomData<&'a usize>,
+    // ParallelVec for Deref to work properly.
+    pub(crate) len: usize,
+    pub(crate) storage: Param::Storage,
+    _marker: PhantomData<&'a Param>,
+}
+
+// SAFE: The slice only gives out shared references to its elements, like a `&[T]`.
+unsafe impl<'a, Param: ParallelParam + Sync> Send for ParallelSlice<'a, Param> {}
+unsafe impl<'a, Param: ParallelParam + Sync> Sync for ParallelSlice<'a, Param> {}
+
+// A shared view can be freely copied, like a `&[T]`.
+impl<'a, Param: ParallelParam> Clone for ParallelSlice<'a, Param> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, Param: ParallelParam> Copy for ParallelSlice<'a, Param> {}
+
+impl<'a, Param: ParallelParam> From<ParallelSliceMut<'a, Param>> for ParallelSlice<'a, Param> {
+    fn from(slice: ParallelSliceMut<'a, Param>) -> Self {
+        // SAFE: The unique borrow is given up for a shared borrow of the same lifetime.
+        unsafe { Self::from_raw_parts(slice.storage, slice.len) }
+    }
 }
 
 impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
@@ -58,6 +81,50 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
         }
     }
 
+    /// Forms a slice over an existing byte buffer, such as a memory mapped file.
+    ///
+    /// The buffer must use the same layout as the buffer of a [`ParallelVec`] with
+    /// the provided `capacity`: each field is stored as a contiguous array of `capacity`
+    /// elements, in order, each starting at the next offset suitably aligned for its type.
+    /// Only the first `len` elements of each field are viewed.
+    ///
+    /// # Errors
+    /// Returns an error if `len` is greater than `capacity`, if the layout for `capacity`
+    /// overflows, if `bytes` is too small for the layout, or if `bytes` is not aligned
+    /// to the alignment of the layout.
+    ///
+    /// # Safety
+    /// The first `len` elements of each field in `bytes` must be properly initialized
+    /// values of their respective types.
+    ///
+    /// [`ParallelVec`]: crate::ParallelVec
+    pub unsafe fn from_bytes(
+        bytes: &'a [u8],
+        capacity: usize,
+        len: usize,
+    ) -> Result<Self, ParallelSliceFromBytesError> {
+        if len > capacity {
+            return Err(ParallelSliceFromBytesError::LengthExceedsCapacity);
+        }
+        let layout = Param::try_layout_for_capacity(capacity)
+            .ok_or(ParallelSliceFromBytesError::CapacityOverflow)?;
+        if bytes.len() < layout.layout.size() {
+            return Err(ParallelSliceFromBytesError::BufferTooSmall {
+                required: layout.layout.size(),
+            });
+        }
+        if bytes.as_ptr().align_offset(layout.layout.align()) != 0 {
+            return Err(ParallelSliceFromBytesError::Misaligned {
+                align: layout.layout.align(),
+            });
+        }
+        let base = NonNull::from(bytes).cast::<u8>();
+        Ok(Self::from_raw_parts(
+            Param::from_base(base, &layout.offsets),
+            len,
+        ))
+    }
+
     /// Returns the number of elements in the vector, also referred to as its ‘length’.
     pub fn len(&self) -> usize {
         self.len
@@ -73,7 +140,7 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
     ///
     /// [`None`]: Option::None
     #[inline]
-    pub fn get<'b: 'a, I: ParallelSliceIndex<Self>>(&'b self, index: I) -> Option<I::Output> {
+    pub fn get<I: ParallelSliceIndex<Self>>(&self, index: I) -> Option<I::Output<'_>> {
         index.get(self)
     }
 
@@ -98,7 +165,7 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
     /// # Panics
     /// This function will panic if `index` is >= `self.len`.
     #[inline]
-    pub fn index<I>(&self, index: I) -> I::Output
+    pub fn index<I>(&self, index: I) -> I::Output<'_>
     where
         I: ParallelSliceIndex<Self>,
     {
@@ -118,6 +185,58 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
         Param::as_ref(Param::ptr_at(self.storage, index))
     }
 
+    /// Creates a view of only the fields selected by `S`, such as
+    /// `(Column<0>, Column<2>)`. The other fields are not accessible through the view.
+    ///
+    /// A field may be selected more than once.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::{Column, ParallelVec};
+    ///
+    /// let vec = ParallelVec::from(vec![(1, 'a', 1.0), (2, 'b', 2.0)]);
+    /// let view = vec.select::<(Column<2>, Column<0>)>();
+    /// assert_eq!(view.as_slices(), (&[1.0, 2.0][..], &[1, 2][..]));
+    /// ```
+    pub fn select<S: ParallelSelection<Param>>(&self) -> ParallelSlice<'a, S::Output> {
+        // SAFE: The selected fields have the same length and are borrowed for the same
+        // lifetime as `self`.
+        unsafe { ParallelSlice::from_raw_parts(S::select(self.storage), self.len) }
+    }
+
+    /// Creates a new [`ParallelVec`] from clones of every element of the slice.
+    ///
+    /// This is the equivalent of `to_vec` on a slice. The vector is allocated once with
+    /// exactly the length of the slice.
+    ///
+    /// [`ParallelVec`]: crate::ParallelVec
+    pub fn to_parallel_vec(&self) -> ParallelVec<Param>
+    where
+        Param: Clone,
+    {
+        let mut vec = ParallelVec::with_capacity(self.len);
+        // SAFE: The slice is borrowed while it is cloned, and the vector has the capacity
+        // for every element.
+        unsafe { vec.extend_cloned(Param::as_ptr(self.storage), self.len) };
+        vec.validate();
+        vec
+    }
+
+    /// Returns read-only raw pointers to the slice’s buffer, one per field.
+    ///
+    /// The caller must ensure that the slice outlives the pointers this function returns, or else they will end up
+    /// pointing to garbage. The memory the pointers point to must never be written to. Use [`as_mut_ptrs`] on a
+    /// [`ParallelSliceMut`] if mutation is needed.
+    ///
+    /// Modifying the container referenced by this slice may cause its buffer to be reallocated, which would also make any
+    /// pointers to it invalid.
+    ///
+    /// [`as_mut_ptrs`]: ParallelSliceMut::as_mut_ptrs
+    #[inline]
+    pub fn as_ptrs(&self) -> Param::ConstPtr {
+        Param::as_const_ptr(self.storage)
+    }
+
     /// Gets the individual slices for every sub-slice.
     #[inline]
     pub fn as_slices(&self) -> Param::Slices<'_> {
@@ -141,13 +260,200 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
             Param::iters(slices)
         }
     }
+
+    /// Returns the indices of the elements of the [`ParallelSlice`] in the order they would be
+    /// in if sorted with a comparator function, without moving any of the elements.
+    ///
+    /// This sort is stable (i.e. does not reorder equal elements).
+    pub fn argsort_by<F>(&self, f: F) -> Vec<usize>
+    where
+        F: Fn(Param::Ref<'_>, Param::Ref<'_>) -> Ordering,
+    {
+        let base = Param::as_ptr(self.storage);
+        let mut indices: Vec<usize> = (0..self.len).collect();
+        indices.sort_by(|a, b| unsafe {
+            f(
+                Param::as_ref(Param::add(base, *a)),
+                Param::as_ref(Param::add(base, *b)),
+            )
+        });
+        indices
+    }
+
+    /// Returns the indices of the elements of the [`ParallelSlice`] in the order they would be
+    /// in if sorted with a key extraction function, without moving any of the elements.
+    ///
+    /// This sort is stable (i.e. does not reorder equal elements).
+    pub fn argsort_by_key<K, F>(&self, f: F) -> Vec<usize>
+    where
+        F: Fn(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        let base = Param::as_ptr(self.storage);
+        let mut indices: Vec<usize> = (0..self.len).collect();
+        indices.sort_by_key(|idx| unsafe { f(Param::as_ref(Param::add(base, *idx))) });
+        indices
+    }
+
+    /// Like [`argsort_by`], but returns the indices as `I`, which can be narrower than
+    /// `usize` to save memory.
+    ///
+    /// # Panics
+    /// Panics if the length of the [`ParallelSlice`] does not fit in `I`.
+    ///
+    /// [`argsort_by`]: Self::argsort_by
+    pub fn argsort_by_as<I, F>(&self, f: F) -> Vec<I>
+    where
+        I: ParallelIndex,
+        F: Fn(Param::Ref<'_>, Param::Ref<'_>) -> Ordering,
+    {
+        let base = Param::as_ptr(self.storage);
+        let mut indices: Vec<I> = index::indices(self.len);
+        indices.sort_by(|a, b| unsafe {
+            f(
+                Param::as_ref(Param::add(base, a.index())),
+                Param::as_ref(Param::add(base, b.index())),
+            )
+        });
+        indices
+    }
+
+    /// Like [`argsort_by_key`], but returns the indices as `I`, which can be narrower
+    /// than `usize` to save memory.
+    ///
+    /// # Panics
+    /// Panics if the length of the [`ParallelSlice`] does not fit in `I`.
+    ///
+    /// [`argsort_by_key`]: Self::argsort_by_key
+    pub fn argsort_by_key_as<I, K, F>(&self, f: F) -> Vec<I>
+    where
+        I: ParallelIndex,
+        F: Fn(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        let base = Param::as_ptr(self.storage);
+        let mut indices: Vec<I> = index::indices(self.len);
+        indices.sort_by_key(|idx| unsafe { f(Param::as_ref(Param::add(base, idx.index()))) });
+        indices
+    }
+
+    /// Binary searches the [`ParallelSlice`] with a comparator function, which should return
+    /// the ordering of an element relative to the target.
+    ///
+    /// The slice must be sorted consistently with the comparator. If the target is found,
+    /// returns `Ok` with the index of a matching element. If there are multiple matches,
+    /// any one of them may be returned. If it is not found, returns `Err` with the index
+    /// where a matching element could be inserted while keeping the slice sorted.
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> Ordering,
+    {
+        binary_search_by::<Param, F>(self.storage, self.len, f)
+    }
+
+    /// Binary searches the [`ParallelSlice`] for the key `b` with a key extraction function.
+    ///
+    /// The slice must be sorted by the key. See [`binary_search_by`] for the returned value.
+    ///
+    /// [`binary_search_by`]: Self::binary_search_by
+    pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|value| f(value).cmp(b))
+    }
+
+    /// Binary searches the field at index `N` of the [`ParallelSlice`] for `value`.
+    ///
+    /// The slice must be sorted by the field, e.g. with `sort_by_column::<N>()`. See
+    /// [`binary_search_by`] for the returned value.
+    ///
+    /// [`binary_search_by`]: Self::binary_search_by
+    pub fn binary_search_column<const N: usize>(
+        &self,
+        value: &<Param as ParallelColumn<N>>::Column,
+    ) -> Result<usize, usize>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Ord,
+    {
+        Param::column(self.as_slices()).binary_search(value)
+    }
+
+    /// Checks if the elements of the [`ParallelSlice`] are sorted using a comparator function,
+    /// which should return `true` if its two arguments are in order.
+    ///
+    /// Empty slices and slices of a single element are always sorted.
+    pub fn is_sorted_by<F>(&self, f: F) -> bool
+    where
+        F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+    {
+        is_sorted_by::<Param, F>(self.storage, self.len, f)
+    }
+
+    /// Checks if the elements of the [`ParallelSlice`] are sorted by a key extraction function.
+    pub fn is_sorted_by_key<K, F>(&self, mut f: F) -> bool
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| f(a) <= f(b))
+    }
+
+    /// Checks if the field at index `N` of the [`ParallelSlice`] is sorted.
+    pub fn is_sorted_column<const N: usize>(&self) -> bool
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: PartialOrd,
+    {
+        Param::column(self.as_slices())
+            .windows(2)
+            .all(|pair| pair[0] <= pair[1])
+    }
 }
 
-impl<'s, 'r, Param> Hash for ParallelSlice<'s, Param>
+/// Checks if `len` elements starting at `storage` are sorted with a comparator function.
+fn is_sorted_by<Param: ParallelParam, F>(storage: Param::Storage, len: usize, mut f: F) -> bool
+where
+    F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+{
+    let base = Param::as_ptr(storage);
+    // SAFE: Both `idx - 1` and `idx` are less than `len`.
+    (1..len).all(|idx| unsafe {
+        f(
+            Param::as_ref(Param::add(base, idx - 1)),
+            Param::as_ref(Param::add(base, idx)),
+        )
+    })
+}
+
+/// Binary searches `len` elements starting at `storage` with a comparator function.
+fn binary_search_by<Param: ParallelParam, F>(
+    storage: Param::Storage,
+    len: usize,
+    mut f: F,
+) -> Result<usize, usize>
+where
+    F: FnMut(Param::Ref<'_>) -> Ordering,
+{
+    let (mut left, mut right) = (0, len);
+    while left < right {
+        let mid = left + (right - left) / 2;
+        // SAFE: `mid` is always less than `right`, which is at most `len`.
+        match f(unsafe { Param::as_ref(Param::ptr_at(storage, mid)) }) {
+            Ordering::Less => left = mid + 1,
+            Ordering::Greater => right = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+    Err(left)
+}
+
+impl<'s, Param> Hash for ParallelSlice<'s, Param>
 where
     Param: ParallelParam + 's,
     Param::Ref<'s>: Hash,
-    'r: 's,
 {
     fn hash<H>(&self, hasher: &mut H)
     where
@@ -166,15 +472,35 @@ where
 ///
 /// Unlike a struct of slices, this type only stores one length instead
 /// of duplicating the values across multiple slice fields.
+///
+/// References into the slice are always tied to the borrow of the slice
+/// itself, so they cannot outlive the [`ParallelVec`] that owns them:
+///
+/// ```compile_fail
+/// # use parallel_vec::ParallelVec;
+/// let item;
+/// {
+///     let mut vec = ParallelVec::new();
+///     vec.push((1, 2));
+///     item = vec.index(0);
+/// }
+/// println!("{:?}", item);
+/// ```
+///
+/// [`ParallelVec`]: crate::ParallelVec
 #[repr(C)]
 pub struct ParallelSliceMut<'a, Param: ParallelParam> {
     // Do not reorder these fields. These must be in the same order as
-    // ParallelVec for Deref and DerefMut to work properly.
-    len: usize,
-    storage: Param::Storage,
+    // ParallelVec for Deref to work properly.
+    pub(crate) len: usize,
+    pub(crate) storage: Param::Storage,
     _marker: PhantomData<&'a usize>,
 }
 
+// SAFE: The slice uniquely borrows its elements, like a `&mut [T]`.
+unsafe impl<'a, Param: ParallelParam + Send> Send for ParallelSliceMut<'a, Param> {}
+unsafe impl<'a, Param: ParallelParam + Sync> Sync for ParallelSliceMut<'a, Param> {}
+
 impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// Forms a slice from a pointer and a length.
     ///
@@ -219,12 +545,162 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         self.len == 0
     }
 
+    /// Creates a new [`ParallelVec`] from clones of every element of the slice.
+    ///
+    /// This is the equivalent of `to_vec` on a slice. The vector is allocated once with
+    /// exactly the length of the slice.
+    ///
+    /// [`ParallelVec`]: crate::ParallelVec
+    pub fn to_parallel_vec(&self) -> ParallelVec<Param>
+    where
+        Param: Clone,
+    {
+        let mut vec = ParallelVec::with_capacity(self.len);
+        // SAFE: The slice is borrowed while it is cloned, and the vector has the capacity
+        // for every element.
+        unsafe { vec.extend_cloned(Param::as_ptr(self.storage), self.len) };
+        vec.validate();
+        vec
+    }
+
+    /// Creates a view of only the fields selected by `S`, such as
+    /// `(Column<0>, Column<2>)`. The other fields are not accessible through the view.
+    ///
+    /// A field may be selected more than once.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::{Column, ParallelVec};
+    ///
+    /// let vec = ParallelVec::from(vec![(1, 'a', 1.0), (2, 'b', 2.0)]);
+    /// let view = vec.select::<(Column<2>, Column<0>)>();
+    /// assert_eq!(view.as_slices(), (&[1.0, 2.0][..], &[1, 2][..]));
+    /// ```
+    pub fn select<S: ParallelSelection<Param>>(&self) -> ParallelSlice<'_, S::Output> {
+        // SAFE: The shared slice borrows from `self`, so it cannot be mutated.
+        unsafe { ParallelSlice::from_raw_parts(S::select(self.storage), self.len) }
+    }
+
+    /// Creates a mutable view of only the fields selected by `S`, such as
+    /// `(Column<0>, Column<2>)`. The other fields are left untouched.
+    ///
+    /// Operations that move elements, like sorting or swapping, only move the selected
+    /// fields, and so change which values of the selected and unselected fields share
+    /// an index.
+    ///
+    /// Selecting a field more than once is a compile-time error, raised when the
+    /// method is instantiated with the selection.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::{Column, ParallelVec};
+    ///
+    /// let mut vec = ParallelVec::from(vec![(1.0f32, "a", 0.5f32), (2.0, "b", 0.25)]);
+    /// let mut slice = vec.as_parallel_slice_mut();
+    /// for (position, velocity) in slice.select_mut::<(Column<0>, Column<2>)>().iter_mut() {
+    ///     *position += *velocity;
+    /// }
+    /// assert_eq!(vec.as_slices().0, &[1.5, 2.25]);
+    /// ```
+    ///
+    /// Selecting the same field twice does not compile:
+    /// ```compile_fail
+    /// use parallel_vec::{Column, ParallelVec};
+    ///
+    /// let mut vec = ParallelVec::from(vec![(1, 2), (3, 4)]);
+    /// vec.as_parallel_slice_mut().select_mut::<(Column<1>, Column<1>)>();
+    /// ```
+    pub fn select_mut<S: ParallelSelection<Param>>(&mut self) -> ParallelSliceMut<'_, S::Output> {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertDistinct::<Param, S>::OK;
+        // SAFE: The selected fields are distinct, and are uniquely borrowed from `self`.
+        unsafe { ParallelSliceMut::from_raw_parts(S::select(self.storage), self.len) }
+    }
+
+    /// Splits the fields into two independent mutable views, one of the fields
+    /// selected by `A` and one of the fields selected by `B`.
+    ///
+    /// As the views do not share any fields, they can be used at the same time, for
+    /// example to update different fields of the same elements on different threads.
+    ///
+    /// Selecting a field more than once, either within `A` or `B`, or by both of them,
+    /// is a compile-time error, raised when the method is instantiated with the
+    /// selections.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::{Column, ParallelVec};
+    ///
+    /// let mut vec = ParallelVec::from(vec![(1, 'a', 1.0), (2, 'b', 2.0)]);
+    /// let mut slice = vec.as_parallel_slice_mut();
+    /// let (mut numbers, mut rest) = slice.split_columns_mut::<(Column<0>,), (Column<2>, Column<1>)>();
+    /// for ((number,), (float, _)) in numbers.iter_mut().zip(rest.iter_mut()) {
+    ///     *number *= 10;
+    ///     *float += 0.5;
+    /// }
+    /// assert_eq!(vec.as_slices(), (&[10, 20][..], &['a', 'b'][..], &[1.5, 2.5][..]));
+    /// ```
+    ///
+    /// Overlapping selections do not compile:
+    /// ```compile_fail
+    /// use parallel_vec::{Column, ParallelVec};
+    ///
+    /// let mut vec = ParallelVec::from(vec![(1, 2, 3), (4, 5, 6)]);
+    /// vec.as_parallel_slice_mut()
+    ///     .split_columns_mut::<(Column<0>, Column<1>), (Column<1>,)>();
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split_columns_mut<A, B>(
+        &mut self,
+    ) -> (
+        ParallelSliceMut<'_, A::Output>,
+        ParallelSliceMut<'_, B::Output>,
+    )
+    where
+        A: ParallelSelection<Param>,
+        B: ParallelSelection<Param>,
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertDisjoint::<Param, A, B>::OK;
+        // SAFE: Every selected field is distinct, so the two views never alias, and both
+        // uniquely borrow from `self`.
+        unsafe {
+            (
+                ParallelSliceMut::from_raw_parts(A::select(self.storage), self.len),
+                ParallelSliceMut::from_raw_parts(B::select(self.storage), self.len),
+            )
+        }
+    }
+
+    /// Borrows the elements as a shared [`ParallelSlice`].
+    ///
+    /// This allows functions that only read parallel data to take a [`ParallelSlice`],
+    /// and be called with a [`ParallelVec`], a [`ParallelSliceMut`], or a sub-slice of
+    /// either.
+    ///
+    /// [`ParallelVec`]: crate::ParallelVec
+    #[inline]
+    pub fn as_parallel_slice(&self) -> ParallelSlice<'_, Param> {
+        // SAFE: The shared slice borrows from `self`, so it cannot be mutated.
+        unsafe { ParallelSlice::from_raw_parts(self.storage, self.len) }
+    }
+
+    /// Reborrows the elements as a [`ParallelSliceMut`] with a shorter lifetime.
+    ///
+    /// This allows the slice to be passed by value to a function that takes a
+    /// [`ParallelSliceMut`] and then used again afterwards.
+    #[inline]
+    pub fn as_parallel_slice_mut(&mut self) -> ParallelSliceMut<'_, Param> {
+        // SAFE: The new slice uniquely borrows from `self` for its lifetime.
+        unsafe { ParallelSliceMut::from_raw_parts(self.storage, self.len) }
+    }
+
     /// Returns a immutable reference to the element at `index`, if available, or
     /// [`None`] if it is out of bounds.
     ///
     /// [`None`]: Option::None
     #[inline]
-    pub fn get<'b: 'a, I>(&'b self, index: I) -> Option<I::Output>
+    pub fn get<I>(&self, index: I) -> Option<I::Output<'_>>
     where
         I: ParallelSliceIndex<Self>,
     {
@@ -236,7 +712,7 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     ///
     /// [`None`]: Option::None
     #[inline]
-    pub fn get_mut<I>(&mut self, index: I) -> Option<I::Output>
+    pub fn get_mut<I>(&mut self, index: I) -> Option<I::Output<'_>>
     where
         I: ParallelSliceIndexMut<Self>,
     {
@@ -251,7 +727,7 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
 
     /// Returns the mutable pointer first element of the slice, or `None` if it is empty.
     #[inline(always)]
-    pub fn first_mut(&mut self) -> Option<Param::RefMut<'a>> {
+    pub fn first_mut(&mut self) -> Option<Param::RefMut<'_>> {
         self.get_mut(0)
     }
 
@@ -280,7 +756,7 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// # Panics
     /// This function will panic if `index >= self.len`.
     #[inline]
-    pub fn index<I>(&self, index: I) -> I::Output
+    pub fn index<I>(&self, index: I) -> I::Output<'_>
     where
         I: ParallelSliceIndex<Self>,
     {
@@ -292,7 +768,7 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// # Panics
     /// This function will panic if `index >= self.len`.
     #[inline]
-    pub fn index_mut<I>(&mut self, index: I) -> I::Output
+    pub fn index_mut<I>(&mut self, index: I) -> I::Output<'_>
     where
         I: ParallelSliceIndexMut<Self>,
     {
@@ -318,9 +794,39 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// The set is only safe if `index >= self.len`.
     #[inline]
     pub unsafe fn set_unchecked(&mut self, index: usize, value: Param) {
+        // Write the new value before dropping the old one, so the slot remains
+        // initialized even if the old value's Drop impl panics.
+        drop(self.replace_unchecked(index, value));
+    }
+
+    /// Replaces the value at an valid index in the slice, returning the old value.
+    ///
+    /// This is the equivalent of [`core::mem::replace`] for a single element. The old
+    /// value is moved out and is not dropped.
+    ///
+    /// # Panics
+    /// This function will panic if `index >= self.len`.
+    #[inline]
+    pub fn replace(&mut self, index: usize, value: Param) -> Param {
+        assert_in_bounds(index, self.len);
+        unsafe { self.replace_unchecked(index, value) }
+    }
+
+    /// Replaces the value at an valid index in the slice, returning the old value,
+    /// without checking bounds.
+    ///
+    /// For a safe alternative see [`replace`].
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is undefined behavior.
+    ///
+    /// [`replace`]: Self::replace
+    #[inline]
+    pub unsafe fn replace_unchecked(&mut self, index: usize, value: Param) -> Param {
         let ptr = Param::ptr_at(self.storage, index);
-        Param::drop(ptr);
+        let old = Param::read(ptr);
         Param::write(ptr, value);
+        old
     }
 
     /// Returns references to elements, without doing bounds checking.
@@ -349,7 +855,48 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         Param::as_mut(Param::ptr_at(self.storage, index))
     }
 
-    /// Sorts the slice with a comparator function.
+    /// Returns mutable references to many elements at once, or `None` if any index is
+    /// out of bounds or if any two indices are equal.
+    ///
+    /// This is a `O(N^2)` check on the number of provided indices, and is intended for
+    /// small `N`.
+    #[inline]
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Option<[Param::RefMut<'_>; N]> {
+        for (i, idx) in indices.iter().enumerate() {
+            if *idx >= self.len || indices[..i].contains(idx) {
+                return None;
+            }
+        }
+        // SAFE: All indices have been checked to be in bounds and disjoint.
+        unsafe { Some(self.get_many_unchecked_mut(indices)) }
+    }
+
+    /// Returns mutable references to many elements at once, without doing bounds
+    /// or overlap checking.
+    ///
+    /// For a safe alternative see [`get_many_mut`].
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds index or with overlapping indices is
+    /// undefined behavior even if the resulting references are not used.
+    ///
+    /// [`get_many_mut`]: Self::get_many_mut
+    #[inline]
+    pub unsafe fn get_many_unchecked_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> [Param::RefMut<'_>; N] {
+        let base = Param::as_ptr(self.storage);
+        indices.map(|idx| Param::as_mut(Param::add(base, idx)))
+    }
+
+    /// Sorts the slice with a comparator function over the rows of the slice,
+    /// moving every field of each element together.
+    ///
+    /// This sort is stable (i.e. does not reorder equal elements).
     ///
     /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
     /// buffer.
@@ -360,7 +907,7 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// [`slice::sort_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by
     pub fn sort_by<F>(&mut self, f: F)
     where
-        F: Fn(Param::Ref<'a>, Param::Ref<'a>) -> Ordering,
+        F: Fn(Param::Ref<'_>, Param::Ref<'_>) -> Ordering,
     {
         let base = Param::as_ptr(self.storage);
         self.sort_via(|indices| {
@@ -384,7 +931,7 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// [`slice::sort_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by_key
     pub fn sort_by_key<K, F>(&mut self, f: F)
     where
-        F: Fn(Param::Ref<'a>) -> K,
+        F: Fn(Param::Ref<'_>) -> K,
         K: Ord,
     {
         let base = Param::as_ptr(self.storage);
@@ -393,11 +940,45 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         });
     }
 
+    /// Sorts the slice with a key extraction function, calling it only once per element.
+    ///
+    /// This sort is stable (i.e. does not reorder equal elements).
+    ///
+    /// This is faster than [`sort_by_key`] when the key is expensive to compute, as the
+    /// keys are computed once into a temporary buffer, sorted, and the resulting
+    /// permutation applied to every field. This function will allocate
+    /// `(sizeof(K) + 2 * sizeof(usize)) * self.len` bytes as an intermediate sorting buffer.
+    ///
+    /// This defers to the `core` implemenation of [`slice::sort_by_cached_key`], so any
+    /// properties it has will also hold for this function.
+    ///
+    /// [`sort_by_key`]: Self::sort_by_key
+    /// [`slice::sort_by_cached_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by_cached_key
+    pub fn sort_by_cached_key<K, F>(&mut self, f: F)
+    where
+        F: Fn(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        let base = Param::as_ptr(self.storage);
+        self.sort_via(|indices| {
+            let mut keys: Vec<(K, usize)> = indices
+                .iter()
+                .map(|idx| unsafe { (f(Param::as_ref(Param::add(base, *idx))), *idx) })
+                .collect();
+            // The indices are unique, so an unstable sort of the pairs is stable.
+            keys.sort_unstable();
+            for (dst, (_, src)) in indices.iter_mut().zip(keys) {
+                *dst = src;
+            }
+        });
+    }
+
     /// Sorts the slice with a comparator function, but might not preserve the order of equal
     /// elements.
     ///
     /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
-    /// buffer.
+    /// buffer. Only the indices are moved while sorting, and every element is then moved at
+    /// most once into its final position, which keeps the cost of sorting wide elements low.
     ///
     /// This defers to the `core` implemenation of [`slice::sort_unstable_by`], so any properties it
     /// has will also hold for this function.
@@ -405,7 +986,7 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// [`slice::sort_unstable_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_unstable_by
     pub fn sort_unstable_by<F>(&mut self, f: F)
     where
-        F: Fn(Param::Ref<'a>, Param::Ref<'a>) -> Ordering,
+        F: Fn(Param::Ref<'_>, Param::Ref<'_>) -> Ordering,
     {
         let base = Param::as_ptr(self.storage);
         self.sort_via(|indices| {
@@ -430,7 +1011,7 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// [`slice::sort_unstable_by_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_unstable_by_key
     pub fn sort_unstable_by_key<K, F>(&mut self, f: F)
     where
-        F: Fn(Param::Ref<'a>) -> K,
+        F: Fn(Param::Ref<'_>) -> K,
         K: Ord,
     {
         let base = Param::as_ptr(self.storage);
@@ -439,6 +1020,169 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         });
     }
 
+    /// Sorts the slice by the values of the field at index `N`, e.g. `sort_by_column::<0>()`
+    /// sorts the slice by its first field.
+    ///
+    /// This sort is stable (i.e. does not reorder equal elements), and only reads the
+    /// sorted field while comparing elements.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
+    /// buffer.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let mut particles = ParallelVec::from(vec![(3u32, 0.5f32), (1, 1.5), (2, 2.5)]);
+    /// particles.as_parallel_slice_mut().sort_by_column::<0>();
+    /// assert_eq!(particles.as_slices(), (&[1, 2, 3][..], &[1.5, 2.5, 0.5][..]));
+    /// ```
+    pub fn sort_by_column<const N: usize>(&mut self)
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Ord,
+    {
+        let (base, len) = (Param::as_ptr(self.storage), self.len);
+        self.sort_via(|indices| {
+            // SAFE: The column is only borrowed while sorting the indices, before any
+            // element moves.
+            let column = Param::column(unsafe { Param::as_slices(base, len) });
+            indices.sort_by_key(|idx| &column[*idx]);
+        });
+    }
+
+    /// Sorts the slice by the values of the field at index `N`, but might not preserve the
+    /// order of equal elements.
+    ///
+    /// This only reads the sorted field while comparing elements.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
+    /// buffer.
+    pub fn sort_unstable_by_column<const N: usize>(&mut self)
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Ord,
+    {
+        let (base, len) = (Param::as_ptr(self.storage), self.len);
+        self.sort_via(|indices| {
+            // SAFE: The column is only borrowed while sorting the indices, before any
+            // element moves.
+            let column = Param::column(unsafe { Param::as_slices(base, len) });
+            indices.sort_unstable_by_key(|idx| &column[*idx]);
+        });
+    }
+
+    /// Reorders the slice with a comparator function such that the element at `index` is at
+    /// its final sorted position, every element before it compares less than or equal to it,
+    /// and every element after it compares greater than or equal to it.
+    ///
+    /// Returns the elements before `index`, the element at `index`, and the elements after
+    /// `index`. This does not preserve the order of equal elements.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate
+    /// buffer, and every element is moved at most once.
+    ///
+    /// This defers to the `core` implemenation of [`slice::select_nth_unstable_by`], so any
+    /// properties it has will also hold for this function.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// [`slice::select_nth_unstable_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.select_nth_unstable_by
+    #[allow(clippy::type_complexity)]
+    pub fn select_nth_unstable_by<F>(
+        &mut self,
+        index: usize,
+        f: F,
+    ) -> (
+        ParallelSliceMut<'_, Param>,
+        Param::RefMut<'_>,
+        ParallelSliceMut<'_, Param>,
+    )
+    where
+        F: Fn(Param::Ref<'_>, Param::Ref<'_>) -> Ordering,
+    {
+        assert_in_bounds(index, self.len);
+        let base = Param::as_ptr(self.storage);
+        self.sort_via(|indices| {
+            indices.select_nth_unstable_by(index, |a, b| unsafe {
+                f(
+                    Param::as_ref(Param::add(base, *a)),
+                    Param::as_ref(Param::add(base, *b)),
+                )
+            });
+        });
+        self.split_around(index)
+    }
+
+    /// Reorders the slice with a key extraction function such that the element at `index`
+    /// is at its final sorted position, every element before it has a key less than or equal
+    /// to its key, and every element after it has a key greater than or equal to its key.
+    ///
+    /// Returns the elements before `index`, the element at `index`, and the elements after
+    /// `index`. This does not preserve the order of equal elements.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate
+    /// buffer, and every element is moved at most once.
+    ///
+    /// This defers to the `core` implemenation of [`slice::select_nth_unstable_by_key`], so
+    /// any properties it has will also hold for this function.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// [`slice::select_nth_unstable_by_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.select_nth_unstable_by_key
+    #[allow(clippy::type_complexity)]
+    pub fn select_nth_unstable_by_key<K, F>(
+        &mut self,
+        index: usize,
+        f: F,
+    ) -> (
+        ParallelSliceMut<'_, Param>,
+        Param::RefMut<'_>,
+        ParallelSliceMut<'_, Param>,
+    )
+    where
+        F: Fn(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        assert_in_bounds(index, self.len);
+        let base = Param::as_ptr(self.storage);
+        self.sort_via(|indices| {
+            indices.select_nth_unstable_by_key(index, |idx| unsafe {
+                f(Param::as_ref(Param::add(base, *idx)))
+            });
+        });
+        self.split_around(index)
+    }
+
+    /// Splits the slice into the elements before `index`, the element at `index`, and the
+    /// elements after `index`.
+    ///
+    /// `index` must be in bounds.
+    #[allow(clippy::type_complexity)]
+    fn split_around(
+        &mut self,
+        index: usize,
+    ) -> (
+        ParallelSliceMut<'_, Param>,
+        Param::RefMut<'_>,
+        ParallelSliceMut<'_, Param>,
+    ) {
+        let base = Param::as_ptr(self.storage);
+        // SAFE: The three parts are disjoint and within the bounds of the slice.
+        unsafe {
+            (
+                ParallelSliceMut::from_raw_parts(self.storage, index),
+                Param::as_mut(Param::add(base, index)),
+                ParallelSliceMut::from_raw_parts(
+                    Param::as_storage(Param::add(base, index + 1)),
+                    self.len - index - 1,
+                ),
+            )
+        }
+    }
+
     #[inline(always)]
     fn sort_via<F>(&mut self, f: F)
     where
@@ -450,25 +1194,183 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
 
         let mut indices: Vec<usize> = (0..self.len).collect();
         f(&mut indices);
+        // SAFE: The indices were sorted from `0..self.len`, so they are a permutation.
+        unsafe { self.permute(&mut indices) };
+    }
 
-        // SAFE: All of the indices used here are valid.
-        unsafe {
-            for src in 0..self.len {
-                let dst = *indices.get_unchecked(src);
-                if src == dst {
-                    continue;
+    /// Reorders the elements of the slice such that the element at `indices[i]` is moved
+    /// to `i`, e.g. as returned by [`argsort_by`].
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate
+    /// buffer, and `self.len` bytes to validate `indices`. Every element is moved at
+    /// most once.
+    ///
+    /// # Panics
+    /// Panics if `indices` is not the same length as the slice, or if it is not a
+    /// permutation of `0..self.len()`.
+    ///
+    /// [`argsort_by`]: Self::argsort_by
+    pub fn apply_permutation(&mut self, indices: &[usize]) {
+        self.apply_permutation_as(indices);
+    }
+
+    /// Like [`apply_permutation`], but with the indices stored as `I`, which can be
+    /// narrower than `usize` to save memory, e.g. as returned by [`argsort_by_as`].
+    ///
+    /// # Panics
+    /// Panics if `indices` is not the same length as the slice, or if it is not a
+    /// permutation of `0..self.len()`.
+    ///
+    /// [`apply_permutation`]: Self::apply_permutation
+    /// [`argsort_by_as`]: Self::argsort_by_as
+    pub fn apply_permutation_as<I: ParallelIndex>(&mut self, indices: &[I]) {
+        assert_eq!(
+            indices.len(),
+            self.len,
+            "permutation length does not match the length of the slice"
+        );
+        let mut seen = alloc::vec![false; self.len];
+        for idx in indices {
+            let idx = idx.index();
+            assert!(
+                idx < self.len && !core::mem::replace(&mut seen[idx], true),
+                "indices are not a permutation"
+            );
+        }
+        // SAFE: `indices` was checked to be a permutation above.
+        unsafe { self.permute(&mut indices.to_vec()) };
+    }
+
+    /// Reorders the elements of the slice such that the element at `indices[i]` is moved
+    /// to `i`, without checking that `indices` is a permutation.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate
+    /// buffer. Every element is moved at most once.
+    ///
+    /// For a safe alternative see [`apply_permutation`].
+    ///
+    /// # Safety
+    /// `indices` must be the same length as the slice, and must be a permutation of
+    /// `0..self.len()`.
+    ///
+    /// [`apply_permutation`]: Self::apply_permutation
+    pub unsafe fn apply_permutation_unchecked(&mut self, indices: &[usize]) {
+        self.permute(&mut indices.to_vec());
+    }
+
+    /// Writes the values yielded by `values` to the elements at `indices`, in order, dropping
+    /// the elements that were there. This is the inverse of [`gather`].
+    ///
+    /// `values` may be any iterator of elements, including another [`ParallelVec`]. The
+    /// values are collected, and every index and the number of values are checked before
+    /// any value is written, so the slice is left unchanged if this panics.
+    ///
+    /// # Panics
+    /// Panics if any index is out of bounds, if any index appears more than once, or if
+    /// `values` does not yield exactly `indices.len()` values.
+    ///
+    /// [`gather`]: Self::gather
+    /// [`ParallelVec`]: crate::ParallelVec
+    pub fn scatter<I>(&mut self, indices: &[usize], values: I)
+    where
+        I: IntoIterator<Item = Param>,
+    {
+        let mut seen = alloc::vec![false; self.len];
+        for idx in indices {
+            assert_in_bounds(*idx, self.len);
+            assert!(
+                !core::mem::replace(&mut seen[*idx], true),
+                "duplicate index in scatter: {}",
+                idx
+            );
+        }
+        let values: Vec<Param> = values.into_iter().collect();
+        assert!(values.len() >= indices.len(), "too few values to scatter");
+        assert!(values.len() <= indices.len(), "too many values to scatter");
+        for (idx, value) in indices.iter().zip(values) {
+            // SAFE: Every index was checked to be in bounds above.
+            unsafe { self.set_unchecked(*idx, value) };
+        }
+    }
+
+    /// Writes the values yielded by `values` to the elements at `indices`, in order, dropping
+    /// the elements that were there, without checking the indices.
+    ///
+    /// If `values` yields fewer values than there are indices, only the first indices are
+    /// written. Extra values are not consumed. If an index appears more than once, the last
+    /// value written to it is kept.
+    ///
+    /// For a safe alternative see [`scatter`].
+    ///
+    /// # Safety
+    /// Every index must be in bounds.
+    ///
+    /// [`scatter`]: Self::scatter
+    pub unsafe fn scatter_unchecked<I>(&mut self, indices: &[usize], values: I)
+    where
+        I: IntoIterator<Item = Param>,
+    {
+        for (idx, value) in indices.iter().zip(values) {
+            self.set_unchecked(*idx, value);
+        }
+    }
+
+    /// Moves the element at `indices[i]` to `i` for every `i`, overwriting `indices`.
+    ///
+    /// # Safety
+    /// `indices` must be a permutation of `0..self.len`.
+    unsafe fn permute<I: ParallelIndex>(&mut self, indices: &mut [I]) {
+        // Apply the permutation one cycle at a time, marking every visited position by
+        // pointing it at itself. Rotating each cycle through a single temporary moves
+        // every element exactly once, instead of the three moves of every swap.
+        // SAFE: All of the indices used here are valid, and no user code runs while the
+        // temporary is held, so every element is always owned exactly once.
+        let base = Param::as_ptr(self.storage);
+        for start in 0..self.len {
+            if indices.get_unchecked(start).index() == start {
+                continue;
+            }
+            let temp = Param::read(Param::add(base, start));
+            let mut dst = start;
+            // Each position of the cycle is the index stored at the previous position,
+            // so it is marked with that index. The start is only marked once the cycle
+            // is closed.
+            let mut dst_index = *indices.get_unchecked(start);
+            loop {
+                let src_index = *indices.get_unchecked(dst);
+                *indices.get_unchecked_mut(dst) = dst_index;
+                let src = src_index.index();
+                if src == start {
+                    *indices.get_unchecked_mut(start) = src_index;
+                    Param::write(Param::add(base, dst), temp);
+                    break;
                 }
-                self.swap_unchecked(src, dst);
-                // TODO: Use swap_unchecked here when stablized.
-                indices.swap(src, dst);
+                Param::copy_to_nonoverlapping(Param::add(base, src), Param::add(base, dst), 1);
+                dst = src;
+                dst_index = src_index;
             }
         }
     }
 
-    /// Returns a raw pointer to the slice’s buffer.
+    /// Returns read-only raw pointers to the slice’s buffer, one per field.
+    ///
+    /// The caller must ensure that the slice outlives the pointers this function returns, or else they will end up
+    /// pointing to garbage. The memory the pointers point to must never be written to. Use [`as_mut_ptrs`] if
+    /// mutation is needed.
+    ///
+    /// Modifying the container referenced by this slice may cause its buffer to be reallocated, which would also make any
+    /// pointers to it invalid.
+    ///
+    /// [`as_mut_ptrs`]: Self::as_mut_ptrs
+    #[inline]
+    pub fn as_ptrs(&self) -> Param::ConstPtr {
+        Param::as_const_ptr(self.storage)
+    }
+
+    /// Returns [`NonNull`] pointers to the slice’s buffer, one per field.
     ///
-    /// The caller must ensure that the slice outlives the pointer this function returns, or else it will end up pointing
-    /// to garbage.
+    /// The caller must ensure that the slice outlives the pointers this function returns, or else they will end up
+    /// pointing to garbage.
     ///
     /// Modifying the container referenced by this slice may cause its buffer to be reallocated, which would also make any
     /// pointers to it invalid.
@@ -557,8 +1459,61 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         }
     }
 
+    /// Swaps the elements in `range` with those in `other`, a set of mutable slices
+    /// with one slice per field.
+    ///
+    /// This is the equivalent of calling [`slice::swap_with_slice`] on `range` of every
+    /// field, e.g. to exchange part of the slice with a back buffer.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `range` starts after it ends or ends after the end of
+    /// the slice, if the provided slices do not all have the same length, or if their
+    /// length differs from the length of `range`. Every length is checked before any
+    /// element is swapped.
+    ///
+    /// [`slice::swap_with_slice`]: https://doc.rust-lang.org/std/primitive.slice.html#method.swap_with_slice
+    pub fn swap_with_slices<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        mut other: Param::SlicesMut<'_>,
+    ) {
+        let range = slice_range(range, self.len);
+        match Param::get_slices_len(&other) {
+            Some(len) if len == range.len() => {}
+            Some(len) => panic!(
+                "Attempted to use swap_with_slices with slices of different lengths: {} vs {}",
+                range.len(),
+                len
+            ),
+            None => panic!("Attempted to use swap_with_slices with slices of uneven lengths"),
+        }
+        // SAFE: The range is in bounds, and `other` is a set of mutable borrows, so it
+        // cannot overlap with `self`.
+        unsafe {
+            let a = Param::ptr_at(self.storage, range.start);
+            let b = Param::get_slices_ptrs(&mut other);
+            Param::swap_nonoverlapping(a, b, range.len());
+        }
+    }
+
     /// Returns an iterator over the [`ParallelSliceMut`].
-    pub fn iter(&self) -> Iter<'a, Param> {
+    pub fn iter(&self) -> Iter<'_, Param> {
+        Iter {
+            ptr: Param::as_ptr(self.storage),
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the [`ParallelSliceMut`] with an unbounded lifetime.
+    ///
+    /// # Safety
+    /// Neither the iterator nor the references it yields may outlive the borrow of `self`.
+    pub(crate) unsafe fn iter_unbounded<'r>(&self) -> Iter<'r, Param>
+    where
+        Param: 'r,
+    {
         Iter {
             ptr: Param::as_ptr(self.storage),
             remaining: self.len,
@@ -567,7 +1522,7 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     }
 
     /// Returns an iterator that allows modifying each value.
-    pub fn iter_mut(&mut self) -> IterMut<'a, Param> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, Param> {
         IterMut {
             ptr: self.as_mut_ptrs(),
             remaining: self.len,
@@ -592,6 +1547,97 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
             Param::iters_mut(slices)
         }
     }
+
+    /// Same as [`ParallelSlice::argsort_by`].
+    pub fn argsort_by<F>(&self, f: F) -> Vec<usize>
+    where
+        F: Fn(Param::Ref<'_>, Param::Ref<'_>) -> Ordering,
+    {
+        self.as_parallel_slice().argsort_by(f)
+    }
+
+    /// Same as [`ParallelSlice::argsort_by_key`].
+    pub fn argsort_by_key<K, F>(&self, f: F) -> Vec<usize>
+    where
+        F: Fn(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        self.as_parallel_slice().argsort_by_key(f)
+    }
+
+    /// Same as [`ParallelSlice::argsort_by_as`].
+    pub fn argsort_by_as<I, F>(&self, f: F) -> Vec<I>
+    where
+        I: ParallelIndex,
+        F: Fn(Param::Ref<'_>, Param::Ref<'_>) -> Ordering,
+    {
+        self.as_parallel_slice().argsort_by_as(f)
+    }
+
+    /// Same as [`ParallelSlice::argsort_by_key_as`].
+    pub fn argsort_by_key_as<I, K, F>(&self, f: F) -> Vec<I>
+    where
+        I: ParallelIndex,
+        F: Fn(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        self.as_parallel_slice().argsort_by_key_as(f)
+    }
+
+    /// Same as [`ParallelSlice::binary_search_by`].
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> Ordering,
+    {
+        self.as_parallel_slice().binary_search_by(f)
+    }
+
+    /// Same as [`ParallelSlice::binary_search_by_key`].
+    pub fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> B,
+        B: Ord,
+    {
+        self.as_parallel_slice().binary_search_by_key(b, f)
+    }
+
+    /// Same as [`ParallelSlice::binary_search_column`].
+    pub fn binary_search_column<const N: usize>(
+        &self,
+        value: &<Param as ParallelColumn<N>>::Column,
+    ) -> Result<usize, usize>
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: Ord,
+    {
+        self.as_parallel_slice().binary_search_column::<N>(value)
+    }
+
+    /// Same as [`ParallelSlice::is_sorted_by`].
+    pub fn is_sorted_by<F>(&self, f: F) -> bool
+    where
+        F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+    {
+        self.as_parallel_slice().is_sorted_by(f)
+    }
+
+    /// Same as [`ParallelSlice::is_sorted_by_key`].
+    pub fn is_sorted_by_key<K, F>(&self, f: F) -> bool
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: PartialOrd,
+    {
+        self.as_parallel_slice().is_sorted_by_key(f)
+    }
+
+    /// Same as [`ParallelSlice::is_sorted_column`].
+    pub fn is_sorted_column<const N: usize>(&self) -> bool
+    where
+        Param: ParallelColumn<N>,
+        <Param as ParallelColumn<N>>::Column: PartialOrd,
+    {
+        self.as_parallel_slice().is_sorted_column::<N>()
+    }
 }
 
 impl<'a, Param: ParallelParam + Clone> ParallelSliceMut<'a, Param> {
@@ -602,6 +1648,20 @@ impl<'a, Param: ParallelParam + Clone> ParallelSliceMut<'a, Param> {
     }
 }
 
+impl<'a, Param: ParallelParam + Default> ParallelSliceMut<'a, Param> {
+    /// Moves the value at an valid index out of the slice, leaving
+    /// `Param::default()` in its place.
+    ///
+    /// This is the equivalent of [`core::mem::take`] for a single element.
+    ///
+    /// # Panics
+    /// This function will panic if `index >= self.len`.
+    #[inline]
+    pub fn take(&mut self, index: usize) -> Param {
+        self.replace(index, Param::default())
+    }
+}
+
 impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// Fills self with elements returned by calling a closure repeatedly.
     ///
@@ -609,47 +1669,52 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// a given value, use fill. If you want to use the [`Default`] trait to generate
     /// values, you can pass `Default::default` as the argument.
     pub fn fill_with<F: FnMut() -> Param>(&mut self, mut f: F) {
-        unsafe {
-            let base = self.as_mut_ptrs();
-            for idx in 0..self.len {
-                Param::write(Param::add(base, idx), f());
-            }
+        for idx in 0..self.len {
+            // SAFE: `idx` is always in bounds.
+            unsafe { self.set_unchecked(idx, f()) };
         }
     }
 }
 
-impl<'s, 'r, Param> Hash for ParallelSliceMut<'s, Param>
+impl<'s, Param> Hash for ParallelSliceMut<'s, Param>
 where
     Param: ParallelParam + 's,
     Param::Ref<'s>: Hash,
-    'r: 's,
 {
     fn hash<H>(&self, hasher: &mut H)
     where
         H: Hasher,
     {
         self.len.hash(hasher);
-        for item in self.iter() {
+        // SAFE: The items are only hashed, and do not escape this function.
+        for item in unsafe { self.iter_unbounded() } {
             item.hash(hasher);
         }
     }
 }
 
 pub trait ParallelSliceIndex<T> {
-    type Output;
-    fn get(self, slice: &T) -> Option<Self::Output>;
-    fn index(self, slice: &T) -> Self::Output;
+    type Output<'r>
+    where
+        T: 'r;
+    fn get(self, slice: &T) -> Option<Self::Output<'_>>;
+    fn index(self, slice: &T) -> Self::Output<'_>;
 }
 
 pub trait ParallelSliceIndexMut<T> {
-    type Output;
-    fn get_mut(self, slice: &mut T) -> Option<Self::Output>;
-    fn index_mut(self, slice: &mut T) -> Self::Output;
+    type Output<'r>
+    where
+        T: 'r;
+    fn get_mut(self, slice: &mut T) -> Option<Self::Output<'_>>;
+    fn index_mut(self, slice: &mut T) -> Self::Output<'_>;
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for usize {
-    type Output = Param::Ref<'s>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = Param::Ref<'s>
+    where
+        ParallelSlice<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Option<Self::Output<'r>> {
         if self >= slice.len {
             return None;
         }
@@ -657,15 +1722,18 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
         unsafe { Some(Param::as_ref(Param::ptr_at(slice.storage, self))) }
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
+    fn index<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Self::Output<'r> {
         assert_in_bounds(self, slice.len);
         unsafe { Param::as_ref(Param::ptr_at(slice.storage, self)) }
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> for usize {
-    type Output = Param::Ref<'s>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = Param::Ref<'r>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
         if self >= slice.len {
             return None;
         }
@@ -673,15 +1741,18 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> f
         unsafe { Some(Param::as_ref(Param::ptr_at(slice.storage, self))) }
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
         assert_in_bounds(self, slice.len);
         unsafe { Param::as_ref(Param::ptr_at(slice.storage, self)) }
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>> for usize {
-    type Output = Param::RefMut<'s>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = Param::RefMut<'r>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
         if self >= slice.len {
             return None;
         }
@@ -689,86 +1760,83 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
         unsafe { Some(Param::as_mut(Param::ptr_at(slice.storage, self))) }
     }
 
-    fn index_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
         assert_in_bounds(self, slice.len);
         unsafe { Param::as_mut(Param::ptr_at(slice.storage, self)) }
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for Range<usize> {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
-        if self.start >= slice.len || self.end > slice.len {
-            return None;
-        }
-
+    type Output<'r>
+        = ParallelSlice<'s, Param>
+    where
+        ParallelSlice<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Option<Self::Output<'r>> {
+        let range = try_slice_range(self, slice.len)?;
         unsafe {
-            let ptr = Param::ptr_at(slice.storage, self.start);
+            let ptr = Param::ptr_at(slice.storage, range.start);
             Some(ParallelSlice::from_raw_parts(
                 Param::as_storage(ptr),
-                self.end - self.start,
+                range.len(),
             ))
         }
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
-        assert_in_bounds(self.start, slice.len);
-        assert_in_bounds_inclusive(self.end, slice.len);
+    fn index<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Self::Output<'r> {
+        let range = slice_range(self, slice.len);
         unsafe {
-            let ptr = Param::ptr_at(slice.storage, self.start);
-            ParallelSlice::from_raw_parts(Param::as_storage(ptr), self.end - self.start)
+            let ptr = Param::ptr_at(slice.storage, range.start);
+            ParallelSlice::from_raw_parts(Param::as_storage(ptr), range.len())
         }
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> for Range<usize> {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
-        if self.start >= slice.len || self.end > slice.len {
-            return None;
-        }
-
+    type Output<'r>
+        = ParallelSlice<'r, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
+        let range = try_slice_range(self, slice.len)?;
         unsafe {
-            let ptr = Param::ptr_at(slice.storage, self.start);
+            let ptr = Param::ptr_at(slice.storage, range.start);
             Some(ParallelSlice::from_raw_parts(
                 Param::as_storage(ptr),
-                self.end - self.start,
+                range.len(),
             ))
         }
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
-        assert_in_bounds(self.start, slice.len);
-        assert_in_bounds_inclusive(self.end, slice.len);
+    fn index<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
+        let range = slice_range(self, slice.len);
         unsafe {
-            let ptr = Param::ptr_at(slice.storage, self.start);
-            ParallelSlice::from_raw_parts(Param::as_storage(ptr), self.end - self.start)
+            let ptr = Param::ptr_at(slice.storage, range.start);
+            ParallelSlice::from_raw_parts(Param::as_storage(ptr), range.len())
         }
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>> for Range<usize> {
-    type Output = ParallelSliceMut<'s, Param>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
-        if self.start >= slice.len || self.end > slice.len {
-            return None;
-        }
-
+    type Output<'r>
+        = ParallelSliceMut<'r, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
+        let range = try_slice_range(self, slice.len)?;
         unsafe {
-            let ptr = Param::ptr_at(slice.storage, self.start);
+            let ptr = Param::ptr_at(slice.storage, range.start);
             Some(ParallelSliceMut::from_raw_parts(
                 Param::as_storage(ptr),
-                self.end - self.start,
+                range.len(),
             ))
         }
     }
 
-    fn index_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
-        assert_in_bounds(self.start, slice.len);
-        assert_in_bounds_inclusive(self.end, slice.len);
+    fn index_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
+        let range = slice_range(self, slice.len);
         unsafe {
-            let ptr = Param::ptr_at(slice.storage, self.start);
-            ParallelSliceMut::from_raw_parts(Param::as_storage(ptr), self.end - self.start)
+            let ptr = Param::ptr_at(slice.storage, range.start);
+            ParallelSliceMut::from_raw_parts(Param::as_storage(ptr), range.len())
         }
     }
 }
@@ -776,70 +1844,57 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>>
     for RangeInclusive<usize>
 {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
-        let range = Range {
-            start: *self.start(),
-            end: *self.end() + 1,
-        };
-        range.get(slice)
+    type Output<'r>
+        = ParallelSlice<'s, Param>
+    where
+        ParallelSlice<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Option<Self::Output<'r>> {
+        try_slice_range(self, slice.len)?.get(slice)
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
-        let range = Range {
-            start: *self.start(),
-            end: *self.end() + 1,
-        };
-        range.index(slice)
+    fn index<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Self::Output<'r> {
+        slice_range(self, slice.len).index(slice)
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>>
     for RangeInclusive<usize>
 {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
-        let range = Range {
-            start: *self.start(),
-            end: *self.end() + 1,
-        };
-        range.get(slice)
+    type Output<'r>
+        = ParallelSlice<'r, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
+        try_slice_range(self, slice.len)?.get(slice)
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
-        let range = Range {
-            start: *self.start(),
-            end: *self.end() + 1,
-        };
-        range.index(slice)
+    fn index<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
+        slice_range(self, slice.len).index(slice)
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>>
     for RangeInclusive<usize>
 {
-    type Output = ParallelSliceMut<'s, Param>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
-        let range = Range {
-            start: *self.start(),
-            end: *self.end() + 1,
-        };
-
-        range.get_mut(slice)
+    type Output<'r>
+        = ParallelSliceMut<'r, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
+        try_slice_range(self, slice.len)?.get_mut(slice)
     }
 
-    fn index_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
-        let range = Range {
-            start: *self.start(),
-            end: *self.end() + 1,
-        };
-        range.index_mut(slice)
+    fn index_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
+        slice_range(self, slice.len).index_mut(slice)
     }
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for RangeTo<usize> {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = ParallelSlice<'s, Param>
+    where
+        ParallelSlice<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Option<Self::Output<'r>> {
         Range {
             start: 0,
             end: self.end,
@@ -847,7 +1902,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
+    fn index<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Self::Output<'r> {
         Range {
             start: 0,
             end: self.end,
@@ -857,8 +1912,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> for RangeTo<usize> {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = ParallelSlice<'r, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
         Range {
             start: 0,
             end: self.end,
@@ -866,7 +1924,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> f
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
         Range {
             start: 0,
             end: self.end,
@@ -878,8 +1936,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> f
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>>
     for RangeTo<usize>
 {
-    type Output = ParallelSliceMut<'s, Param>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = ParallelSliceMut<'r, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
         Range {
             start: 0,
             end: self.end,
@@ -887,7 +1948,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
         .get_mut(slice)
     }
 
-    fn index_mut<'a>(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
         Range {
             start: 0,
             end: self.end,
@@ -897,8 +1958,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for RangeFrom<usize> {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = ParallelSlice<'s, Param>
+    where
+        ParallelSlice<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Option<Self::Output<'r>> {
         Range {
             start: self.start,
             end: slice.len,
@@ -906,7 +1970,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
+    fn index<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Self::Output<'r> {
         Range {
             start: self.start,
             end: slice.len,
@@ -918,8 +1982,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>>
     for RangeFrom<usize>
 {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = ParallelSlice<'r, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
         Range {
             start: self.start,
             end: slice.len,
@@ -927,7 +1994,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>>
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
         Range {
             start: self.start,
             end: slice.len,
@@ -939,8 +2006,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>>
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>>
     for RangeFrom<usize>
 {
-    type Output = ParallelSliceMut<'s, Param>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = ParallelSliceMut<'r, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
         Range {
             start: self.start,
             end: slice.len,
@@ -948,7 +2018,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
         .get_mut(slice)
     }
 
-    fn index_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
         Range {
             start: self.start,
             end: slice.len,
@@ -958,8 +2028,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for RangeFull {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = ParallelSlice<'s, Param>
+    where
+        ParallelSlice<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Option<Self::Output<'r>> {
         Range {
             start: 0,
             end: slice.len,
@@ -967,7 +2040,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
+    fn index<'r>(self, slice: &'r ParallelSlice<'s, Param>) -> Self::Output<'r> {
         Range {
             start: 0,
             end: slice.len,
@@ -977,8 +2050,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> for RangeFull {
-    type Output = ParallelSlice<'s, Param>;
-    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = ParallelSlice<'r, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
         Range {
             start: 0,
             end: slice.len,
@@ -986,7 +2062,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> f
         .get(slice)
     }
 
-    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index<'r>(self, slice: &'r ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
         Range {
             start: 0,
             end: slice.len,
@@ -996,8 +2072,11 @@ impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>> f
 }
 
 impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>> for RangeFull {
-    type Output = ParallelSliceMut<'s, Param>;
-    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+    type Output<'r>
+        = ParallelSliceMut<'r, Param>
+    where
+        ParallelSliceMut<'s, Param>: 'r;
+    fn get_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Option<Self::Output<'r>> {
         Range {
             start: 0,
             end: slice.len,
@@ -1005,7 +2084,7 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
         .get_mut(slice)
     }
 
-    fn index_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
+    fn index_mut<'r>(self, slice: &'r mut ParallelSliceMut<'s, Param>) -> Self::Output<'r> {
         Range {
             start: 0,
             end: slice.len,
@@ -0,0 +1,291 @@
+use crate::{
+    iter::{Iter, IterMut},
+    ParallelParam, ParallelSlice, ParallelVec,
+};
+use alloc::vec::Vec;
+
+/// A key to a value of a [`ParallelSlotMap`].
+///
+/// Once the value of a key is removed, the key is invalidated even if its slot is reused
+/// by a later insertion. A key is only reused after its slot has been reused `2^32`
+/// times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParallelKey {
+    slot: u32,
+    generation: u32,
+}
+
+#[derive(Debug, Clone)]
+struct Slot {
+    /// Incremented every time the value in the slot is removed.
+    generation: u32,
+    /// The index of the value in the dense storage, if the slot is occupied.
+    index: Option<usize>,
+}
+
+/// A container that hands out stable [`ParallelKey`]s for its values, while storing
+/// them densely in a [`ParallelVec`].
+///
+/// Inserting and removing values are `O(1)`. Removing a value moves the last value into
+/// its place, so the values are not kept in insertion order, but the keys of every other
+/// value remain valid. As the values are stored contiguously, iterating over the fields
+/// is as fast as with a [`ParallelVec`].
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::ParallelSlotMap;
+///
+/// let mut particles = ParallelSlotMap::new();
+/// let a = particles.insert((1.0f32, 10u32));
+/// let b = particles.insert((2.0, 20));
+/// assert_eq!(particles.remove(a), Some((1.0, 10)));
+/// assert_eq!(particles.get(a), None);
+/// assert_eq!(particles.get(b), Some((&2.0, &20)));
+/// assert_eq!(particles.as_slices().0, &[2.0]);
+/// ```
+pub struct ParallelSlotMap<Param: ParallelParam> {
+    values: ParallelVec<Param>,
+    /// The key of each value in the dense storage.
+    keys: Vec<ParallelKey>,
+    slots: Vec<Slot>,
+    /// The indices of the vacant slots.
+    free: Vec<u32>,
+}
+
+impl<Param: ParallelParam> ParallelSlotMap<Param> {
+    /// Constructs a new, empty `ParallelSlotMap`.
+    ///
+    /// The map will not allocate until values are inserted into it.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Constructs a new, empty `ParallelSlotMap` with the capacity for `capacity`
+    /// values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: ParallelVec::with_capacity(capacity),
+            keys: Vec::with_capacity(capacity),
+            slots: Vec::with_capacity(capacity),
+            free: Vec::new(),
+        }
+    }
+
+    /// Returns the number of values in the map.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the map contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the number of values the map can hold without reallocating its values.
+    pub fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more values.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+        self.keys.reserve(additional);
+    }
+
+    /// Inserts a value into the map, returning its key.
+    ///
+    /// # Panics
+    /// Panics if the map already holds `u32::MAX` slots.
+    pub fn insert(&mut self, value: Param) -> ParallelKey {
+        let index = self.values.len();
+        let slot = match self.free.last() {
+            Some(slot) => *slot,
+            None => u32::try_from(self.slots.len())
+                .ok()
+                .filter(|slot| *slot != u32::MAX)
+                .expect("ParallelSlotMap has too many slots"),
+        };
+        // The value is pushed before the slot is published, so a panic while growing the
+        // values leaves the map unchanged.
+        self.values.push(value);
+        let generation = if self.free.pop().is_some() {
+            let entry = &mut self.slots[slot as usize];
+            entry.index = Some(index);
+            entry.generation
+        } else {
+            self.slots.push(Slot {
+                generation: 0,
+                index: Some(index),
+            });
+            0
+        };
+        let key = ParallelKey { slot, generation };
+        self.keys.push(key);
+        key
+    }
+
+    /// Removes the value of `key` from the map, returning it, or `None` if the key is
+    /// not in the map.
+    ///
+    /// The last value of the map is moved into the place of the removed value.
+    pub fn remove(&mut self, key: ParallelKey) -> Option<Param> {
+        let index = self.index_of(key)?;
+        let slot = &mut self.slots[key.slot as usize];
+        slot.index = None;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(key.slot);
+
+        self.keys.swap_remove(index);
+        if let Some(moved) = self.keys.get(index) {
+            self.slots[moved.slot as usize].index = Some(index);
+        }
+        Some(self.values.swap_remove(index))
+    }
+
+    /// Returns `true` if the map contains a value for `key`.
+    pub fn contains_key(&self, key: ParallelKey) -> bool {
+        self.index_of(key).is_some()
+    }
+
+    /// Returns a reference to the value of `key`, or `None` if the key is not in the map.
+    pub fn get(&self, key: ParallelKey) -> Option<Param::Ref<'_>> {
+        let index = self.index_of(key)?;
+        self.values.get(index)
+    }
+
+    /// Returns a mutable reference to the value of `key`, or `None` if the key is not in
+    /// the map.
+    pub fn get_mut(&mut self, key: ParallelKey) -> Option<Param::RefMut<'_>> {
+        let index = self.index_of(key)?;
+        self.values.get_mut(index)
+    }
+
+    /// Gets the index of the value of `key` in the dense storage of the map, or `None`
+    /// if the key is not in the map.
+    ///
+    /// The index is only valid until the next value is removed from the map.
+    pub fn index_of(&self, key: ParallelKey) -> Option<usize> {
+        let slot = self.slots.get(key.slot as usize)?;
+        if slot.generation != key.generation {
+            return None;
+        }
+        slot.index
+    }
+
+    /// Removes every value from the map, invalidating all of their keys.
+    pub fn clear(&mut self) {
+        for key in self.keys.drain(..) {
+            let slot = &mut self.slots[key.slot as usize];
+            slot.index = None;
+            slot.generation = slot.generation.wrapping_add(1);
+            self.free.push(key.slot);
+        }
+        self.values.clear();
+    }
+
+    /// The keys of the values of the map, in the order of the dense storage.
+    pub fn keys(&self) -> &[ParallelKey] {
+        &self.keys
+    }
+
+    /// Gets the values of the map as a [`ParallelSlice`], in the order of the dense
+    /// storage.
+    pub fn values(&self) -> ParallelSlice<'_, Param> {
+        self.values.as_parallel_slice()
+    }
+
+    /// Gets the fields of every value of the map as slices, in the order of the dense
+    /// storage.
+    pub fn as_slices(&self) -> Param::Slices<'_> {
+        self.values.as_slices()
+    }
+
+    /// Gets the fields of every value of the map as mutable slices, in the order of the
+    /// dense storage.
+    pub fn as_slices_mut(&mut self) -> Param::SlicesMut<'_> {
+        self.values.as_slices_mut()
+    }
+
+    /// Returns an iterator over the keys and values of the map.
+    pub fn iter(
+        &self,
+    ) -> core::iter::Zip<core::iter::Copied<core::slice::Iter<'_, ParallelKey>>, Iter<'_, Param>>
+    {
+        self.keys.iter().copied().zip(self.values.iter())
+    }
+
+    /// Returns an iterator over the keys and mutable references to the values of the map.
+    pub fn iter_mut(
+        &mut self,
+    ) -> core::iter::Zip<core::iter::Copied<core::slice::Iter<'_, ParallelKey>>, IterMut<'_, Param>>
+    {
+        self.keys.iter().copied().zip(self.values.iter_mut())
+    }
+}
+
+impl<Param: ParallelParam> Default for ParallelSlotMap<Param> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ParallelSlotMap;
+    use alloc::{string::String, vec::Vec};
+
+    #[test]
+    fn test_insert_remove() {
+        let mut map = ParallelSlotMap::new();
+        let keys: Vec<_> = (0..5u32).map(|idx| map.insert((idx, idx as f32))).collect();
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.remove(keys[1]), Some((1, 1.0)));
+        assert_eq!(map.remove(keys[1]), None);
+        assert!(!map.contains_key(keys[1]));
+        assert_eq!(map.as_slices().0, &[0, 4, 2, 3]);
+        assert_eq!(map.keys(), &[keys[0], keys[4], keys[2], keys[3]]);
+        for (idx, key) in keys.iter().enumerate() {
+            if idx != 1 {
+                assert_eq!(map.get(*key), Some((&(idx as u32), &(idx as f32))));
+            }
+        }
+        assert_eq!(map.index_of(keys[4]), Some(1));
+    }
+
+    #[test]
+    fn test_stale_keys() {
+        let mut map = ParallelSlotMap::new();
+        let a = map.insert((String::from("a"),));
+        map.remove(a);
+        let b = map.insert((String::from("b"),));
+        assert_ne!(a, b);
+        assert_eq!(map.get(a), None);
+        assert_eq!(map.get(b), Some((&String::from("b"),)));
+
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.get(b), None);
+        let c = map.insert((String::from("c"),));
+        assert_eq!(map.get(c).unwrap().0, "c");
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut map = ParallelSlotMap::new();
+        for idx in 0..4u32 {
+            map.insert((idx, 0u32));
+        }
+        for (_, (id, count)) in map.iter_mut() {
+            *count = *id * 2;
+        }
+        let key = map.keys()[2];
+        map.remove(map.keys()[0]);
+        *map.get_mut(key).unwrap().1 += 1;
+        let values: Vec<(u32, u32)> = map.iter().map(|(_, (a, b))| (*a, *b)).collect();
+        assert_eq!(values, [(3, 6), (1, 2), (2, 5)]);
+        assert_eq!(map.values().len(), 3);
+    }
+}
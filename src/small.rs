@@ -0,0 +1,384 @@
+use crate::{
+    iter::{Iter, IterMut},
+    ArrayParallelVec, CapacityError, ParallelParam, ParallelSlice, ParallelSliceMut, ParallelVec,
+};
+
+enum Data<Param: ParallelParam, const N: usize> {
+    Inline(ArrayParallelVec<Param, N>),
+    Heap(ParallelVec<Param>),
+}
+
+/// A [`ParallelVec`] that stores up to `N` elements inline, like an
+/// [`ArrayParallelVec`], and only moves them to the heap once it grows beyond that.
+///
+/// This avoids allocating for the common case of a handful of elements, at the cost of
+/// the size of the arrays, which is always part of the size of the container.
+///
+/// As the inline elements move with the container, it does not dereference to a
+/// [`ParallelSliceMut`] like [`ParallelVec`]. Use [`as_parallel_slice`] and
+/// [`as_parallel_slice_mut`] instead.
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::SmallParallelVec;
+///
+/// let mut contacts = SmallParallelVec::<(u32, f32), 2>::new();
+/// contacts.push((1, 0.5));
+/// contacts.push((2, 0.25));
+/// assert!(!contacts.spilled());
+/// contacts.push((3, 0.125));
+/// assert!(contacts.spilled());
+/// assert_eq!(contacts.as_slices().0, &[1, 2, 3]);
+/// ```
+///
+/// [`as_parallel_slice`]: Self::as_parallel_slice
+/// [`as_parallel_slice_mut`]: Self::as_parallel_slice_mut
+pub struct SmallParallelVec<Param: ParallelParam, const N: usize> {
+    data: Data<Param, N>,
+}
+
+impl<Param: ParallelParam, const N: usize> SmallParallelVec<Param, N> {
+    /// Constructs a new, empty `SmallParallelVec`.
+    ///
+    /// The container will not allocate until more than `N` elements are pushed onto it.
+    pub fn new() -> Self {
+        Self {
+            data: Data::Inline(ArrayParallelVec::new()),
+        }
+    }
+
+    /// Constructs a new, empty `SmallParallelVec` with the capacity for at least
+    /// `capacity` elements.
+    ///
+    /// The container only allocates if `capacity` is greater than `N`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        if capacity <= N {
+            Self::new()
+        } else {
+            Self {
+                data: Data::Heap(ParallelVec::with_capacity(capacity)),
+            }
+        }
+    }
+
+    /// Returns the number of elements in the container.
+    pub fn len(&self) -> usize {
+        match &self.data {
+            Data::Inline(array) => array.len(),
+            Data::Heap(vec) => vec.len(),
+        }
+    }
+
+    /// Returns `true` if the container contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements the container can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        match &self.data {
+            Data::Inline(_) => N,
+            Data::Heap(vec) => vec.capacity(),
+        }
+    }
+
+    /// Returns `true` if the elements have been moved to the heap.
+    pub fn spilled(&self) -> bool {
+        matches!(self.data, Data::Heap(_))
+    }
+
+    /// Reserves capacity for at least `additional` more elements, moving the elements
+    /// to the heap if they no longer fit inline.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn reserve(&mut self, additional: usize) {
+        match &mut self.data {
+            Data::Inline(array) => {
+                let required = array
+                    .len()
+                    .checked_add(additional)
+                    .expect("SmallParallelVec capacity overflow");
+                if required > N {
+                    self.spill(required);
+                }
+            }
+            Data::Heap(vec) => vec.reserve(additional),
+        }
+    }
+
+    /// Appends an element to the back of the container, moving the elements to the heap
+    /// if they no longer fit inline.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn push(&mut self, value: Param) {
+        match &mut self.data {
+            Data::Inline(array) => {
+                if let Err(CapacityError(value)) = array.push(value) {
+                    let required = (N + 1)
+                        .checked_next_power_of_two()
+                        .expect("SmallParallelVec capacity overflow");
+                    self.spill(required);
+                    self.push(value);
+                }
+            }
+            Data::Heap(vec) => vec.push(value),
+        }
+    }
+
+    /// Removes the last element from the container and returns it, or `None` if it is
+    /// empty.
+    pub fn pop(&mut self) -> Option<Param> {
+        match &mut self.data {
+            Data::Inline(array) => array.pop(),
+            Data::Heap(vec) => vec.pop(),
+        }
+    }
+
+    /// Removes the element at `index` and returns it, moving the last element into its
+    /// place.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> Param {
+        match &mut self.data {
+            Data::Inline(array) => array.swap_remove(index),
+            Data::Heap(vec) => vec.swap_remove(index),
+        }
+    }
+
+    /// Shortens the container to `len` elements, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        match &mut self.data {
+            Data::Inline(array) => array.truncate(len),
+            Data::Heap(vec) => vec.truncate(len),
+        }
+    }
+
+    /// Removes every element from the container. Keeps any heap allocation.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Param::Ref<'_>> {
+        let (storage, len) = self.raw_parts();
+        if index >= len {
+            return None;
+        }
+        // SAFE: The element is in bounds, and borrowed from `self`.
+        Some(unsafe { Param::as_ref(Param::ptr_at(storage, index)) })
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of
+    /// bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<Param::RefMut<'_>> {
+        let (storage, len) = self.raw_parts_mut();
+        if index >= len {
+            return None;
+        }
+        // SAFE: The element is in bounds, and borrowed mutably from `self`.
+        Some(unsafe { Param::as_mut(Param::ptr_at(storage, index)) })
+    }
+
+    /// Gets the elements of the container as a [`ParallelSlice`].
+    pub fn as_parallel_slice(&self) -> ParallelSlice<'_, Param> {
+        let (storage, len) = self.raw_parts();
+        // SAFE: The elements are initialized, and borrowed from `self`.
+        unsafe { ParallelSlice::from_raw_parts(storage, len) }
+    }
+
+    /// Gets the elements of the container as a [`ParallelSliceMut`].
+    pub fn as_parallel_slice_mut(&mut self) -> ParallelSliceMut<'_, Param> {
+        let (storage, len) = self.raw_parts_mut();
+        // SAFE: The elements are initialized, and borrowed mutably from `self`.
+        unsafe { ParallelSliceMut::from_raw_parts(storage, len) }
+    }
+
+    /// Gets the fields of every element as slices.
+    pub fn as_slices(&self) -> Param::Slices<'_> {
+        let (storage, len) = self.raw_parts();
+        // SAFE: The elements are initialized, and borrowed from `self`.
+        unsafe { Param::as_slices(Param::as_ptr(storage), len) }
+    }
+
+    /// Gets the fields of every element as mutable slices.
+    pub fn as_slices_mut(&mut self) -> Param::SlicesMut<'_> {
+        let (storage, len) = self.raw_parts_mut();
+        // SAFE: The elements are initialized, and borrowed mutably from `self`.
+        unsafe { Param::as_slices_mut(Param::as_ptr(storage), len) }
+    }
+
+    /// Returns an iterator over references to the elements.
+    pub fn iter(&self) -> Iter<'_, Param> {
+        let (storage, len) = self.raw_parts();
+        Iter {
+            ptr: Param::as_ptr(storage),
+            remaining: len,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> IterMut<'_, Param> {
+        let (storage, len) = self.raw_parts_mut();
+        IterMut {
+            ptr: Param::as_ptr(storage),
+            remaining: len,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Converts the container into a [`ParallelVec`], allocating if the elements are
+    /// still stored inline.
+    pub fn into_parallel_vec(mut self) -> ParallelVec<Param> {
+        if !self.spilled() {
+            let len = self.len();
+            self.spill(len);
+        }
+        match core::mem::replace(&mut self.data, Data::Inline(ArrayParallelVec::new())) {
+            Data::Heap(vec) => vec,
+            Data::Inline(_) => unreachable!(),
+        }
+    }
+
+    fn raw_parts(&self) -> (Param::Storage, usize) {
+        match &self.data {
+            Data::Inline(array) => (Param::arrays_storage(&array.arrays), array.len),
+            Data::Heap(vec) => (vec.storage, vec.len),
+        }
+    }
+
+    fn raw_parts_mut(&mut self) -> (Param::Storage, usize) {
+        match &mut self.data {
+            Data::Inline(array) => (Param::arrays_storage_mut(&mut array.arrays), array.len),
+            Data::Heap(vec) => (vec.storage, vec.len),
+        }
+    }
+
+    /// Moves the inline elements into a heap allocation with the capacity for at least
+    /// `capacity` elements.
+    fn spill(&mut self, capacity: usize) {
+        if let Data::Inline(array) = &mut self.data {
+            let mut vec = ParallelVec::with_capacity(capacity);
+            // SAFE: The inline elements are initialized, and are moved into the new
+            // allocation, which has room for all of them. They are forgotten by setting
+            // the length of the inline storage to zero.
+            unsafe {
+                let src = Param::as_ptr(Param::arrays_storage_mut(&mut array.arrays));
+                Param::copy_to_nonoverlapping(src, Param::as_ptr(vec.storage), array.len);
+                vec.len = array.len;
+            }
+            array.len = 0;
+            self.data = Data::Heap(vec);
+        }
+    }
+}
+
+impl<Param: ParallelParam, const N: usize> Default for SmallParallelVec<Param, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param: ParallelParam, const N: usize> Extend<Param> for SmallParallelVec<Param, N> {
+    fn extend<I: IntoIterator<Item = Param>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<Param: ParallelParam, const N: usize> FromIterator<Param> for SmallParallelVec<Param, N> {
+    fn from_iter<I: IntoIterator<Item = Param>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<Param: ParallelParam, const N: usize> From<ParallelVec<Param>> for SmallParallelVec<Param, N> {
+    /// Wraps the elements of `vec` without moving them back inline.
+    fn from(vec: ParallelVec<Param>) -> Self {
+        Self {
+            data: Data::Heap(vec),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SmallParallelVec;
+    use crate::ParallelVec;
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+    use std::rc::Rc;
+
+    #[test]
+    fn test_inline() {
+        let mut vec = SmallParallelVec::<(u32, String), 4>::new();
+        assert_eq!(vec.capacity(), 4);
+        for idx in 0..4 {
+            vec.push((idx, idx.to_string()));
+        }
+        assert!(!vec.spilled());
+        assert_eq!(vec.get(1), Some((&1, &String::from("1"))));
+        *vec.get_mut(2).unwrap().0 = 20;
+        assert_eq!(vec.swap_remove(0), (0, String::from("0")));
+        assert_eq!(vec.as_slices().0, &[3, 1, 20]);
+        assert_eq!(vec.pop(), Some((20, String::from("2"))));
+        vec.truncate(1);
+        assert_eq!(vec.len(), 1);
+        assert_eq!(vec.as_parallel_slice().len(), 1);
+    }
+
+    #[test]
+    fn test_spill() {
+        let mut vec: SmallParallelVec<(u32, String), 2> =
+            (0..2).map(|idx| (idx, idx.to_string())).collect();
+        assert!(!vec.spilled());
+        vec.push((2, String::from("2")));
+        assert!(vec.spilled());
+        assert!(vec.capacity() >= 3);
+        for (idx, name) in vec.iter_mut() {
+            name.push_str(&idx.to_string());
+        }
+        let names: Vec<&str> = vec.iter().map(|(_, name)| name.as_str()).collect();
+        assert_eq!(names, ["00", "11", "22"]);
+
+        let vec: ParallelVec<_> = vec.into_parallel_vec();
+        assert_eq!(vec.len(), 3);
+        assert!(SmallParallelVec::<(u8,), 2>::with_capacity(3).spilled());
+    }
+
+    #[test]
+    fn test_drop() {
+        let rc = Rc::new(());
+        let mut inline = SmallParallelVec::<(u8, Rc<()>), 4>::new();
+        inline.extend((0..3).map(|idx| (idx, rc.clone())));
+        let mut spilled = SmallParallelVec::<(Rc<()>,), 1>::new();
+        spilled.extend((0..3).map(|_| (rc.clone(),)));
+        assert_eq!(Rc::strong_count(&rc), 7);
+        inline.truncate(1);
+        assert_eq!(Rc::strong_count(&rc), 5);
+        core::mem::drop(inline);
+        core::mem::drop(spilled);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_zero_inline() {
+        let mut vec = SmallParallelVec::<(u32,), 0>::new();
+        assert_eq!(vec.capacity(), 0);
+        vec.push((1,));
+        assert!(vec.spilled());
+        assert_eq!(vec.as_slices_mut().0, &mut [1]);
+    }
+}
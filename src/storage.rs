@@ -0,0 +1,295 @@
+use alloc::alloc::Layout;
+use allocator_api2::alloc::{AllocError, Allocator};
+use core::{cell::Cell, marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
+
+/// A backing store that the buffer of a [`ParallelVec`] is allocated from.
+///
+/// A vector only ever holds a single buffer from its storage, which it allocates, resizes,
+/// and frees through this trait. All of the fields of the vector live within that buffer,
+/// so the same vector logic runs unchanged over heap memory, arenas, fixed buffers, or
+/// buffers owned by foreign code.
+///
+/// Every [`Allocator`] is a `Storage`, and [`Global`] is used by default. Backing stores
+/// that can only hand out a single buffer, like [`FixedStorage`], implement this trait
+/// directly.
+///
+/// # Safety
+/// Buffers returned by [`allocate`], [`grow`], and [`shrink`] must be valid for reads and
+/// writes of the size of the requested layout, aligned to its alignment, and must remain
+/// valid until they are passed to [`deallocate`], [`grow`], or [`shrink`]. If [`grow`] or
+/// [`shrink`] returns an error, the original buffer must be left unchanged.
+///
+/// [`ParallelVec`]: crate::ParallelVec
+/// [`Global`]: crate::Global
+/// [`allocate`]: Self::allocate
+/// [`deallocate`]: Self::deallocate
+/// [`grow`]: Self::grow
+/// [`shrink`]: Self::shrink
+pub unsafe trait Storage {
+    /// Allocates a buffer that fits `layout`.
+    ///
+    /// `layout` is never zero-sized.
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError>;
+
+    /// Frees a buffer returned by this storage.
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by this storage with `layout`, and must not be used
+    /// after this call.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+    /// Grows a buffer returned by this storage from `old_layout` to `new_layout`,
+    /// preserving its first `old_layout.size()` bytes.
+    ///
+    /// By default, this allocates a new buffer and copies the contents over.
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by this storage with `old_layout`, and
+    /// `new_layout` must be at least as large. On success, `ptr` must not be used again.
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let new = self.allocate(new_layout)?;
+        ptr.as_ptr()
+            .copy_to_nonoverlapping(new.as_ptr(), old_layout.size());
+        self.deallocate(ptr, old_layout);
+        Ok(new)
+    }
+
+    /// Shrinks a buffer returned by this storage from `old_layout` to `new_layout`,
+    /// preserving its first `new_layout.size()` bytes.
+    ///
+    /// By default, this allocates a new buffer and copies the contents over.
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by this storage with `old_layout`, and
+    /// `new_layout` must be no larger. On success, `ptr` must not be used again.
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let new = self.allocate(new_layout)?;
+        ptr.as_ptr()
+            .copy_to_nonoverlapping(new.as_ptr(), new_layout.size());
+        self.deallocate(ptr, old_layout);
+        Ok(new)
+    }
+}
+
+// SAFE: Allocators uphold the same guarantees for the blocks they return.
+unsafe impl<A: Allocator> Storage for A {
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        Ok(Allocator::allocate(self, layout)?.cast::<u8>())
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        Allocator::deallocate(self, ptr, layout)
+    }
+
+    #[inline]
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        Ok(Allocator::grow(self, ptr, old_layout, new_layout)?.cast::<u8>())
+    }
+
+    #[inline]
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        Ok(Allocator::shrink(self, ptr, old_layout, new_layout)?.cast::<u8>())
+    }
+}
+
+/// A [`Storage`] that hands out a single, fixed buffer that it borrows.
+///
+/// The buffer is handed out starting at its first suitably aligned byte, and is resized
+/// in place. Requests that do not fit within the buffer, or that are made while the
+/// buffer is already in use, fail.
+///
+/// # Example
+/// ```rust
+/// use core::mem::MaybeUninit;
+/// use parallel_vec::{FixedStorage, ParallelVec};
+///
+/// let mut buffer = [MaybeUninit::uninit(); 256];
+/// let mut vec = ParallelVec::with_capacity_in(8, FixedStorage::new(&mut buffer));
+/// vec.extend((0..8).map(|idx| (idx as u32, idx as f32)));
+/// assert_eq!(vec.as_slices().0, &[0, 1, 2, 3, 4, 5, 6, 7]);
+/// assert!(vec.try_reserve_exact(100).is_err());
+/// ```
+#[derive(Debug)]
+pub struct FixedStorage<'a> {
+    buffer: NonNull<u8>,
+    len: usize,
+    in_use: Cell<bool>,
+    _marker: PhantomData<&'a mut [MaybeUninit<u8>]>,
+}
+
+// SAFE: The storage uniquely borrows its buffer.
+unsafe impl Send for FixedStorage<'_> {}
+
+impl<'a> FixedStorage<'a> {
+    /// Creates a storage that hands out `buffer`.
+    pub fn new(buffer: &'a mut [MaybeUninit<u8>]) -> Self {
+        Self {
+            len: buffer.len(),
+            buffer: NonNull::from(buffer).cast::<u8>(),
+            in_use: Cell::new(false),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a storage that hands out the `len` bytes at `buffer`.
+    ///
+    /// # Safety
+    /// `buffer` must be valid for reads and writes of `len` bytes for `'a`, and must not
+    /// be accessed through any other pointer while it is handed out.
+    #[cfg(all(feature = "shm", unix))]
+    pub(crate) unsafe fn from_raw_parts(buffer: NonNull<u8>, len: usize) -> Self {
+        Self {
+            buffer,
+            len,
+            in_use: Cell::new(false),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the size of the buffer in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if `ptr` can be resized in place to `layout`.
+    fn fits(&self, ptr: NonNull<u8>, layout: Layout) -> bool {
+        // SAFE: `ptr` was handed out from within the buffer.
+        let offset = unsafe { ptr.as_ptr().offset_from(self.buffer.as_ptr()) } as usize;
+        ptr.as_ptr().align_offset(layout.align()) == 0 && self.len - offset >= layout.size()
+    }
+}
+
+// SAFE: The buffer is uniquely borrowed for 'a, and is only handed out once at a time.
+unsafe impl Storage for FixedStorage<'_> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        let offset = self.buffer.as_ptr().align_offset(layout.align());
+        if self.in_use.get() || offset > self.len || self.len - offset < layout.size() {
+            return Err(AllocError);
+        }
+        self.in_use.set(true);
+        // SAFE: The offset is within the buffer.
+        Ok(unsafe { NonNull::new_unchecked(self.buffer.as_ptr().add(offset)) })
+    }
+
+    unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {
+        self.in_use.set(false);
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        _: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        if self.fits(ptr, new_layout) {
+            Ok(ptr)
+        } else {
+            Err(AllocError)
+        }
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        _: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        if self.fits(ptr, new_layout) {
+            Ok(ptr)
+        } else {
+            Err(AllocError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FixedStorage, Storage};
+    use crate::{ParallelVec, TryReserveError};
+    use alloc::{alloc::Layout, rc::Rc, string::String};
+    use core::mem::MaybeUninit;
+
+    #[test]
+    fn test_fixed_storage_grows_in_place() {
+        let mut buffer = [MaybeUninit::uninit(); 512];
+        let range = buffer.as_ptr_range();
+        let mut vec = ParallelVec::with_capacity_in(2, FixedStorage::new(&mut buffer));
+        for idx in 0..16 {
+            vec.push((idx, String::from("a"), idx as u8));
+        }
+        let base = vec.as_slices().0.as_ptr();
+        assert!(range.contains(&base.cast()));
+        vec.shrink_to_fit();
+        assert_eq!(vec.as_slices().0.as_ptr(), base);
+        assert_eq!(
+            vec.as_slices().2,
+            &(0..16).collect::<alloc::vec::Vec<u8>>()[..]
+        );
+        assert!(matches!(
+            vec.try_reserve_exact(100),
+            Err(TryReserveError::AllocError { .. })
+        ));
+        assert_eq!(vec.len(), 16);
+    }
+
+    #[test]
+    fn test_fixed_storage_hands_out_one_buffer() {
+        let mut buffer = [MaybeUninit::uninit(); 64];
+        let storage = FixedStorage::new(&mut buffer);
+        let layout = Layout::from_size_align(24, 8).unwrap();
+        let ptr = storage.allocate(layout).unwrap();
+        assert_eq!(ptr.as_ptr().align_offset(8), 0);
+        assert!(storage.allocate(layout).is_err());
+        unsafe {
+            let larger = Layout::from_size_align(56, 8).unwrap();
+            assert_eq!(storage.grow(ptr, layout, larger), Ok(ptr));
+            assert!(storage
+                .grow(ptr, larger, Layout::from_size_align(72, 8).unwrap())
+                .is_err());
+            storage.deallocate(ptr, larger);
+        }
+        assert!(storage
+            .allocate(Layout::from_size_align(72, 1).unwrap())
+            .is_err());
+        assert!(storage.allocate(layout).is_ok());
+    }
+
+    #[test]
+    fn test_fixed_storage_drops_elements() {
+        let mut buffer = [MaybeUninit::uninit(); 64];
+        let value = Rc::new(());
+        let mut vec = ParallelVec::with_capacity_in(4, FixedStorage::new(&mut buffer));
+        vec.push((value.clone(), 1u32));
+        vec.push((value.clone(), 2u32));
+        assert_eq!(Rc::strong_count(&value), 3);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+}
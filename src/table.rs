@@ -0,0 +1,149 @@
+use crate::{ParallelParam, ParallelSlice, ParallelSliceMut};
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display, Formatter, Write};
+
+/// Formats a [`ParallelSlice`] as an aligned text table, with one row per element and
+/// one column per field.
+///
+/// See [`ParallelSlice::table`].
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::ParallelVec;
+///
+/// let vec = ParallelVec::from(vec![(1, "alpha"), (22, "b")]);
+/// assert_eq!(
+///     vec.table().headers(&["id", "name"]).to_string(),
+///     "| id | name  |\n\
+///      |----|-------|\n\
+///      | 1  | alpha |\n\
+///      | 22 | b     |\n"
+/// );
+/// ```
+pub struct Table<'a, Param: ParallelParam + 'a> {
+    slices: Param::Slices<'a>,
+    len: usize,
+    headers: Option<&'a [&'a str]>,
+}
+
+impl<'a, Param: ParallelParam + 'a> Table<'a, Param> {
+    /// Adds a header row naming each column.
+    ///
+    /// Columns without a name are left blank, and extra names are ignored.
+    pub fn headers(mut self, headers: &'a [&'a str]) -> Self {
+        self.headers = Some(headers);
+        self
+    }
+}
+
+impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
+    /// Creates a [`Table`] that formats the slice as an aligned text table, for debugging
+    /// or snapshot tests of small slices.
+    pub fn table(&self) -> Table<'_, Param> {
+        Table {
+            slices: self.as_slices(),
+            len: self.len(),
+            headers: None,
+        }
+    }
+}
+
+impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
+    /// Creates a [`Table`] that formats the slice as an aligned text table, for debugging
+    /// or snapshot tests of small slices.
+    pub fn table(&self) -> Table<'_, Param> {
+        Table {
+            slices: self.as_slices(),
+            len: self.len(),
+            headers: None,
+        }
+    }
+}
+
+/// Writes `cells`, a row-major grid with `columns` columns, as an aligned table.
+fn write_table(
+    f: &mut Formatter<'_>,
+    headers: Option<&[&str]>,
+    columns: usize,
+    cells: &[String],
+) -> fmt::Result {
+    let header = |column: usize| headers.and_then(|h| h.get(column)).copied().unwrap_or("");
+    let mut widths: Vec<usize> = (0..columns)
+        .map(|column| header(column).chars().count())
+        .collect();
+    for (idx, cell) in cells.iter().enumerate() {
+        let width = &mut widths[idx % columns];
+        *width = (*width).max(cell.chars().count());
+    }
+    let write_row = |f: &mut Formatter<'_>, row: &mut dyn Iterator<Item = &str>| {
+        for (cell, width) in row.zip(&widths) {
+            write!(f, "| {}", cell)?;
+            for _ in cell.chars().count()..*width + 1 {
+                f.write_char(' ')?;
+            }
+        }
+        f.write_str("|\n")
+    };
+    if headers.is_some() {
+        write_row(f, &mut (0..columns).map(header))?;
+        for width in &widths {
+            f.write_char('|')?;
+            for _ in 0..width + 2 {
+                f.write_char('-')?;
+            }
+        }
+        f.write_str("|\n")?;
+    }
+    for row in cells.chunks(columns) {
+        write_row(f, &mut row.iter().map(String::as_str))?;
+    }
+    Ok(())
+}
+
+macro_rules! impl_table {
+    ($n:literal, $($ts:ident $vs:ident),*) => {
+        impl<'a, $($ts: Display + 'a),*> Display for Table<'a, ($($ts,)*)> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let ($($vs,)*) = self.slices;
+                let mut cells = Vec::with_capacity(self.len * $n);
+                for idx in 0..self.len {
+                    $(cells.push(alloc::format!("{}", $vs[idx]));)*
+                }
+                write_table(f, self.headers, $n, &cells)
+            }
+        }
+    };
+}
+
+impl_table!(1, T1 V1);
+impl_table!(2, T1 V1, T2 V2);
+impl_table!(3, T1 V1, T2 V2, T3 V3);
+impl_table!(4, T1 V1, T2 V2, T3 V3, T4 V4);
+impl_table!(5, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5);
+impl_table!(6, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6);
+impl_table!(7, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7);
+impl_table!(8, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8);
+impl_table!(9, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9);
+impl_table!(10, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10);
+impl_table!(
+    11, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11
+);
+impl_table!(
+    12, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12
+);
+impl_table!(
+    13, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13
+);
+impl_table!(
+    14, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14
+);
+impl_table!(
+    15, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14, T15 V15
+);
+impl_table!(
+    16, T1 V1, T2 V2, T3 V3, T4 V4, T5 V5, T6 V6, T7 V7, T8 V8, T9 V9, T10 V10, T11 V11, T12 V12,
+    T13 V13, T14 V14, T15 V15, T16 V16
+);
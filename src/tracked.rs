@@ -0,0 +1,339 @@
+use crate::{
+    iter::{Iter, IterMut},
+    ParallelParam, ParallelSlice, ParallelVec,
+};
+use alloc::vec::Vec;
+use core::{iter::Enumerate, marker::PhantomData};
+
+/// A [`ParallelVec`] that records the tick at which each element was last changed.
+///
+/// The container has a current tick, which is advanced with [`advance_tick`]. Every
+/// element is stamped with the current tick whenever it is inserted, or whenever mutable
+/// access to it is handed out, whether or not it is actually modified. This allows
+/// incremental systems, such as network replication, to only process the elements that
+/// changed since they last ran, with [`iter_changed_since`].
+///
+/// # Example
+/// ```rust
+/// use parallel_vec::TrackedParallelVec;
+///
+/// let mut entities = TrackedParallelVec::new();
+/// entities.push((1u32, 0.0f32));
+/// entities.push((2, 0.0));
+///
+/// // Everything is new to the first replication pass.
+/// let last_sync = entities.advance_tick();
+/// *entities.get_mut(1).unwrap().1 = 3.0;
+///
+/// let changed: Vec<_> = entities.iter_changed_since(last_sync).collect();
+/// assert_eq!(changed, [(1, (&2, &3.0))]);
+/// ```
+///
+/// [`advance_tick`]: Self::advance_tick
+/// [`iter_changed_since`]: Self::iter_changed_since
+pub struct TrackedParallelVec<Param: ParallelParam> {
+    vec: ParallelVec<Param>,
+    /// The tick at which each element was last changed.
+    ticks: Vec<u64>,
+    tick: u64,
+}
+
+impl<Param: ParallelParam> TrackedParallelVec<Param> {
+    /// Constructs a new, empty `TrackedParallelVec`, with a current tick of zero.
+    pub fn new() -> Self {
+        Self::from(ParallelVec::new())
+    }
+
+    /// Constructs a new, empty `TrackedParallelVec` with the capacity for `capacity`
+    /// elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from(ParallelVec::with_capacity(capacity))
+    }
+
+    /// Returns the number of elements in the container.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if the container contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Returns the current tick, which changed elements are stamped with.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Advances the current tick, and returns the new tick.
+    ///
+    /// Elements changed before this call are not returned by [`iter_changed_since`]
+    /// with the returned tick.
+    ///
+    /// [`iter_changed_since`]: Self::iter_changed_since
+    pub fn advance_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Returns the tick at which the element at `index` was last changed, or `None` if
+    /// it is out of bounds.
+    pub fn changed_tick(&self, index: usize) -> Option<u64> {
+        self.ticks.get(index).copied()
+    }
+
+    /// Appends an element to the back of the container.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    pub fn push(&mut self, value: Param) {
+        self.vec.push(value);
+        self.ticks.push(self.tick);
+    }
+
+    /// Removes the last element from the container and returns it, or `None` if it is
+    /// empty.
+    pub fn pop(&mut self) -> Option<Param> {
+        self.ticks.pop();
+        self.vec.pop()
+    }
+
+    /// Inserts an element at `index`, shifting all elements after it to the right.
+    ///
+    /// Only the inserted element is stamped as changed.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: Param) {
+        self.vec.insert(index, value);
+        self.ticks.insert(index, self.tick);
+    }
+
+    /// Removes the element at `index` and returns it, shifting all elements after it to
+    /// the left.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Param {
+        crate::assert_in_bounds(index, self.len());
+        self.ticks.remove(index);
+        match self.vec.remove(index) {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+
+    /// Removes the element at `index` and returns it, moving the last element into its
+    /// place.
+    ///
+    /// The moved element keeps the tick at which it was last changed.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> Param {
+        let value = self.vec.swap_remove(index);
+        self.ticks.swap_remove(index);
+        value
+    }
+
+    /// Shortens the container to `len` elements, dropping the rest.
+    pub fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len);
+        self.ticks.truncate(len);
+    }
+
+    /// Removes every element from the container.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Param::Ref<'_>> {
+        self.vec.get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of
+    /// bounds, and stamps the element as changed.
+    pub fn get_mut(&mut self, index: usize) -> Option<Param::RefMut<'_>> {
+        *self.ticks.get_mut(index)? = self.tick;
+        self.vec.get_mut(index)
+    }
+
+    /// Gets the elements as a [`ParallelSlice`].
+    pub fn as_parallel_slice(&self) -> ParallelSlice<'_, Param> {
+        self.vec.as_parallel_slice()
+    }
+
+    /// Gets the fields of every element as slices.
+    pub fn as_slices(&self) -> Param::Slices<'_> {
+        self.vec.as_slices()
+    }
+
+    /// Gets the fields of every element as mutable slices, and stamps every element as
+    /// changed.
+    pub fn as_slices_mut(&mut self) -> Param::SlicesMut<'_> {
+        self.mark_all_changed();
+        self.vec.as_slices_mut()
+    }
+
+    /// Returns an iterator over references to the elements.
+    pub fn iter(&self) -> Iter<'_, Param> {
+        self.vec.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements, and stamps every
+    /// element as changed.
+    pub fn iter_mut(&mut self) -> IterMut<'_, Param> {
+        self.mark_all_changed();
+        self.vec.iter_mut()
+    }
+
+    /// Returns an iterator over the indices of, and references to, the elements that
+    /// changed at or after `tick`.
+    pub fn iter_changed_since(&self, tick: u64) -> ChangedSince<'_, Param> {
+        ChangedSince {
+            ticks: self.ticks.iter().enumerate(),
+            storage: self.vec.storage,
+            since: tick,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Stamps every element as changed at the current tick.
+    pub fn mark_all_changed(&mut self) {
+        let tick = self.tick;
+        self.ticks.iter_mut().for_each(|changed| *changed = tick);
+    }
+
+    /// Gets mutable access to the elements without stamping them as changed.
+    ///
+    /// The number of elements can not be changed through the returned slice, so the
+    /// ticks remain in sync with the elements.
+    pub fn bypass_change_detection(&mut self) -> crate::ParallelSliceMut<'_, Param> {
+        self.vec.as_parallel_slice_mut()
+    }
+
+    /// Converts the container into a [`ParallelVec`], discarding the ticks.
+    pub fn into_parallel_vec(self) -> ParallelVec<Param> {
+        self.vec
+    }
+}
+
+impl<Param: ParallelParam> Default for TrackedParallelVec<Param> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param: ParallelParam> From<ParallelVec<Param>> for TrackedParallelVec<Param> {
+    /// Wraps the elements of `vec`, stamping all of them with the tick zero.
+    fn from(vec: ParallelVec<Param>) -> Self {
+        Self {
+            ticks: alloc::vec![0; vec.len()],
+            vec,
+            tick: 0,
+        }
+    }
+}
+
+impl<Param: ParallelParam> Extend<Param> for TrackedParallelVec<Param> {
+    fn extend<I: IntoIterator<Item = Param>>(&mut self, iter: I) {
+        // Push every element together with its tick, so the ticks stay in sync with the
+        // elements even if the iterator panics.
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.vec.reserve(lower);
+        self.ticks.reserve(lower);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+/// An iterator over the elements of a [`TrackedParallelVec`] that changed at or after a
+/// given tick, created by [`TrackedParallelVec::iter_changed_since`].
+pub struct ChangedSince<'a, Param: ParallelParam> {
+    ticks: Enumerate<core::slice::Iter<'a, u64>>,
+    storage: Param::Storage,
+    since: u64,
+    _marker: PhantomData<&'a Param>,
+}
+
+impl<'a, Param: ParallelParam> Iterator for ChangedSince<'a, Param> {
+    type Item = (usize, Param::Ref<'a>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let since = self.since;
+        let (index, _) = self.ticks.find(|(_, changed)| **changed >= since)?;
+        // SAFE: There is one tick for every element, and the container is borrowed for 'a.
+        let value = unsafe { Param::as_ref(Param::ptr_at(self.storage, index)) };
+        Some((index, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.ticks.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TrackedParallelVec;
+    use alloc::{string::String, vec::Vec};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    fn changed(vec: &TrackedParallelVec<(u32, String)>, tick: u64) -> Vec<usize> {
+        vec.iter_changed_since(tick)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    #[test]
+    fn test_change_ticks() {
+        let mut vec: TrackedParallelVec<(u32, String)> = TrackedParallelVec::new();
+        vec.extend((0..4).map(|idx| (idx, String::new())));
+        assert_eq!(changed(&vec, 0), [0, 1, 2, 3]);
+
+        let tick = vec.advance_tick();
+        assert_eq!(changed(&vec, tick), []);
+        vec.get_mut(2).unwrap().1.push('x');
+        vec.push((4, String::new()));
+        assert_eq!(changed(&vec, tick), [2, 4]);
+        assert_eq!(vec.changed_tick(2), Some(1));
+        assert_eq!(vec.changed_tick(0), Some(0));
+
+        let tick = vec.advance_tick();
+        vec.bypass_change_detection().as_slices_mut().0[0] = 10;
+        vec.insert(1, (5, String::new()));
+        assert_eq!(changed(&vec, tick), [1]);
+        vec.swap_remove(0);
+        assert_eq!(changed(&vec, tick), [1]);
+        assert_eq!(vec.remove(1), (5, String::new()));
+        assert_eq!(changed(&vec, tick), []);
+        assert_eq!(vec.len(), 4);
+
+        for (id, _) in vec.iter_mut() {
+            *id += 1;
+        }
+        assert_eq!(changed(&vec, tick).len(), 4);
+        let values: Vec<u32> = vec
+            .iter_changed_since(tick)
+            .map(|(_, (id, _))| *id)
+            .collect();
+        assert_eq!(values, vec.as_slices().0);
+    }
+
+    #[test]
+    fn test_extend_keeps_ticks_on_panic() {
+        let mut vec: TrackedParallelVec<(u32, String)> = TrackedParallelVec::new();
+        vec.push((0, String::new()));
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.extend((1..4).map(|idx| {
+                assert!(idx < 3);
+                (idx, String::new())
+            }));
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 3);
+        assert_eq!(changed(&vec, 0), [0, 1, 2]);
+        assert_eq!(vec.changed_tick(2), Some(0));
+    }
+}
@@ -1,9 +1,21 @@
-use crate::{assert_in_bounds, iter::IntoIter, out_of_bounds, ParallelParam, ParallelSliceMut};
+use crate::{
+    assert_bitmask_len, assert_in_bounds, bitmask_get,
+    growth::{Doubling, GrowthPolicy},
+    handle_reserve_error,
+    iter::{IntoIter, IterMut},
+    out_of_bounds,
+    param::MemoryLayout,
+    slice::ParallelSliceIndexMut,
+    slice_range, ParallelParam, ParallelSliceMut, Storage, TryReserveError,
+};
 use alloc::vec::Vec;
+use allocator_api2::alloc::Global;
 use core::{
+    cmp::Ordering,
     fmt::{Debug, Formatter},
     hash::{Hash, Hasher},
-    ops::{Deref, DerefMut},
+    mem::ManuallyDrop,
+    ops::{Deref, Range, RangeBounds},
 };
 
 /// A contiguously growable heterogenous array type.
@@ -16,12 +28,61 @@ use core::{
 /// allocation pressure. It also only stores one length and capacity instead
 /// of duplicating the values across multiple `Vec` fields.
 ///
+/// The `Growth` parameter controls how the capacity grows when the vector needs
+/// to reallocate. See [`GrowthPolicy`] for more details.
+///
+/// The `A` parameter is the [`Storage`] the vector's buffer is allocated from, which
+/// may be any [`Allocator`]. By default, this is the [`Global`] allocator.
+///
+/// A [`ParallelVec`] dereferences to a [`ParallelSliceMut`] over all of its elements, so
+/// every slice method that only reads the elements can be called directly on the vector.
+/// A shared [`ParallelSlice`] can be borrowed with [`as_parallel_slice`].
+///
+/// Unlike a `Vec<T>`, the vector does not implement `DerefMut`. A [`ParallelSliceMut`] is
+/// a sized value, so handing out a mutable reference to it would allow it to be swapped
+/// or replaced, leaving the vector with another buffer than its capacity describes.
+/// Methods that mutate the elements are instead called through
+/// [`as_parallel_slice_mut`], which returns the slice by value:
+///
+/// ```compile_fail
+/// use parallel_vec::ParallelVec;
+///
+/// let mut a = ParallelVec::from(vec![(1, 2)]);
+/// let mut b = ParallelVec::from(vec![(3, 4), (5, 6)]);
+/// core::mem::swap(&mut *a, &mut *b);
+/// ```
+///
+/// [`Allocator`]: crate::Allocator
+/// [`ParallelSlice`]: crate::ParallelSlice
+/// [`as_parallel_slice`]: ParallelSliceMut::as_parallel_slice
+/// [`as_parallel_slice_mut`]: Self::as_parallel_slice_mut
 /// [structures of arrays]: https://en.wikipedia.org/wiki/AoS_and_SoA#Structure_of_arrays
 #[repr(C)]
-pub struct ParallelVec<Param: ParallelParam> {
+pub struct ParallelVec<Param: ParallelParam, Growth: GrowthPolicy = Doubling, A: Storage = Global> {
     pub(crate) len: usize,
     pub(crate) storage: Param::Storage,
     pub(crate) capacity: usize,
+    pub(crate) growth: Growth,
+    pub(crate) column_align: usize,
+    pub(crate) alloc: A,
+}
+
+// SAFE: The vector owns its elements, like a `Vec`.
+unsafe impl<Param, Growth, A> Send for ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + Send,
+    Growth: GrowthPolicy + Send,
+    A: Storage + Send,
+{
+}
+
+// SAFE: Shared references to the vector only give out shared references to its elements.
+unsafe impl<Param, Growth, A> Sync for ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + Sync,
+    Growth: GrowthPolicy + Sync,
+    A: Storage + Sync,
+{
 }
 
 impl<Param: ParallelParam> ParallelVec<Param> {
@@ -40,17 +101,263 @@ impl<Param: ParallelParam> ParallelVec<Param> {
     /// It is important to note that although the returned vector has the capacity specified,
     /// the vector will have a zero length.
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_growth_policy(capacity, Doubling)
+    }
+
+    /// Creates a [`ParallelVec`] of `len` elements, where each element is the value
+    /// returned by calling `f` with its index.
+    ///
+    /// The vector is allocated once with exactly `len` capacity.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let vec = ParallelVec::from_fn(4, |i| (i, i as f32 * 0.5));
+    /// assert_eq!(vec.as_slices().0, &[0, 1, 2, 3]);
+    /// assert_eq!(vec.as_slices().1, &[0.0, 0.5, 1.0, 1.5]);
+    /// ```
+    pub fn from_fn<F>(len: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize) -> Param,
+    {
+        let mut vec = Self::with_capacity(len);
+        for idx in 0..len {
+            unsafe {
+                // SAFE: The vector was allocated with the capacity for every element.
+                Param::write(Param::ptr_at(vec.storage, idx), f(idx));
+            }
+            vec.len = idx + 1;
+        }
+        vec.validate();
+        vec
+    }
+
+    /// Creates a [`ParallelVec`] of `n` clones of `elem`.
+    ///
+    /// The vector is allocated once with exactly `n` capacity, and `elem` itself is moved
+    /// into the last element. This is also available as `parallel_vec![elem; n]`.
+    pub fn from_elem(elem: Param, n: usize) -> Self
+    where
+        Param: Clone,
+    {
+        let mut vec = Self::with_capacity(n);
+        if n > 0 {
+            for _ in 1..n {
+                vec.push(elem.clone());
+            }
+            vec.push(elem);
+        }
+        vec
+    }
+
+    /// Creates a [`ParallelVec`] by concatenating clones of the elements of each vector
+    /// in `vecs`, in order.
+    ///
+    /// The total length is computed up front, so the result is allocated once with
+    /// exactly the capacity it needs.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let a = ParallelVec::from(vec![(1, String::from("a")), (2, String::from("b"))]);
+    /// let b = ParallelVec::from(vec![(3, String::from("c"))]);
+    /// let joined = ParallelVec::concat(&[a, b]);
+    /// assert_eq!(joined.as_slices().0, &[1, 2, 3]);
+    /// ```
+    pub fn concat<G: GrowthPolicy, B: Storage>(vecs: &[ParallelVec<Param, G, B>]) -> Self
+    where
+        Param: Clone,
+    {
+        let len = vecs.iter().map(|vec| vec.len).sum();
+        let mut new = Self::with_capacity(len);
+        for vec in vecs {
+            // SAFE: The elements of `vec` are initialized and borrowed for the whole
+            // call, and `new` was allocated with the capacity for all of them.
+            unsafe { new.extend_cloned(Param::as_ptr(vec.storage), vec.len) };
+        }
+        new.validate();
+        new
+    }
+
+    /// Creates a [`ParallelVec`] by moving the elements of each vector yielded by `vecs`
+    /// into it, in order.
+    ///
+    /// The vectors are collected before any element is moved, so the total length is
+    /// computed up front and the result is allocated once with exactly the capacity it
+    /// needs. Each input is then moved with a single copy per field.
+    pub fn concat_iter<G, B, I>(vecs: I) -> Self
+    where
+        G: GrowthPolicy,
+        B: Storage,
+        I: IntoIterator<Item = ParallelVec<Param, G, B>>,
+    {
+        let mut vecs: Vec<_> = vecs.into_iter().collect();
+        let len = vecs.iter().map(|vec| vec.len).sum();
+        let mut new = Self::with_capacity(len);
+        for vec in vecs.iter_mut() {
+            new.append(vec);
+        }
+        new
+    }
+}
+
+impl<Param: ParallelParam, Growth: GrowthPolicy> ParallelVec<Param, Growth> {
+    /// Constructs a new, empty `ParallelVec` which will use the given growth policy
+    /// when reallocating.
+    ///
+    /// The vector will not allocate until elements are pushed onto it.
+    pub fn with_growth_policy(growth: Growth) -> Self {
+        Self::with_capacity_and_growth_policy(0, growth)
+    }
+
+    /// Constructs a new, empty [`ParallelVec`] with the specified capacity, which will
+    /// use the given growth policy when reallocating.
+    ///
+    /// The vector will be able to hold exactly capacity elements without reallocating.
+    /// If capacity is 0, the vector will not allocate.
+    pub fn with_capacity_and_growth_policy(capacity: usize, growth: Growth) -> Self {
+        Self::with_capacity_and_growth_policy_in(capacity, growth, Global)
+    }
+}
+
+impl<Param: ParallelParam, A: Storage> ParallelVec<Param, Doubling, A> {
+    /// Constructs a new, empty `ParallelVec` which allocates from the provided
+    /// allocator.
+    ///
+    /// The vector will not allocate until elements are pushed onto it.
+    pub fn new_in(alloc: A) -> Self {
+        Self::with_capacity_in(0, alloc)
+    }
+
+    /// Constructs a new, empty [`ParallelVec`] with the specified capacity, which
+    /// allocates from the provided allocator.
+    ///
+    /// The vector will be able to hold exactly capacity elements without reallocating.
+    /// If capacity is 0, the vector will not allocate.
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self::with_capacity_and_growth_policy_in(capacity, Doubling, alloc)
+    }
+}
+
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> ParallelVec<Param, Growth, A> {
+    /// Whether every field of `Param` is zero-sized. Such vectors never allocate.
+    const IS_ZST: bool = core::mem::size_of::<Param>() == 0;
+
+    /// Constructs a new, empty [`ParallelVec`] with the specified capacity, which will
+    /// use the given growth policy when reallocating and allocates from the provided
+    /// allocator.
+    ///
+    /// The vector will be able to hold exactly capacity elements without reallocating.
+    /// If capacity is 0, the vector will not allocate.
+    ///
+    /// If every field of `Param` is zero-sized, the vector never allocates and its
+    /// capacity is always `usize::MAX`.
+    pub fn with_capacity_and_growth_policy_in(capacity: usize, growth: Growth, alloc: A) -> Self {
+        let capacity = if Self::IS_ZST { usize::MAX } else { capacity };
         unsafe {
             Self {
                 len: 0,
                 capacity,
-                storage: if capacity == 0 {
+                storage: if capacity == 0 || Self::IS_ZST {
                     Param::dangling()
                 } else {
-                    Param::alloc(capacity)
+                    Param::alloc(capacity, 1, &alloc)
                 },
+                growth,
+                column_align: 1,
+                alloc,
+            }
+        }
+    }
+
+    /// Returns a reference to the vector's [`GrowthPolicy`].
+    pub fn growth_policy(&self) -> &Growth {
+        &self.growth
+    }
+
+    /// Returns a reference to the underlying allocator.
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    /// Returns the minimum alignment in bytes of the start of every field.
+    ///
+    /// Fields are always aligned to at least the alignment of their type. By
+    /// default, this is 1, and no additional alignment is applied.
+    pub fn column_alignment(&self) -> usize {
+        self.column_align
+    }
+
+    /// Sets the minimum alignment in bytes of the start of every field, e.g. to 32 or
+    /// 64 bytes for aligned SIMD loads, or to meet the requirements of a GPU or DMA
+    /// interface.
+    ///
+    /// The end of the buffer is padded to a multiple of `align` as well, so no two
+    /// fields share an `align` sized block. Setting this to [`CACHE_LINE_SIZE`] pads
+    /// every field to whole cache lines, so threads writing to different fields of the
+    /// same vector never contend for the same cache line.
+    ///
+    /// [`CACHE_LINE_SIZE`]: crate::CACHE_LINE_SIZE
+    ///
+    /// If the vector has already allocated, its elements are moved into a new
+    /// allocation with the requested alignment. Zero-sized fields are not stored in
+    /// the buffer, so they are not affected.
+    ///
+    /// # Panics
+    /// Panics if `align` is not a power of two, or if the new capacity exceeds
+    /// `isize::MAX` bytes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let mut vec: ParallelVec<(u8, f32)> = ParallelVec::new();
+    /// vec.set_column_alignment(64);
+    /// vec.push((1, 2.0));
+    ///
+    /// let (bytes, floats) = vec.as_slices();
+    /// assert_eq!(bytes.as_ptr().align_offset(64), 0);
+    /// assert_eq!(floats.as_ptr().align_offset(64), 0);
+    /// ```
+    pub fn set_column_alignment(&mut self, align: usize) {
+        assert!(
+            align.is_power_of_two(),
+            "column alignment must be a power of two"
+        );
+        if align == self.column_align {
+            return;
+        }
+        if self.capacity > 0 && !Self::IS_ZST {
+            unsafe {
+                let storage = Param::alloc(self.capacity, align, &self.alloc);
+                Param::copy_to_nonoverlapping(
+                    Param::as_ptr(self.storage),
+                    Param::as_ptr(storage),
+                    self.len,
+                );
+                Param::dealloc(
+                    &mut self.storage,
+                    self.capacity,
+                    self.column_align,
+                    &self.alloc,
+                );
+                self.storage = storage;
             }
         }
+        self.column_align = align;
+        self.validate();
+    }
+
+    /// Gets the [`MemoryLayout`] of the buffer backing the vector.
+    ///
+    /// This is the layout returned by [`ParallelParam::layout_for_capacity_aligned`]
+    /// for the current capacity and [`column_alignment`] of the vector.
+    ///
+    /// [`column_alignment`]: Self::column_alignment
+    pub fn memory_layout(&self) -> MemoryLayout<Param> {
+        Param::layout_for_capacity_aligned(self.capacity, self.column_align)
     }
 
     /// Returns the number of elements the vector can hold without reallocating.
@@ -58,6 +365,110 @@ impl<Param: ParallelParam> ParallelVec<Param> {
         self.capacity
     }
 
+    /// Borrows the elements as a [`ParallelSliceMut`].
+    ///
+    /// Every method of [`ParallelSliceMut`] that only needs shared access can be called
+    /// directly on the vector. Methods that mutate the elements are called through the
+    /// slice returned here.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let mut vec = ParallelVec::from(vec![(3, 'c'), (1, 'a'), (2, 'b')]);
+    /// vec.as_parallel_slice_mut().sort_by_key(|(key, _)| *key);
+    /// assert_eq!(vec.as_slices(), (&[1, 2, 3][..], &['a', 'b', 'c'][..]));
+    /// ```
+    #[inline]
+    pub fn as_parallel_slice_mut(&mut self) -> ParallelSliceMut<'_, Param> {
+        // SAFE: The slice uniquely borrows the vector's elements for its lifetime.
+        unsafe { ParallelSliceMut::from_raw_parts(self.storage, self.len) }
+    }
+
+    /// Borrows the vector as the [`ParallelSliceMut`] it dereferences to.
+    ///
+    /// The returned reference must never be handed out: swapping or replacing the slice
+    /// behind it would desynchronize the vector's length and buffer from its capacity.
+    #[inline(always)]
+    fn slice_mut(&mut self) -> &mut ParallelSliceMut<'static, Param> {
+        // SAFE: Both ParallelVec and ParallelSliceMut have the same
+        // layout in memory due to #[repr(C)]
+        unsafe {
+            let ptr: *mut Self = self;
+            &mut *(ptr.cast::<ParallelSliceMut<'static, Param>>())
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`, if available, or
+    /// [`None`] if it is out of bounds.
+    ///
+    /// See [`ParallelSliceMut::get_mut`].
+    #[inline]
+    pub fn get_mut<I>(&mut self, index: I) -> Option<I::Output<'_>>
+    where
+        I: ParallelSliceIndexMut<ParallelSliceMut<'static, Param>>,
+    {
+        index.get_mut(self.slice_mut())
+    }
+
+    /// Returns the first element of the vector, or `None` if it is empty.
+    #[inline]
+    pub fn first_mut(&mut self) -> Option<Param::RefMut<'_>> {
+        self.get_mut(0)
+    }
+
+    /// Returns the last element of the vector, or `None` if it is empty.
+    #[inline]
+    pub fn last_mut(&mut self) -> Option<Param::RefMut<'_>> {
+        self.slice_mut().last_mut()
+    }
+
+    /// Gets a mutable reference to the elements at `index`.
+    ///
+    /// See [`ParallelSliceMut::index_mut`].
+    ///
+    /// # Panics
+    /// This function will panic if `index` is out of bounds.
+    #[inline]
+    pub fn index_mut<I>(&mut self, index: I) -> I::Output<'_>
+    where
+        I: ParallelSliceIndexMut<ParallelSliceMut<'static, Param>>,
+    {
+        index.index_mut(self.slice_mut())
+    }
+
+    /// Returns mutable references to elements, without doing bounds checking.
+    ///
+    /// For a safe alternative see [`get_mut`].
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is undefined behavior even if the
+    /// resulting reference is not used.
+    ///
+    /// [`get_mut`]: Self::get_mut
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> Param::RefMut<'_> {
+        self.slice_mut().get_unchecked_mut(index)
+    }
+
+    /// Gets mutable individual slices for every field.
+    #[inline]
+    pub fn as_slices_mut(&mut self) -> Param::SlicesMut<'_> {
+        self.slice_mut().as_slices_mut()
+    }
+
+    /// Returns an iterator that allows modifying each value.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, Param> {
+        self.slice_mut().iter_mut()
+    }
+
+    /// Gets individual mutable iterators for every field.
+    #[inline]
+    pub fn iters_mut(&mut self) -> Param::ItersMut<'_> {
+        self.slice_mut().iters_mut()
+    }
+
     /// Clears the vector, removing all values.
     ///
     /// Note that this method has no effect on the allocated capacity of the vector.
@@ -74,17 +485,19 @@ impl<Param: ParallelParam> ParallelVec<Param> {
         if self.len <= len {
             return;
         }
+        let end = self.len;
+        // Set len first in case one of the Drop impls panics, so the
+        // vector never observes a partially dropped tail.
+        self.len = len;
         unsafe {
-            self.drop_range(len, self.len);
-            self.len = len;
+            self.drop_range(len, end);
+            self.poison(len, end);
         }
+        self.validate();
     }
 
     pub(crate) unsafe fn drop_range(&mut self, start: usize, end: usize) {
-        let base = Param::as_ptr(self.storage);
-        for idx in start..end {
-            Param::drop(Param::add(base, idx));
-        }
+        Param::drop_slice(Param::ptr_at(self.storage, start), end - start);
     }
 
     /// Shrinks the capacity of the vector with a lower bound.
@@ -93,19 +506,39 @@ impl<Param: ParallelParam> ParallelVec<Param> {
     /// the supplied value.
     ///
     /// If the current capacity is less than the lower limit, this is a no-op.
+    ///
+    /// The existing allocation is shrunk in place when possible. If the vector is
+    /// empty and shrunk to a capacity of 0, the allocation is freed.
     pub fn shrink_to(&mut self, min_capacity: usize) {
-        if min_capacity > self.capacity {
+        let capacity = core::cmp::max(self.len, min_capacity);
+        if capacity >= self.capacity || Self::IS_ZST {
             return;
         }
-        let capacity = core::cmp::max(self.len, min_capacity);
-        let src = Param::as_ptr(self.storage);
         unsafe {
-            let dst = Param::alloc(capacity);
-            Param::copy_to_nonoverlapping(src, Param::as_ptr(dst), self.len);
-            Param::dealloc(&mut self.storage, self.capacity);
-            self.storage = dst;
+            if capacity == 0 {
+                Param::dealloc(
+                    &mut self.storage,
+                    self.capacity,
+                    self.column_align,
+                    &self.alloc,
+                );
+                self.storage = Param::dangling();
+            } else {
+                match Param::try_realloc(
+                    self.storage,
+                    self.capacity,
+                    capacity,
+                    self.len,
+                    self.column_align,
+                    &self.alloc,
+                ) {
+                    Ok(storage) => self.storage = storage,
+                    Err(err) => handle_reserve_error(err),
+                }
+            }
         }
         self.capacity = capacity;
+        self.validate();
     }
 
     /// Shrinks the capacity of the vector as much as possible.
@@ -116,8 +549,142 @@ impl<Param: ParallelParam> ParallelVec<Param> {
         self.shrink_to(self.len);
     }
 
+    /// Converts the vector into a `Vec` of rows, interleaving the fields of each element.
+    ///
+    /// The `Vec` is allocated once with exactly the length of the vector, and the
+    /// elements are moved in a single sequential pass.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let vec = ParallelVec::from(vec![(1, 'a'), (2, 'b')]);
+    /// assert_eq!(vec.into_vec(), vec![(1, 'a'), (2, 'b')]);
+    /// ```
+    pub fn into_vec(mut self) -> Vec<Param> {
+        let len = self.len;
+        let mut rows: Vec<Param> = Vec::with_capacity(len);
+        unsafe {
+            let src = Param::as_ptr(self.storage);
+            let dst = rows.as_mut_ptr();
+            for idx in 0..len {
+                dst.add(idx).write(Param::read(Param::add(src, idx)));
+            }
+            rows.set_len(len);
+            // The elements have all been moved out, so only the buffer is freed.
+            self.poison(0, len);
+            self.len = 0;
+        }
+        rows
+    }
+
+    /// Converts the vector into one `Vec` per field. This is the inverse of converting a
+    /// tuple of `Vec`s into a [`ParallelVec`] with `TryFrom`.
+    ///
+    /// As the fields share a single allocation, each `Vec` is allocated separately with
+    /// exactly the length of the vector, and each field is moved with a single copy.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let vec = ParallelVec::from(vec![(1, 'a'), (2, 'b')]);
+    /// let (numbers, letters) = vec.into_vecs();
+    /// assert_eq!(numbers, vec![1, 2]);
+    /// assert_eq!(letters, vec!['a', 'b']);
+    /// ```
+    pub fn into_vecs(mut self) -> Param::Vecs {
+        let len = self.len;
+        let mut vecs = Param::vecs_with_capacity(len);
+        unsafe {
+            let dst = Param::get_vec_ptrs(&mut vecs);
+            Param::copy_to_nonoverlapping(Param::as_ptr(self.storage), dst, len);
+            Param::set_vec_len(&mut vecs, len);
+            // The elements have all been moved out, so only the buffer is freed.
+            self.poison(0, len);
+            self.len = 0;
+        }
+        vecs
+    }
+
+    /// Consumes and leaks the vector, returning a mutable slice per field of its
+    /// contents.
+    ///
+    /// The buffer of the vector is never deallocated, so the slices can be given the
+    /// `'static` lifetime. This is mainly useful for data that lives for the remainder
+    /// of the program, such as tables that are initialized once.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let vec = ParallelVec::from(vec![(1, 'a'), (2, 'b')]);
+    /// let (numbers, letters) = vec.leak();
+    /// numbers[0] += 10;
+    /// assert_eq!(numbers, &[11, 2]);
+    /// assert_eq!(letters, &['a', 'b']);
+    /// ```
+    pub fn leak(self) -> Param::SlicesMut<'static>
+    where
+        A: 'static,
+    {
+        let vec = ManuallyDrop::new(self);
+        // SAFE: The buffer is never deallocated, and the vector can no longer be used.
+        unsafe { Param::as_slices_mut(Param::as_ptr(vec.storage), vec.len) }
+    }
+
+    /// Forces the length of the vector to `new_len`.
+    ///
+    /// This does not drop or initialize any elements. It is normally used together with
+    /// [`spare_capacity_mut`] to initialize elements in place.
+    ///
+    /// # Safety
+    /// `new_len` must be less than or equal to [`capacity`], and the elements at
+    /// `old_len..new_len` must be initialized in every field.
+    ///
+    /// [`spare_capacity_mut`]: Self::spare_capacity_mut
+    /// [`capacity`]: Self::capacity
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity);
+        self.len = new_len;
+        self.validate();
+    }
+
+    /// Returns the remaining spare capacity of the vector as a slice of
+    /// [`MaybeUninit<T>`] per field.
+    ///
+    /// The returned slices can be used to fill the vector with data before marking the
+    /// data as initialized with [`set_len`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let mut vec = ParallelVec::<(u32, f32)>::with_capacity(4);
+    /// let (ids, weights) = vec.spare_capacity_mut();
+    /// for idx in 0..3 {
+    ///     ids[idx].write(idx as u32);
+    ///     weights[idx].write(0.5);
+    /// }
+    /// unsafe { vec.set_len(3) };
+    /// assert_eq!(vec.as_slices().0, &[0, 1, 2]);
+    /// ```
+    ///
+    /// [`MaybeUninit<T>`]: core::mem::MaybeUninit
+    /// [`set_len`]: Self::set_len
+    pub fn spare_capacity_mut(&mut self) -> Param::UninitSlicesMut<'_> {
+        // SAFE: The elements past the length are within the allocation, and are only
+        // exposed as possibly uninitialized.
+        unsafe {
+            Param::as_uninit_slices_mut(
+                Param::ptr_at(self.storage, self.len),
+                self.capacity - self.len,
+            )
+        }
+    }
+
     /// Moves all the elements of `other` into `Self`, leaving `other` empty.
-    pub fn append(&mut self, other: &mut ParallelVec<Param>) {
+    pub fn append<G: GrowthPolicy, B: Storage>(&mut self, other: &mut ParallelVec<Param, G, B>) {
         self.reserve(other.len);
         unsafe {
             let src = Param::as_ptr(other.storage);
@@ -126,8 +693,138 @@ impl<Param: ParallelParam> ParallelVec<Param> {
             self.len += other.len;
             // No need to drop from the other vec, data has been moved to
             // the current one. Just set the length here.
+            other.poison(0, other.len);
             other.len = 0;
         }
+        self.validate();
+    }
+
+    /// Performs an inner join of `self` and `other`, matching the elements whose keys
+    /// extracted by `left_key` and `right_key` are equal, and collects the value returned
+    /// by `f` for every matching pair of elements into a new [`ParallelVec`].
+    ///
+    /// The results are ordered by the element of `self` they were produced from, and then
+    /// by the element of `other`. The key of every element is computed once, and the
+    /// elements of `other` are sorted by key, so this takes `O((n + m) log m)` time for
+    /// `n` elements in `self` and `m` in `other`, plus the number of matches.
+    ///
+    /// `f` is called once for every match, in that order, and typically builds a tuple
+    /// concatenating the fields of both elements.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let users = ParallelVec::from(vec![(1u32, "alice"), (2, "bob"), (3, "carol")]);
+    /// let orders = ParallelVec::from(vec![(3u32, 10.0f32), (1, 5.0), (3, 2.5)]);
+    /// let joined: ParallelVec<(u32, &str, f32)> = users.join_by_key(
+    ///     &orders,
+    ///     |(id, _)| *id,
+    ///     |(id, _)| *id,
+    ///     |(id, name), (_, total)| (*id, *name, *total),
+    /// );
+    /// assert_eq!(joined.as_slices().1, &["alice", "carol", "carol"]);
+    /// assert_eq!(joined.as_slices().2, &[5.0, 10.0, 2.5]);
+    /// ```
+    pub fn join_by_key<Other, G, B, K, R, FL, FR, F>(
+        &self,
+        other: &ParallelVec<Other, G, B>,
+        mut left_key: FL,
+        mut right_key: FR,
+        mut f: F,
+    ) -> ParallelVec<R>
+    where
+        Other: ParallelParam,
+        G: GrowthPolicy,
+        B: Storage,
+        K: Ord,
+        R: ParallelParam,
+        FL: FnMut(Param::Ref<'_>) -> K,
+        FR: FnMut(Other::Ref<'_>) -> K,
+        F: FnMut(Param::Ref<'_>, Other::Ref<'_>) -> R,
+    {
+        let right_keys: Vec<K> = other.iter().map(&mut right_key).collect();
+        let mut order: Vec<usize> = (0..other.len).collect();
+        order.sort_by(|a, b| right_keys[*a].cmp(&right_keys[*b]));
+        let mut joined = ParallelVec::new();
+        for left in 0..self.len {
+            // SAFE: `left` is in bounds of `self`, and every index in `order` is in bounds
+            // of `other`.
+            unsafe {
+                let key = left_key(self.get_unchecked(left));
+                let start = order.partition_point(|idx| right_keys[*idx] < key);
+                for idx in &order[start..] {
+                    if right_keys[*idx] != key {
+                        break;
+                    }
+                    joined.push(f(self.get_unchecked(left), other.get_unchecked(*idx)));
+                }
+            }
+        }
+        joined
+    }
+
+    /// Moves the elements in `range` to the end of `dst`, shifting the elements after the
+    /// range down to close the gap. The order of the moved elements and of the remaining
+    /// elements is preserved.
+    ///
+    /// `dst` is reserved once, and the moved elements and the remaining elements are
+    /// each moved with a single copy per field.
+    ///
+    /// # Panics
+    /// Panics if the start of the range is greater than its end, or if the end of the
+    /// range is greater than the length of the vector.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let mut src = ParallelVec::from(vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+    /// let mut dst = ParallelVec::from(vec![(0, 'z')]);
+    /// src.transfer_range(1..3, &mut dst);
+    /// assert_eq!(src.as_slices().0, &[1, 4]);
+    /// assert_eq!(dst.as_slices().0, &[0, 2, 3]);
+    /// ```
+    pub fn transfer_range<R, G, B>(&mut self, range: R, dst: &mut ParallelVec<Param, G, B>)
+    where
+        R: RangeBounds<usize>,
+        G: GrowthPolicy,
+        B: Storage,
+    {
+        let Range { start, end } = slice_range(range, self.len);
+        let count = end - start;
+        dst.reserve(count);
+        unsafe {
+            let src = Param::ptr_at(self.storage, start);
+            Param::copy_to_nonoverlapping(src, Param::ptr_at(dst.storage, dst.len), count);
+            dst.len += count;
+            Param::copy_to(Param::ptr_at(self.storage, end), src, self.len - end);
+            self.len -= count;
+            self.poison(self.len, self.len + count);
+        }
+        self.validate();
+        dst.validate();
+    }
+
+    /// Appends clones of the `count` elements starting at `src` to the end of the
+    /// vector.
+    ///
+    /// # Safety
+    /// `src` must point to `count` initialized elements that stay valid while they are
+    /// cloned, and the vector must have the capacity for `count` more elements.
+    pub(crate) unsafe fn extend_cloned(&mut self, src: Param::Ptr, count: usize)
+    where
+        Param: Clone,
+    {
+        let dst = Param::ptr_at(self.storage, self.len);
+        for idx in 0..count {
+            // The original is still owned elsewhere, so it must not be dropped here.
+            let value = ManuallyDrop::new(Param::read(Param::add(src, idx)));
+            Param::write(Param::add(dst, idx), (*value).clone());
+            // Only count the element once it has been written, so a panicking
+            // clone leaves `self` with only fully initialized elements.
+            self.len += 1;
+        }
     }
 
     /// Appends an element to the back of a collection.
@@ -138,6 +835,40 @@ impl<Param: ParallelParam> ParallelVec<Param> {
             Param::write(ptr, value);
             self.len += 1;
         }
+        self.validate();
+    }
+
+    /// Appends an element to the back of a collection, returning an error instead
+    /// of panicking or aborting if the vector needs to grow and the allocation fails.
+    pub fn try_push(&mut self, value: Param) -> Result<(), TryReserveError> {
+        self.grow_amortized(1)?;
+        unsafe {
+            let ptr = Param::ptr_at(self.storage, self.len);
+            Param::write(ptr, value);
+            self.len += 1;
+        }
+        self.validate();
+        Ok(())
+    }
+
+    /// Appends an element to the back of a collection if there is sufficient spare
+    /// capacity, otherwise the element is returned back as an error.
+    ///
+    /// Unlike [`push`], this method will never reallocate, so pointers into the
+    /// vector remain valid. On success, returns the index the element was written to.
+    ///
+    /// [`push`]: Self::push
+    pub fn push_within_capacity(&mut self, value: Param) -> Result<usize, Param> {
+        if self.len == self.capacity {
+            return Err(value);
+        }
+        unsafe {
+            let index = self.len;
+            let ptr = Param::ptr_at(self.storage, index);
+            Param::write(ptr, value);
+            self.len += 1;
+            Ok(index)
+        }
     }
 
     /// Removes the last element from the vector and returns it,
@@ -152,6 +883,8 @@ impl<Param: ParallelParam> ParallelVec<Param> {
                 let ptr = Param::ptr_at(self.storage, self.len - 1);
                 let value = Param::read(ptr);
                 self.len -= 1;
+                self.poison(self.len, self.len + 1);
+                self.validate();
                 Some(value)
             }
         }
@@ -177,108 +910,677 @@ impl<Param: ParallelParam> ParallelVec<Param> {
                 let end = Param::ptr_at(self.storage, self.len);
                 Param::copy_to_nonoverlapping(end, target_ptr, 1);
             }
+            self.poison(self.len, self.len + 1);
+            self.validate();
 
             value
         }
     }
 
-    /// Inserts a value at `index`. Moves all of the elements above
-    /// `index` up one index. This is a `O(N)` operation.
+    /// Removes and drops the elements at `indices`, filling the gaps they leave with
+    /// elements from the end of the vector.
+    ///
+    /// The indices are removed from highest to lowest with the same semantics as
+    /// [`swap_remove`], so an element that is itself being removed is never moved into
+    /// a gap. The elements that are kept below the new length stay where they are.
+    ///
+    /// This does not preserve ordering, but is `O(k)` for `k` removed elements. If you
+    /// need to preserve the element order, use [`remove_many`] instead.
     ///
     /// # Panics
-    /// This function will panic if `index` is greater than or equal to
-    /// `len()`.
-    pub fn insert(&mut self, index: usize, value: Param) {
-        if index > self.len {
-            out_of_bounds(index, self.len);
+    /// Panics if `indices` is not sorted in strictly increasing order, or if any of the
+    /// indices are out of bounds.
+    ///
+    /// [`swap_remove`]: Self::swap_remove
+    /// [`remove_many`]: Self::remove_many
+    pub fn swap_remove_many(&mut self, indices: &[usize]) {
+        for pair in indices.windows(2) {
+            assert!(
+                pair[0] < pair[1],
+                "indices are not sorted and deduplicated: {} >= {}",
+                pair[0],
+                pair[1]
+            );
         }
-        unsafe {
-            // TODO: (Performance) In the case where we do grow, this can result in redundant copying.
-            self.reserve(1);
-            let ptr = Param::ptr_at(self.storage, index);
-            Param::copy_to(ptr, Param::add(ptr, 1), self.len - index);
-            Param::write(ptr, value);
-            self.len += 1;
+        if let Some(last) = indices.last() {
+            assert_in_bounds(*last, self.len);
+        }
+        // Removing from the highest index down ensures that the last element is never
+        // one that is still waiting to be removed.
+        for idx in indices.iter().rev() {
+            drop(self.swap_remove(*idx));
         }
     }
 
-    /// Removes a value at `index`. Moves all of the elements above
-    /// `index` down one index. This is a `O(N)` operation.
+    /// Retains only the elements whose entry in `mask` is `true`, dropping the rest and
+    /// keeping the order of the retained elements.
     ///
-    /// Returns `None` if `index` is is greater than or equal to `len()`.
-    pub fn remove(&mut self, index: usize) -> Option<Param> {
-        if index >= self.len {
-            return None;
-        }
+    /// This compacts the vector in a single pass, and does not change its capacity.
+    ///
+    /// # Panics
+    /// Panics if `mask` is not the same length as the vector.
+    pub fn retain_by_mask(&mut self, mask: &[bool]) {
+        assert_eq!(
+            mask.len(),
+            self.len,
+            "mask length does not match the length of the vector"
+        );
+        self.retain_indices(|idx| mask[idx]);
+    }
+
+    /// Retains only the elements whose bit in `bitmask` is set, dropping the rest and
+    /// keeping the order of the retained elements.
+    ///
+    /// The bit for the element at `idx` is bit `idx % 64` of `bitmask[idx / 64]`, counting
+    /// from the least significant bit. Bits past the length of the vector are ignored.
+    ///
+    /// This compacts the vector in a single pass, and does not change its capacity.
+    ///
+    /// # Panics
+    /// Panics if `bitmask` has fewer bits than the length of the vector.
+    pub fn retain_by_bitmask(&mut self, bitmask: &[u64]) {
+        assert_bitmask_len(bitmask, self.len);
+        self.retain_indices(|idx| bitmask_get(bitmask, idx));
+    }
+
+    fn retain_indices<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(usize) -> bool,
+    {
+        let len = self.len;
+        // Hide the elements from the vector while they are being compacted. If dropping
+        // an element panics, the guard moves the unprocessed elements down and restores
+        // the length, so the vector never exposes a dropped or duplicated element.
+        self.len = 0;
+        let mut guard = RetainGuard {
+            vec: self,
+            read: 0,
+            write: 0,
+            len,
+        };
+        while guard.read < len {
+            let read = guard.read;
+            guard.read += 1;
+            unsafe {
+                let src = Param::ptr_at(guard.vec.storage, read);
+                if keep(read) {
+                    if read != guard.write {
+                        let dst = Param::ptr_at(guard.vec.storage, guard.write);
+                        Param::copy_to_nonoverlapping(src, dst, 1);
+                    }
+                    guard.write += 1;
+                } else {
+                    Param::drop_slice(src, 1);
+                }
+            }
+        }
+    }
+
+    /// Inserts a value at `index`. Moves all of the elements above
+    /// `index` up one index. This is a `O(N)` operation.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is greater than or equal to
+    /// `len()`.
+    pub fn insert(&mut self, index: usize, value: Param) {
+        if index > self.len {
+            out_of_bounds(index, self.len);
+        }
+        unsafe {
+            // TODO: (Performance) In the case where we do grow, this can result in redundant copying.
+            self.reserve(1);
+            let ptr = Param::ptr_at(self.storage, index);
+            Param::copy_to(ptr, Param::add(ptr, 1), self.len - index);
+            Param::write(ptr, value);
+            self.len += 1;
+        }
+        self.validate();
+    }
+
+    /// Removes a value at `index`. Moves all of the elements above
+    /// `index` down one index. This is a `O(N)` operation.
+    ///
+    /// Returns `None` if `index` is is greater than or equal to `len()`.
+    pub fn remove(&mut self, index: usize) -> Option<Param> {
+        if index >= self.len {
+            return None;
+        }
         unsafe {
             let ptr = Param::ptr_at(self.storage, index);
             let value = Param::read(ptr);
             Param::copy_to(Param::add(ptr, 1), ptr, self.len - index - 1);
             self.len -= 1;
+            self.poison(self.len, self.len + 1);
+            self.validate();
             Some(value)
         }
     }
 
+    /// Removes and drops the elements at `indices`, shifting the remaining elements down
+    /// to close the gaps while keeping their order.
+    ///
+    /// The vector is compacted in a single pass, moving each run of elements between
+    /// two removed indices with a single copy per field, so this takes `O(n)` time
+    /// regardless of how many elements are removed.
+    ///
+    /// # Panics
+    /// Panics if `indices` is not sorted in strictly increasing order, or if any of the
+    /// indices are out of bounds.
+    pub fn remove_many(&mut self, indices: &[usize]) {
+        for pair in indices.windows(2) {
+            assert!(
+                pair[0] < pair[1],
+                "indices are not sorted and deduplicated: {} >= {}",
+                pair[0],
+                pair[1]
+            );
+        }
+        if let Some(last) = indices.last() {
+            assert_in_bounds(*last, self.len);
+        }
+        let len = self.len;
+        // Hide the elements while they are being compacted, as in `retain_indices`.
+        self.len = 0;
+        let mut guard = RetainGuard {
+            vec: self,
+            read: 0,
+            write: 0,
+            len,
+        };
+        for idx in indices {
+            let run = *idx - guard.read;
+            unsafe {
+                if run > 0 && guard.read != guard.write {
+                    Param::copy_to(
+                        Param::ptr_at(guard.vec.storage, guard.read),
+                        Param::ptr_at(guard.vec.storage, guard.write),
+                        run,
+                    );
+                }
+                guard.write += run;
+                guard.read = *idx + 1;
+                Param::drop_slice(Param::ptr_at(guard.vec.storage, *idx), 1);
+            }
+        }
+    }
+
     /// Reserves capacity for at least `additional` more elements to be inserted in the
     /// given [`ParallelVec`]. The collection may reserve more space to avoid frequent
-    /// reallocations. After calling reserve, capacity will be greater than or
+    /// reallocations, as determined by its [`GrowthPolicy`]. After calling reserve, capacity will be greater than or
     /// equal to `self.len() + additional`. Does nothing if capacity is already
     /// sufficient.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
     pub fn reserve(&mut self, additional: usize) {
+        if let Err(err) = self.grow_amortized(additional) {
+            handle_reserve_error(err);
+        }
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more elements to be
+    /// inserted in the given [`ParallelVec`]. After calling `reserve_exact`, capacity
+    /// will be greater than or equal to `self.len() + additional`. Does nothing if
+    /// the capacity is already sufficient.
+    ///
+    /// Prefer [`reserve`] if future insertions are expected.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows.
+    ///
+    /// [`reserve`]: Self::reserve
+    pub fn reserve_exact(&mut self, additional: usize) {
+        if let Err(err) = self.grow_exact(additional) {
+            handle_reserve_error(err);
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be inserted
+    /// in the given [`ParallelVec`]. The collection may reserve more space to avoid
+    /// frequent reallocations. After calling `try_reserve`, capacity will be greater
+    /// than or equal to `self.len() + additional` if it returns `Ok(())`. Does nothing
+    /// if capacity is already sufficient.
+    ///
+    /// # Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned and the vector is left unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.grow_amortized(additional)
+    }
+
+    /// Tries to reserve the minimum capacity for exactly `additional` more elements to
+    /// be inserted in the given [`ParallelVec`]. After calling `try_reserve_exact`,
+    /// capacity will be greater than or equal to `self.len() + additional` if it
+    /// returns `Ok(())`. Does nothing if the capacity is already sufficient.
+    ///
+    /// Prefer [`try_reserve`] if future insertions are expected.
+    ///
+    /// # Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned and the vector is left unchanged.
+    ///
+    /// [`try_reserve`]: Self::try_reserve
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.grow_exact(additional)
+    }
+
+    fn grow_amortized(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let new_len = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if new_len <= self.capacity {
+            return Ok(());
+        }
+        let capacity = self
+            .growth
+            .grow(self.capacity, new_len)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        debug_assert!(capacity >= new_len);
+        self.grow_to(capacity)
+    }
+
+    fn grow_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let new_len = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if new_len <= self.capacity {
+            return Ok(());
+        }
+        self.grow_to(new_len)
+    }
+
+    fn grow_to(&mut self, capacity: usize) -> Result<(), TryReserveError> {
         unsafe {
-            let new_len = self.len + additional;
-            if new_len > self.capacity {
-                let capacity = new_len.next_power_of_two().max(4);
-                let dst = Param::alloc(capacity);
-                let src = self.as_mut_ptrs();
-                Param::copy_to_nonoverlapping(src, Param::as_ptr(dst), self.len);
-                Param::dealloc(&mut self.storage, self.capacity);
-                self.storage = dst;
-                self.capacity = capacity;
+            self.storage = if self.capacity == 0 {
+                Param::try_alloc(capacity, self.column_align, &self.alloc)?
+            } else {
+                Param::try_realloc(
+                    self.storage,
+                    self.capacity,
+                    capacity,
+                    self.len,
+                    self.column_align,
+                    &self.alloc,
+                )?
+            };
+        }
+        self.capacity = capacity;
+        self.validate();
+        Ok(())
+    }
+
+    /// Checks the internal invariants of the vector.
+    ///
+    /// This is a no-op unless the `debug-validate` feature is enabled in a debug build.
+    #[inline(always)]
+    pub(crate) fn validate(&self) {
+        #[cfg(all(feature = "debug-validate", debug_assertions))]
+        {
+            assert!(
+                self.len <= self.capacity,
+                "ParallelVec length exceeds its capacity: {} > {}",
+                self.len,
+                self.capacity
+            );
+            let layout = self.memory_layout();
+            let sizes = Param::field_sizes(self.capacity);
+            let mut end = 0;
+            for (offset, size) in layout.offsets().iter().zip(sizes.as_ref()) {
+                assert!(*offset >= end, "ParallelVec fields overlap");
+                end = offset + size;
+            }
+            assert!(
+                end <= layout.layout().size(),
+                "ParallelVec fields exceed the allocation"
+            );
+        }
+    }
+
+    /// Overwrites the memory of the elements in `start..end` with a poison pattern.
+    ///
+    /// This is a no-op unless the `debug-validate` feature is enabled in a debug build.
+    ///
+    /// # Safety
+    /// `start..end` must be within the capacity of the vector, and the elements in the
+    /// range must have already been moved out or dropped.
+    #[inline(always)]
+    pub(crate) unsafe fn poison(&self, start: usize, end: usize) {
+        #[cfg(all(feature = "debug-validate", debug_assertions))]
+        if self.capacity > 0 {
+            let layout = self.memory_layout();
+            let sizes = Param::field_sizes(1);
+            let base = Param::base(self.storage).as_ptr();
+            for (offset, size) in layout.offsets().iter().zip(sizes.as_ref()) {
+                base.add(offset + size * start)
+                    .write_bytes(POISON, size * (end - start));
             }
         }
+        #[cfg(not(all(feature = "debug-validate", debug_assertions)))]
+        let _ = (start, end);
     }
 }
 
-impl<Param: ParallelParam + Copy> ParallelVec<Param> {
+/// The byte pattern written over the memory of removed elements with the
+/// `debug-validate` feature.
+#[cfg(all(feature = "debug-validate", debug_assertions))]
+const POISON: u8 = 0xA5;
+
+impl<Param, Growth, A> ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam,
+    Growth: GrowthPolicy + Clone,
+    A: Storage + Clone,
+{
+    /// Creates an empty vector with the same growth policy, allocator, and column
+    /// alignment as `self`, with the exact capacity provided.
+    pub(crate) fn empty_like(&self, capacity: usize) -> Self {
+        let mut new =
+            Self::with_capacity_and_growth_policy_in(0, self.growth.clone(), self.alloc.clone());
+        new.set_column_alignment(self.column_align);
+        new.reserve_exact(capacity);
+        new
+    }
+
+    /// Splits the vector into a vector of every element for which `f` returns `true`,
+    /// and a vector of every other element, keeping the relative order of the elements.
+    ///
+    /// The predicate is called for every element before any element is moved. Each run
+    /// of consecutive elements that go to the same vector is then moved with a single
+    /// copy per field. Both vectors are allocated with exactly the capacity they need,
+    /// and have the same growth policy, allocator, and column alignment as `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let particles = ParallelVec::from(vec![(1.0f32, true), (2.0, false), (3.0, true)]);
+    /// let (alive, dead) = particles.partition(|(_, alive)| *alive);
+    /// assert_eq!(alive.as_slices().0, &[1.0, 3.0]);
+    /// assert_eq!(dead.as_slices().0, &[2.0]);
+    /// ```
+    pub fn partition<F>(mut self, mut f: F) -> (Self, Self)
+    where
+        F: FnMut(Param::Ref<'_>) -> bool,
+    {
+        let len = self.len;
+        let mask: Vec<bool> = self.iter().map(&mut f).collect();
+        let matched = mask.iter().filter(|matched| **matched).count();
+        let mut left = self.empty_like(matched);
+        let mut right = self.empty_like(len - matched);
+        unsafe {
+            let src = Param::as_ptr(self.storage);
+            let mut start = 0;
+            while start < len {
+                let side = mask[start];
+                let run = mask[start..]
+                    .iter()
+                    .position(|matched| *matched != side)
+                    .unwrap_or(len - start);
+                let dst = if side { &mut left } else { &mut right };
+                // SAFE: Each output was reserved with enough capacity for all of its
+                // elements, and every element is moved out of `self` exactly once.
+                Param::copy_to_nonoverlapping(
+                    Param::add(src, start),
+                    Param::ptr_at(dst.storage, dst.len),
+                    run,
+                );
+                dst.len += run;
+                start += run;
+            }
+            // The elements have all been moved out, so only the buffer is freed.
+            self.poison(0, len);
+            self.len = 0;
+        }
+        left.validate();
+        right.validate();
+        (left, right)
+    }
+
+    /// Merges two vectors that are both sorted by the key extracted by `f` into a single
+    /// sorted vector.
+    ///
+    /// The merge is stable: elements with equal keys keep their relative order, and the
+    /// elements of `self` are placed before the elements of `other` with an equal key.
+    /// The key of every element is computed once before any element is moved. Each run
+    /// of consecutive elements taken from the same input is then moved with a single copy
+    /// per field. The result is allocated with exactly the capacity it needs, and has the
+    /// same growth policy, allocator, and column alignment as `self`.
+    ///
+    /// If either input is not sorted, the order of the result is unspecified, but it
+    /// still contains every element of both inputs.
+    ///
+    /// # Panics
+    /// If comparing two keys panics, the elements that were not yet moved are leaked.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let index = ParallelVec::from(vec![(1, 'a'), (4, 'b'), (6, 'c')]);
+    /// let additions = ParallelVec::from(vec![(2, 'd'), (4, 'e'), (9, 'f')]);
+    /// let merged = index.merge_sorted_by_key(additions, |(key, _)| *key);
+    /// assert_eq!(merged.as_slices().0, &[1, 2, 4, 4, 6, 9]);
+    /// assert_eq!(merged.as_slices().1, &['a', 'd', 'b', 'e', 'c', 'f']);
+    /// ```
+    pub fn merge_sorted_by_key<K, F>(mut self, mut other: Self, mut f: F) -> Self
+    where
+        K: Ord,
+        F: FnMut(Param::Ref<'_>) -> K,
+    {
+        let left_keys: Vec<K> = self.iter().map(&mut f).collect();
+        let right_keys: Vec<K> = other.iter().map(&mut f).collect();
+        let (left_len, right_len) = (self.len, other.len);
+        let mut merged = self.empty_like(left_len + right_len);
+        // Hide the elements from both inputs while they are moved, so they are leaked
+        // rather than dropped twice if comparing the keys panics.
+        self.len = 0;
+        other.len = 0;
+        let (mut left, mut right) = (0, 0);
+        unsafe {
+            while left < left_len || right < right_len {
+                let (src, start, run) = if right == right_len
+                    || (left < left_len && left_keys[left] <= right_keys[right])
+                {
+                    let run = match right_keys.get(right) {
+                        Some(key) => left_keys[left..]
+                            .iter()
+                            .position(|left_key| left_key > key)
+                            .unwrap_or(left_len - left),
+                        None => left_len - left,
+                    };
+                    left += run;
+                    (&self, left - run, run)
+                } else {
+                    let run = match left_keys.get(left) {
+                        Some(key) => right_keys[right..]
+                            .iter()
+                            .position(|right_key| right_key >= key)
+                            .unwrap_or(right_len - right),
+                        None => right_len - right,
+                    };
+                    right += run;
+                    (&other, right - run, run)
+                };
+                // SAFE: The output was reserved with enough capacity for both inputs, and
+                // every element is moved out of its input exactly once.
+                Param::copy_to_nonoverlapping(
+                    Param::ptr_at(src.storage, start),
+                    Param::ptr_at(merged.storage, merged.len),
+                    run,
+                );
+                merged.len += run;
+            }
+            // The elements have all been moved out, so only the buffers are freed.
+            self.poison(0, left_len);
+            other.poison(0, right_len);
+        }
+        merged.validate();
+        merged
+    }
+
     /// Creates a [`ParallelVec`] by repeating `self` `n` times.
-    pub fn repeat(&self, n: usize) -> ParallelVec<Param> {
-        let mut new = ParallelVec::with_capacity(n * self.len);
-        let mut dst = Param::as_ptr(new.storage);
-        new.len = n * self.len;
+    ///
+    /// The result is allocated once with exactly the capacity it needs. Like
+    /// `slice::repeat`, after the first copy of `self`, the filled region is doubled on
+    /// each step by cloning from the elements that were already written.
+    ///
+    /// # Panics
+    /// Panics if the length of the result overflows.
+    pub fn repeat(&self, n: usize) -> Self
+    where
+        Param: Clone,
+    {
+        let len = self.len.checked_mul(n).expect("capacity overflow");
+        let mut new = self.empty_like(len);
+        if len == 0 {
+            return new;
+        }
         unsafe {
-            let base = Param::as_ptr(self.storage);
-            for _ in 0..n {
-                for idx in 0..self.len {
-                    let value = Param::read(Param::add(base, idx));
-                    Param::write(dst, value);
-                    dst = Param::add(dst, 1);
-                }
+            new.extend_cloned(Param::as_ptr(self.storage), self.len);
+            while new.len < len {
+                let count = new.len.min(len - new.len);
+                new.extend_cloned(Param::as_ptr(new.storage), count);
             }
         }
+        new.validate();
         new
     }
 }
 
-impl<Param: ParallelParam> Drop for ParallelVec<Param> {
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> Drop
+    for ParallelVec<Param, Growth, A>
+{
     fn drop(&mut self) {
+        self.validate();
         let end = self.len;
         // Set len to 0 first in case one of the Drop impls panics
         self.len = 0;
         unsafe {
-            self.drop_range(0, end);
-            Param::dealloc(&mut self.storage, self.capacity);
+            let _guard = DeallocOnDrop::<Param, A> {
+                storage: self.storage,
+                capacity: self.capacity,
+                column_align: self.column_align,
+                alloc: &self.alloc,
+            };
+            Param::drop_slice(Param::as_ptr(self.storage), end);
+            self.poison(0, end);
+        }
+    }
+}
+
+/// Restores the length of a vector being compacted by [`ParallelVec::retain_indices`]
+/// or [`ParallelVec::remove_many`], moving any unprocessed elements down after the retained elements.
+struct RetainGuard<'a, Param: ParallelParam, Growth: GrowthPolicy, A: Storage> {
+    vec: &'a mut ParallelVec<Param, Growth, A>,
+    read: usize,
+    write: usize,
+    len: usize,
+}
+
+impl<'a, Param: ParallelParam, Growth: GrowthPolicy, A: Storage> Drop
+    for RetainGuard<'a, Param, Growth, A>
+{
+    fn drop(&mut self) {
+        let remaining = self.len - self.read;
+        unsafe {
+            if self.read != self.write {
+                let src = Param::ptr_at(self.vec.storage, self.read);
+                let dst = Param::ptr_at(self.vec.storage, self.write);
+                Param::copy_to(src, dst, remaining);
+            }
+            self.vec.len = self.write + remaining;
+            self.vec.poison(self.vec.len, self.len);
+        }
+        self.vec.validate();
+    }
+}
+
+/// Deallocates a buffer when dropped, so that it is still freed if dropping
+/// its elements panics.
+pub(crate) struct DeallocOnDrop<'a, Param: ParallelParam, A: Storage> {
+    pub(crate) storage: Param::Storage,
+    pub(crate) capacity: usize,
+    pub(crate) column_align: usize,
+    pub(crate) alloc: &'a A,
+}
+
+impl<'a, Param: ParallelParam, A: Storage> Drop for DeallocOnDrop<'a, Param, A> {
+    fn drop(&mut self) {
+        unsafe {
+            Param::dealloc(
+                &mut self.storage,
+                self.capacity,
+                self.column_align,
+                self.alloc,
+            );
         }
     }
 }
 
+/// The size in bytes of the blocks of rows that `From<Vec<Param>>` moves into the
+/// columns at a time, small enough for a block to stay in the L1 cache.
+const DEINTERLEAVE_BLOCK_BYTES: usize = 4096;
+
 impl<Param: ParallelParam> From<Vec<Param>> for ParallelVec<Param> {
-    fn from(value: Vec<Param>) -> Self {
-        Self::from_iter(value.into_iter())
+    /// Converts a `Vec` of rows into a [`ParallelVec`], moving every field into its own
+    /// column.
+    ///
+    /// The vector is allocated once with exactly the length of `value`. The rows are
+    /// then moved in blocks of about 4 KiB: each block is moved into one column after
+    /// another, so it is only loaded into the cache once while every column is written
+    /// front to back.
+    fn from(mut value: Vec<Param>) -> Self {
+        let len = value.len();
+        let mut vec = Self::with_capacity(len);
+        let block = (DEINTERLEAVE_BLOCK_BYTES / core::mem::size_of::<Param>().max(1)).max(1);
+        unsafe {
+            // SAFE: The rows are moved out of `value` below, and moving them cannot
+            // panic, so `value` must only free its buffer.
+            value.set_len(0);
+            let src = value.as_ptr();
+            let dst = Param::as_ptr(vec.storage);
+            for start in (0..len).step_by(block) {
+                let count = block.min(len - start);
+                Param::write_rows(Param::add(dst, start), src.add(start), count);
+            }
+        }
+        vec.len = len;
+        vec.validate();
+        vec
+    }
+}
+
+impl<Param: ParallelParam, const N: usize> From<[Param; N]> for ParallelVec<Param> {
+    /// Converts an array of rows into a [`ParallelVec`], without going through a `Vec`.
+    ///
+    /// The vector is allocated once with exactly `N` capacity.
+    ///
+    /// # Example
+    /// ```rust
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let vec = ParallelVec::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// assert_eq!(vec.as_slices().0, &[1, 2, 3]);
+    /// assert_eq!(vec.capacity(), 3);
+    /// ```
+    fn from(value: [Param; N]) -> Self {
+        let mut vec = Self::with_capacity(N);
+        for (idx, item) in IntoIterator::into_iter(value).enumerate() {
+            unsafe {
+                // SAFE: The vector was allocated with the capacity for every element.
+                Param::write(Param::ptr_at(vec.storage, idx), item);
+            }
+            vec.len = idx + 1;
+        }
+        vec.validate();
+        vec
     }
 }
 
-impl<'a, Param: ParallelParam> PartialEq for ParallelVec<Param>
+impl<'a, Param: ParallelParam, Growth: GrowthPolicy, A: Storage> PartialEq
+    for ParallelVec<Param, Growth, A>
 where
     Param: 'a,
     Param::Ref<'a>: PartialEq,
@@ -287,33 +1589,99 @@ where
         if self.len != other.len {
             return false;
         }
-        if self.storage == other.storage {
-            // Pointing to the same storage. Shortcut out.
-            return true;
+        // SAFE: The items are only compared, and do not escape this function.
+        unsafe {
+            let (a, b) = (self.iter_unbounded(), other.iter_unbounded());
+            a.zip(b).all(|(a, b)| a.eq(&b))
         }
-        self.iter().zip(other.iter()).all(|(a, b)| a.eq(&b))
     }
 }
 
-impl<'a, Param: ParallelParam> Eq for ParallelVec<Param>
+impl<'a, Param: ParallelParam, Growth: GrowthPolicy, A: Storage> Eq
+    for ParallelVec<Param, Growth, A>
 where
     Param: 'a,
     Param::Ref<'a>: Eq,
 {
 }
 
-impl<'a, Param: ParallelParam> Debug for ParallelVec<Param>
+impl<'a, Param: ParallelParam, Growth: GrowthPolicy, A: Storage> PartialOrd
+    for ParallelVec<Param, Growth, A>
+where
+    Param: 'a,
+    Param::Ref<'a>: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        // SAFE: The items are only compared, and do not escape this function.
+        unsafe {
+            let (a, b) = (self.iter_unbounded(), other.iter_unbounded());
+            a.partial_cmp(b)
+        }
+    }
+}
+
+impl<'a, Param: ParallelParam, Growth: GrowthPolicy, A: Storage> Ord
+    for ParallelVec<Param, Growth, A>
+where
+    Param: 'a,
+    Param::Ref<'a>: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        // SAFE: The items are only compared, and do not escape this function.
+        unsafe {
+            let (a, b) = (self.iter_unbounded(), other.iter_unbounded());
+            a.cmp(b)
+        }
+    }
+}
+
+impl<'a, Param: ParallelParam, Growth: GrowthPolicy, A: Storage> PartialEq<[Param]>
+    for ParallelVec<Param, Growth, A>
+where
+    Param: 'a,
+    Param::Ref<'a>: PartialEq,
+{
+    fn eq(&self, other: &[Param]) -> bool {
+        if self.len != other.len() {
+            return false;
+        }
+        // SAFE: The items are only compared, and do not escape this function.
+        unsafe {
+            let b = other
+                .iter()
+                .map(|value| Param::as_ref(Param::field_ptrs(value)));
+            self.iter_unbounded().zip(b).all(|(a, b)| a.eq(&b))
+        }
+    }
+}
+
+impl<'a, 'b, Param: ParallelParam, Growth: GrowthPolicy, A: Storage> PartialEq<&'b [Param]>
+    for ParallelVec<Param, Growth, A>
+where
+    Param: 'a,
+    Param::Ref<'a>: PartialEq,
+{
+    fn eq(&self, other: &&'b [Param]) -> bool {
+        self.eq(*other)
+    }
+}
+
+impl<'a, Param: ParallelParam, Growth: GrowthPolicy, A: Storage> Debug
+    for ParallelVec<Param, Growth, A>
 where
     Param: 'a,
     Param::Ref<'a>: Debug,
 {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
         fmt.write_str("ParallelVec")?;
-        fmt.debug_list().entries(self.iter()).finish()
+        // SAFE: The items are only formatted, and do not escape this function.
+        let iter = unsafe { self.iter_unbounded() };
+        fmt.debug_list().entries(iter).finish()
     }
 }
 
-impl<'a, Param: ParallelParam> Hash for ParallelVec<Param>
+impl<'a, Param: ParallelParam, Growth: GrowthPolicy, A: Storage> Hash
+    for ParallelVec<Param, Growth, A>
 where
     Param: 'a,
     Param::Ref<'a>: Hash,
@@ -322,18 +1690,28 @@ where
     where
         H: Hasher,
     {
-        self.deref().hash(hasher);
+        self.len.hash(hasher);
+        // SAFE: The items are only hashed, and do not escape this function.
+        for item in unsafe { self.iter_unbounded() } {
+            item.hash(hasher);
+        }
     }
 }
 
-impl<Param: ParallelParam> FromIterator<Param> for ParallelVec<Param> {
+impl<Param, Growth, A> FromIterator<Param> for ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam,
+    Growth: GrowthPolicy + Default,
+    A: Storage + Default,
+{
     fn from_iter<T>(iter: T) -> Self
     where
         T: IntoIterator<Item = Param>,
     {
         let iter = iter.into_iter();
         let (min, _) = iter.size_hint();
-        let mut parallel_vec = Self::with_capacity(min);
+        let mut parallel_vec =
+            Self::with_capacity_and_growth_policy_in(min, Growth::default(), A::default());
         for item in iter {
             parallel_vec.push(item);
         }
@@ -341,22 +1719,28 @@ impl<Param: ParallelParam> FromIterator<Param> for ParallelVec<Param> {
     }
 }
 
-impl<Param: ParallelParam> IntoIterator for ParallelVec<Param> {
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> IntoIterator
+    for ParallelVec<Param, Growth, A>
+{
     type Item = Param;
-    type IntoIter = IntoIter<Param>;
+    type IntoIter = IntoIter<Param, A>;
     fn into_iter(self) -> Self::IntoIter {
-        let iter = IntoIter {
-            storage: self.storage,
-            capacity: self.capacity,
-            len: self.len,
+        let this = ManuallyDrop::new(self);
+        IntoIter {
+            storage: this.storage,
+            capacity: this.capacity,
+            column_align: this.column_align,
+            len: this.len,
             idx: 0,
-        };
-        core::mem::forget(self);
-        iter
+            // SAFE: `this` is never dropped, so the allocator is only moved once.
+            alloc: unsafe { core::ptr::read(&this.alloc) },
+        }
     }
 }
 
-impl<Param: ParallelParam> Extend<Param> for ParallelVec<Param> {
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> Extend<Param>
+    for ParallelVec<Param, Growth, A>
+{
     fn extend<T>(&mut self, iter: T)
     where
         T: IntoIterator<Item = Param>,
@@ -370,27 +1754,53 @@ impl<Param: ParallelParam> Extend<Param> for ParallelVec<Param> {
     }
 }
 
-impl<Param: ParallelParam + Clone> Clone for ParallelVec<Param> {
+impl<Param, Growth, A> Clone for ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam + Clone,
+    Growth: GrowthPolicy + Clone,
+    A: Storage + Clone,
+{
     fn clone(&self) -> Self {
-        let mut clone = Self::with_capacity(self.len);
+        let mut clone = self.empty_like(self.len);
+        clone.clone_from(self);
+        clone
+    }
+
+    /// Overwrites the contents of `self` with a clone of the contents of `source`.
+    ///
+    /// The existing allocation of `self` is reused if it has enough capacity for
+    /// all of the elements in `source`. The allocator of `self` is kept.
+    fn clone_from(&mut self, source: &Self) {
+        self.clear();
+        self.growth.clone_from(&source.growth);
+        self.set_column_alignment(source.column_align);
+        self.reserve(source.len);
         unsafe {
-            let base = Param::as_ptr(self.storage);
-            for idx in 0..self.len {
-                let value = Param::read(Param::add(base, idx));
-                clone.push(value.clone());
-            }
+            self.extend_cloned(Param::as_ptr(source.storage), source.len);
         }
-        clone
+        self.validate();
     }
 }
 
-impl<Param: ParallelParam> Default for ParallelVec<Param> {
+impl<Param, Growth, A> Default for ParallelVec<Param, Growth, A>
+where
+    Param: ParallelParam,
+    Growth: GrowthPolicy + Default,
+    A: Storage + Default,
+{
+    /// Creates an empty `ParallelVec`, equivalent to [`ParallelVec::new`].
+    ///
+    /// The vector will not allocate until elements are pushed onto it.
     fn default() -> Self {
-        Self::new()
+        Self::with_capacity_and_growth_policy_in(0, Growth::default(), A::default())
     }
 }
 
-impl<Param: ParallelParam> Deref for ParallelVec<Param> {
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> Deref
+    for ParallelVec<Param, Growth, A>
+{
+    // The `'static` lifetime never escapes: every reference handed out by
+    // `ParallelSliceMut` is tied to the borrow of the slice itself.
     type Target = ParallelSliceMut<'static, Param>;
     fn deref(&self) -> &Self::Target {
         // SAFE: Both ParallelVec and ParallelSliceMut have the same
@@ -402,46 +1812,273 @@ impl<Param: ParallelParam> Deref for ParallelVec<Param> {
     }
 }
 
-impl<Param: ParallelParam> DerefMut for ParallelVec<Param> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        // SAFE: Both ParallelVec and ParallelSliceMut have the same
-        // layout in memory due to #[repr(C)]
-        unsafe {
-            let ptr: *mut Self = self;
-            &mut *(ptr.cast::<Self::Target>())
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::ParallelVec;
+    use crate::growth::{Exact, OneAndAHalf};
+    use crate::{
+        Column, ParallelParam, ParallelSlice, ParallelSliceFromBytesError, ParallelSliceMut,
+        ParallelVecConversionError, TryReserveError,
+    };
+    use allocator_api2::alloc::{AllocError, Allocator, Global};
+    use core::alloc::Layout;
+    use core::cell::{Cell, UnsafeCell};
+    use core::cmp::Ordering;
+    use core::hash::{Hash, Hasher};
+    use core::ptr::NonNull;
+    use std::collections::hash_map::DefaultHasher;
     use std::convert::From;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
     use std::rc::Rc;
+    use std::string::{String, ToString};
     use std::vec::Vec;
 
-    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-    struct ZST;
+    /// Tracks the number of live allocations made through it.
+    #[derive(Clone, Default)]
+    struct CountingAlloc(Rc<Cell<isize>>);
 
-    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-    struct ZST2;
+    unsafe impl Allocator for CountingAlloc {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.0.set(self.0.get() + 1);
+            Global.allocate(layout)
+        }
 
-    #[test]
-    fn layouts_do_not_overlap() {
-        // Trying with both (small, large) and (large, small) to ensure nothing bleeds into anything else.
-        // This verifies we correctly chunk the slices from the larger allocations.
-        let mut vec_ab = ParallelVec::new();
-        let mut vec_ba = ParallelVec::new();
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.0.set(self.0.get() - 1);
+            Global.deallocate(ptr, layout)
+        }
+    }
 
-        fn ab(v: usize) -> (u8, f64) {
-            (v as u8, 200.0 + ((v as f64) / 200.0))
+    struct FailingAlloc;
+
+    unsafe impl Allocator for FailingAlloc {
+        fn allocate(&self, _: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            Err(AllocError)
         }
 
-        fn ba(v: usize) -> (f64, u8) {
-            (15.0 + ((v as f64) / 16.0), (200 - v) as u8)
+        unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {
+            unreachable!()
         }
+    }
 
-        // Combined with the tests inside, also verifies that we are copying the data on grow correctly.
+    /// Hands out a single fixed buffer that it does not own.
+    struct FixedAlloc<'a>(&'a UnsafeCell<[u64; 16]>, Cell<bool>);
+
+    unsafe impl Allocator for FixedAlloc<'_> {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            let size = core::mem::size_of::<[u64; 16]>();
+            if self.1.replace(true) || layout.size() > size || layout.align() > 8 {
+                return Err(AllocError);
+            }
+            let ptr = core::ptr::slice_from_raw_parts_mut(self.0.get().cast::<u8>(), size);
+            // SAFE: The pointer comes from a reference, so it is not null.
+            Ok(unsafe { NonNull::new_unchecked(ptr) })
+        }
+
+        unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {
+            self.1.set(false);
+        }
+    }
+
+    /// Counts the number of times it has been dropped, and panics on drop if `panics`
+    /// is set.
+    #[derive(Clone)]
+    struct DropCounter {
+        drops: Rc<Cell<usize>>,
+        panics: bool,
+    }
+
+    impl DropCounter {
+        fn new(drops: &Rc<Cell<usize>>, panics: bool) -> Self {
+            Self {
+                drops: drops.clone(),
+                panics,
+            }
+        }
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+            if self.panics {
+                panic!("DropCounter panicked");
+            }
+        }
+    }
+
+    fn counters(
+        drops: &Rc<Cell<usize>>,
+        n: usize,
+        panics_at: Option<usize>,
+    ) -> ParallelVec<(DropCounter, usize)> {
+        (0..n)
+            .map(|idx| (DropCounter::new(drops, panics_at == Some(idx)), idx))
+            .collect()
+    }
+
+    #[allow(clippy::upper_case_acronyms)]
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+    struct ZST;
+
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+    struct ZST2;
+
+    #[test]
+    fn memory_layout() {
+        let layout = <(u8, u64, u16)>::layout_for_capacity(3);
+        assert_eq!(layout.offsets(), &[0, 8, 32]);
+        assert_eq!(layout.layout(), Layout::from_size_align(38, 8).unwrap());
+    }
+
+    #[test]
+    fn memory_layout_aligned() {
+        let layout = <(u8, u64, u16)>::layout_for_capacity_aligned(3, 64);
+        assert_eq!(layout.offsets(), &[0, 64, 128]);
+        assert_eq!(layout.layout(), Layout::from_size_align(192, 64).unwrap());
+        assert!(<(u8, u64)>::try_layout_for_capacity_aligned(3, 48).is_none());
+        assert!(<(u8, u64)>::try_layout_for_capacity_aligned(3, 0).is_none());
+        assert!(<(u64, u64)>::try_layout_for_capacity_aligned(3, 0).is_none());
+        assert!(<(u64, u64)>::try_layout_for_capacity_aligned(3, 3).is_none());
+        assert!(<(u64, u64)>::try_layout_for_capacity_aligned(3, 24).is_none());
+        assert!(<(u64,)>::try_layout_for_capacity_aligned(0, 24).is_none());
+    }
+
+    #[test]
+    fn test_column_alignment() {
+        let is_aligned = |vec: &ParallelVec<(u8, u32, ZST, u16)>| {
+            let (a, b, _, d) = vec.as_slices();
+            a.as_ptr().align_offset(64) == 0
+                && b.as_ptr().align_offset(64) == 0
+                && d.as_ptr().align_offset(64) == 0
+        };
+        let mut vec = ParallelVec::new();
+        vec.push((0u8, 0u32, ZST, 0u16));
+        vec.set_column_alignment(64);
+        assert_eq!(vec.column_alignment(), 64);
+        assert!(is_aligned(&vec));
+        for i in 1..100 {
+            vec.push((i as u8, i as u32, ZST, i as u16));
+        }
+        assert!(is_aligned(&vec));
+        vec.truncate(10);
+        vec.shrink_to_fit();
+        assert!(is_aligned(&vec));
+        assert!(vec
+            .memory_layout()
+            .offsets()
+            .iter()
+            .all(|offset| offset % 64 == 0));
+
+        let clone = vec.clone();
+        assert_eq!(clone.column_alignment(), 64);
+        assert!(is_aligned(&clone));
+        assert_eq!(clone, vec);
+
+        vec.set_column_alignment(1);
+        assert_eq!(vec, clone);
+        let (a, b, _, d) = vec.as_slices();
+        assert_eq!(a, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(b, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(d, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_cache_line_padding() {
+        let mut vec: ParallelVec<(u8, u16, u64)> = ParallelVec::with_capacity(5);
+        vec.set_column_alignment(crate::CACHE_LINE_SIZE);
+        let layout = vec.memory_layout();
+        let sizes = <(u8, u16, u64)>::field_sizes(vec.capacity());
+        let lines = |offset: usize, size: usize| {
+            offset / crate::CACHE_LINE_SIZE
+                ..(offset + size + crate::CACHE_LINE_SIZE - 1) / crate::CACHE_LINE_SIZE
+        };
+        let (a, b, c) = (
+            lines(layout.offsets()[0], sizes[0]),
+            lines(layout.offsets()[1], sizes[1]),
+            lines(layout.offsets()[2], sizes[2]),
+        );
+        assert!(a.end <= b.start && b.end <= c.start);
+        assert_eq!(layout.layout().size() % crate::CACHE_LINE_SIZE, 0);
+        assert_eq!(c.end * crate::CACHE_LINE_SIZE, layout.layout().size());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_column_alignment_panics() {
+        let mut vec: ParallelVec<(u8, u32)> = ParallelVec::new();
+        vec.set_column_alignment(3);
+    }
+
+    #[test]
+    fn slice_from_bytes() {
+        let mut src = ParallelVec::with_capacity(8);
+        for i in 0..5 {
+            src.push((i as u8, i as u64 * 3, i as u16));
+        }
+        let size = <(u8, u64, u16)>::layout_for_capacity(8).layout.size();
+        let bytes = unsafe {
+            core::slice::from_raw_parts(<(u8, u64, u16)>::base(src.storage).as_ptr(), size)
+        };
+
+        let slice = unsafe { ParallelSlice::<(u8, u64, u16)>::from_bytes(bytes, 8, 5) }.unwrap();
+        assert_eq!(slice.len(), 5);
+        assert_eq!(slice.as_slices(), src.as_slices());
+
+        let err = |bytes, capacity, len| unsafe {
+            ParallelSlice::<(u8, u64, u16)>::from_bytes(bytes, capacity, len).err()
+        };
+        assert_eq!(
+            err(bytes, 4, 5),
+            Some(ParallelSliceFromBytesError::LengthExceedsCapacity)
+        );
+        assert_eq!(
+            err(bytes, usize::MAX, 5),
+            Some(ParallelSliceFromBytesError::CapacityOverflow)
+        );
+        assert_eq!(
+            err(&bytes[..size - 1], 8, 5),
+            Some(ParallelSliceFromBytesError::BufferTooSmall { required: size })
+        );
+        assert_eq!(
+            err(&bytes[1..], 4, 4),
+            Some(ParallelSliceFromBytesError::Misaligned { align: 8 })
+        );
+    }
+
+    #[test]
+    fn foreign_buffer() {
+        let buffer = UnsafeCell::new([0u64; 16]);
+        let alloc = FixedAlloc(&buffer, Cell::new(false));
+        let mut vec = ParallelVec::with_capacity_in(8, &alloc);
+        for i in 0..8 {
+            vec.push((i as u8, i as u64 * 3));
+        }
+        assert!(matches!(
+            vec.try_push((8, 24)),
+            Err(TryReserveError::AllocError { .. })
+        ));
+        assert_eq!(vec.len(), 8);
+        assert_eq!(vec.index(7), (&7, &21));
+        drop(vec);
+        assert!(!alloc.1.get());
+    }
+
+    #[test]
+    fn layouts_do_not_overlap() {
+        // Trying with both (small, large) and (large, small) to ensure nothing bleeds into anything else.
+        // This verifies we correctly chunk the slices from the larger allocations.
+        let mut vec_ab = ParallelVec::new();
+        let mut vec_ba = ParallelVec::new();
+
+        fn ab(v: usize) -> (u8, f64) {
+            (v as u8, 200.0 + ((v as f64) / 200.0))
+        }
+
+        fn ba(v: usize) -> (f64, u8) {
+            (15.0 + ((v as f64) / 16.0), (200 - v) as u8)
+        }
+
+        // Combined with the tests inside, also verifies that we are copying the data on grow correctly.
         for i in 0..100 {
             vec_ab.push(ab(i));
             let (a, b) = vec_ab.as_slices();
@@ -479,6 +2116,23 @@ mod tests {
         assert!(src.is_empty());
     }
 
+    #[test]
+    fn test_default_in_derived_struct() {
+        #[derive(Default)]
+        struct Entities {
+            data: ParallelVec<(u32, f32)>,
+            next_id: u32,
+        }
+
+        let entities = Entities::default();
+        assert!(entities.data.is_empty());
+        assert_eq!(
+            entities.data.capacity(),
+            ParallelVec::<(u32, f32)>::new().capacity()
+        );
+        assert_eq!(entities.next_id, 0);
+    }
+
     #[test]
     fn test_with_capacity() {
         let src: ParallelVec<(i32, i32, u64)> = ParallelVec::with_capacity(1000);
@@ -507,6 +2161,118 @@ mod tests {
         assert!(src.capacity() >= 10000);
     }
 
+    #[test]
+    fn test_reserve_exact() {
+        let mut src = ParallelVec::new();
+        src.push((0, 0, 0, 0));
+        src.reserve_exact(10);
+        assert_eq!(src.len(), 1);
+        assert_eq!(src.capacity(), 11);
+        src.reserve_exact(5);
+        assert_eq!(src.capacity(), 11);
+    }
+
+    #[test]
+    fn test_try_reserve() {
+        let mut src = ParallelVec::new();
+        src.push((0u64, 0u64));
+        assert_eq!(src.try_reserve(100), Ok(()));
+        assert!(src.capacity() >= 101);
+        assert_eq!(src.try_reserve_exact(200), Ok(()));
+        assert_eq!(src.capacity(), 201);
+        assert_eq!(
+            src.try_reserve(usize::MAX),
+            Err(TryReserveError::CapacityOverflow)
+        );
+        assert_eq!(
+            src.try_reserve_exact(usize::MAX / 4),
+            Err(TryReserveError::CapacityOverflow)
+        );
+        assert_eq!(src.len(), 1);
+        assert_eq!(src.capacity(), 201);
+        assert_eq!(src.index(0), (&0, &0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reserve_panics() {
+        let mut src = ParallelVec::new();
+        src.push((0u64, 0u64));
+        src.reserve(usize::MAX);
+    }
+
+    #[test]
+    fn test_growth_policy_exact() {
+        let mut src = ParallelVec::with_growth_policy(Exact);
+        for i in 0..10 {
+            src.push((i, i));
+            assert_eq!(src.capacity(), i + 1);
+        }
+        src.reserve(5);
+        assert_eq!(src.capacity(), 15);
+    }
+
+    #[test]
+    fn test_growth_policy_one_and_a_half() {
+        let mut src = ParallelVec::with_capacity_and_growth_policy(10, OneAndAHalf);
+        src.extend((0..10).map(|i| (i, i)));
+        assert_eq!(src.capacity(), 10);
+        src.push((10, 10));
+        assert_eq!(src.capacity(), 15);
+        src.reserve(100);
+        assert_eq!(src.capacity(), 111);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, (0..11).collect::<Vec<_>>().as_slice());
+        assert_eq!(b, (0..11).collect::<Vec<_>>().as_slice());
+    }
+
+    #[test]
+    fn test_new_in() {
+        let alloc = CountingAlloc::default();
+        let mut src = ParallelVec::new_in(alloc.clone());
+        assert_eq!(alloc.0.get(), 0);
+        src.extend((0..100).map(|i| (i, i as f64)));
+        assert_eq!(alloc.0.get(), 1);
+        let clone = src.clone();
+        assert_eq!(alloc.0.get(), 2);
+        core::mem::drop(clone);
+        src.shrink_to_fit();
+        assert_eq!(alloc.0.get(), 1);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, (0..100).collect::<Vec<_>>().as_slice());
+        assert_eq!(b[99], 99.0);
+        let mut iter = src.into_iter();
+        assert_eq!(iter.next(), Some((0, 0.0)));
+        assert_eq!(alloc.0.get(), 1);
+        core::mem::drop(iter);
+        assert_eq!(alloc.0.get(), 0);
+    }
+
+    #[test]
+    fn test_with_capacity_in() {
+        let alloc = CountingAlloc::default();
+        let src: ParallelVec<(u8, u32), _, _> = ParallelVec::with_capacity_in(10, alloc.clone());
+        assert_eq!(src.capacity(), 10);
+        assert_eq!(alloc.0.get(), 1);
+        core::mem::drop(src);
+        assert_eq!(alloc.0.get(), 0);
+    }
+
+    #[test]
+    fn test_try_reserve_alloc_error() {
+        let mut src = ParallelVec::new_in(FailingAlloc);
+        assert!(matches!(
+            src.try_push((1u32, 2u64)),
+            Err(TryReserveError::AllocError { .. })
+        ));
+        assert!(matches!(
+            src.try_reserve(10),
+            Err(TryReserveError::AllocError { .. })
+        ));
+        assert_eq!(src.len(), 0);
+        assert_eq!(src.capacity(), 0);
+    }
+
     #[test]
     fn test_clone() {
         let mut src = ParallelVec::new();
@@ -519,6 +2285,31 @@ mod tests {
         assert_eq!(dst.index(1), (&3.0, &4.0));
     }
 
+    #[test]
+    fn test_clone_from_reuses_allocation() {
+        let mut src = ParallelVec::new();
+        src.push((1.0, 2.0));
+        src.push((3.0, 4.0));
+
+        let mut dst = ParallelVec::with_capacity(4);
+        dst.push((5.0, 6.0));
+        dst.push((7.0, 8.0));
+        dst.push((9.0, 10.0));
+        let ptrs = dst.as_parallel_slice_mut().as_mut_ptrs();
+        dst.clone_from(&src);
+        assert_eq!(dst.as_parallel_slice_mut().as_mut_ptrs(), ptrs);
+        assert_eq!(dst.capacity(), 4);
+        assert_eq!(dst.len(), 2);
+        assert_eq!(dst.index(0), (&1.0, &2.0));
+        assert_eq!(dst.index(1), (&3.0, &4.0));
+
+        let mut small = ParallelVec::new();
+        small.push((0.0, 0.0));
+        small.clone_from(&dst);
+        assert_eq!(small.len(), 2);
+        assert_eq!(small.index(1), (&3.0, &4.0));
+    }
+
     #[test]
     fn test_works_with_zsts() {
         let mut src = ParallelVec::new();
@@ -533,6 +2324,228 @@ mod tests {
         assert_eq!(src.len(), 4);
     }
 
+    #[test]
+    fn test_all_zsts_never_allocate() {
+        let mut src = ParallelVec::<(ZST, ZST2), _, _>::with_capacity_in(10, FailingAlloc);
+        assert_eq!(src.capacity(), usize::MAX);
+        for _ in 0..100 {
+            src.push((ZST, ZST2));
+        }
+        assert_eq!(
+            src.try_reserve(usize::MAX),
+            Err(TryReserveError::CapacityOverflow)
+        );
+        src.shrink_to_fit();
+        assert_eq!(src.capacity(), usize::MAX);
+        assert_eq!(src.len(), 100);
+        assert_eq!(src.pop(), Some((ZST, ZST2)));
+        assert_eq!(src.into_iter().count(), 99);
+    }
+
+    #[test]
+    fn test_zst_columns_are_dangling() {
+        let mut src = ParallelVec::new();
+        src.push((1u8, ZST, 20u64));
+        src.reserve(100);
+        let (_, zst, _) = src.as_slices();
+        assert_eq!(
+            zst.as_ptr(),
+            NonNull::<ZST>::dangling().as_ptr() as *const ZST
+        );
+        src.shrink_to(0);
+        assert_eq!(src.index(0), (&1, &ZST, &20u64));
+    }
+
+    #[test]
+    fn test_single_field() {
+        let mut src = ParallelVec::new();
+        for i in 0..10 {
+            src.push((i,));
+        }
+        assert_eq!(src.len(), 10);
+        assert_eq!(src.index(3), (&3,));
+        assert_eq!(src.as_slices(), (&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9][..],));
+        assert_eq!(src.pop(), Some((9,)));
+        src.as_parallel_slice_mut().sort_by(|(a,), (b,)| b.cmp(a));
+        assert_eq!(src.first(), Some((&8,)));
+    }
+
+    #[test]
+    fn test_all_arities() {
+        macro_rules! test_arity {
+            ($($vs:expr),+) => {{
+                let mut vec = ParallelVec::new();
+                vec.push(($($vs,)+));
+                vec.push(($($vs,)+));
+                vec.as_parallel_slice_mut().swap(0, 1);
+                assert_eq!(vec.len(), 2);
+                assert!(vec.pop().is_some());
+                assert_eq!(vec.into_iter().count(), 1);
+            }};
+        }
+
+        test_arity!(1u8);
+        test_arity!(1u8, 2u16);
+        test_arity!(1u8, 2u16, 3u32);
+        test_arity!(1u8, 2u16, 3u32, 4u64);
+        test_arity!(1u8, 2u16, 3u32, 4u64, 5u8);
+        test_arity!(1u8, 2u16, 3u32, 4u64, 5u8, 6u16);
+        test_arity!(1u8, 2u16, 3u32, 4u64, 5u8, 6u16, 7u32);
+        test_arity!(1u8, 2u16, 3u32, 4u64, 5u8, 6u16, 7u32, 8u64);
+        test_arity!(1u8, 2u16, 3u32, 4u64, 5u8, 6u16, 7u32, 8u64, 9u8);
+        test_arity!(1u8, 2u16, 3u32, 4u64, 5u8, 6u16, 7u32, 8u64, 9u8, 10u16);
+        test_arity!(1u8, 2u16, 3u32, 4u64, 5u8, 6u16, 7u32, 8u64, 9u8, 10u16, 11u32);
+        test_arity!(1u8, 2u16, 3u32, 4u64, 5u8, 6u16, 7u32, 8u64, 9u8, 10u16, 11u32, 12u64);
+        test_arity!(1u8, 2u16, 3u32, 4u64, 5u8, 6u16, 7u32, 8u64, 9u8, 10u16, 11u32, 12u64, 13u8);
+        test_arity!(
+            1u8, 2u16, 3u32, 4u64, 5u8, 6u16, 7u32, 8u64, 9u8, 10u16, 11u32, 12u64, 13u8, 14u16
+        );
+        test_arity!(
+            1u8, 2u16, 3u32, 4u64, 5u8, 6u16, 7u32, 8u64, 9u8, 10u16, 11u32, 12u64, 13u8, 14u16,
+            15u32
+        );
+        test_arity!(
+            1u8, 2u16, 3u32, 4u64, 5u8, 6u16, 7u32, 8u64, 9u8, 10u16, 11u32, 12u64, 13u8, 14u16,
+            15u32, 16u64
+        );
+    }
+
+    #[test]
+    fn test_borrowed_fields() {
+        let names = [
+            std::string::String::from("a"),
+            std::string::String::from("b"),
+        ];
+        let mut src = ParallelVec::new();
+        for (idx, name) in names.iter().enumerate() {
+            src.push((name.as_str(), idx));
+        }
+        assert_eq!(src.index(1), (&"b", &1));
+        assert_eq!(src, src.clone());
+        let (names, _) = src.as_slices();
+        assert_eq!(names, &["a", "b"]);
+    }
+
+    #[test]
+    fn test_truncate_panic_safety() {
+        let drops = Rc::new(Cell::new(0));
+        let mut src = counters(&drops, 6, Some(3));
+        let result = catch_unwind(AssertUnwindSafe(|| src.truncate(2)));
+        assert!(result.is_err());
+        assert_eq!(src.len(), 2);
+        // The values after the panicking one are still dropped.
+        assert_eq!(drops.get(), 4);
+        drop(src);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn test_drop_panic_safety() {
+        let drops = Rc::new(Cell::new(0));
+        let allocs = CountingAlloc::default();
+        let mut src = ParallelVec::new_in(allocs.clone());
+        for idx in 0..4 {
+            src.push((DropCounter::new(&drops, idx == 1), idx));
+        }
+        assert!(catch_unwind(AssertUnwindSafe(|| drop(src))).is_err());
+        assert_eq!(drops.get(), 4);
+        assert_eq!(allocs.0.get(), 0);
+    }
+
+    #[test]
+    fn test_into_iter_drop_panic_safety() {
+        let drops = Rc::new(Cell::new(0));
+        let allocs = CountingAlloc::default();
+        let mut src = ParallelVec::new_in(allocs.clone());
+        for idx in 0..4 {
+            src.push((DropCounter::new(&drops, idx == 2), idx));
+        }
+        let mut iter = src.into_iter();
+        drop(iter.next());
+        assert!(catch_unwind(AssertUnwindSafe(|| drop(iter))).is_err());
+        assert_eq!(drops.get(), 4);
+        assert_eq!(allocs.0.get(), 0);
+    }
+
+    #[test]
+    fn test_extend_panic_safety() {
+        let drops = Rc::new(Cell::new(0));
+        let mut src = counters(&drops, 2, None);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            src.extend((0..4).map(|idx| {
+                if idx == 3 {
+                    panic!("iterator panicked");
+                }
+                (DropCounter::new(&drops, false), idx)
+            }))
+        }));
+        assert!(result.is_err());
+        assert_eq!(src.len(), 5);
+        drop(src);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn test_fill_with_panic_safety() {
+        let drops = Rc::new(Cell::new(0));
+        let mut src = counters(&drops, 4, None);
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            src.as_parallel_slice_mut().fill_with(|| {
+                calls += 1;
+                if calls == 3 {
+                    panic!("closure panicked");
+                }
+                (DropCounter::new(&drops, false), 0)
+            })
+        }));
+        assert!(result.is_err());
+        // The first two values were replaced and dropped.
+        assert_eq!(drops.get(), 2);
+        assert_eq!(src.len(), 4);
+        drop(src);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn test_set_panic_safety() {
+        let drops = Rc::new(Cell::new(0));
+        let mut src = counters(&drops, 3, Some(1));
+        let value = (DropCounter::new(&drops, false), 10);
+        assert!(catch_unwind(AssertUnwindSafe(|| src
+            .as_parallel_slice_mut()
+            .set(1, value)))
+        .is_err());
+        assert_eq!(drops.get(), 1);
+        assert_eq!(*src.index(1).1, 10);
+        drop(src);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn test_clone_does_not_drop_source() {
+        let drops = Rc::new(Cell::new(0));
+        let src = counters(&drops, 3, None);
+        let dst = src.clone();
+        assert_eq!(drops.get(), 0);
+        drop(src);
+        drop(dst);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn test_clone_from_drops_previous_elements() {
+        let drops = Rc::new(Cell::new(0));
+        let src = counters(&drops, 2, None);
+        let mut dst = counters(&drops, 3, None);
+        dst.clone_from(&src);
+        assert_eq!(drops.get(), 3);
+        assert_eq!(dst.len(), 2);
+        drop(src);
+        drop(dst);
+        assert_eq!(drops.get(), 7);
+    }
+
     #[test]
     fn test_push() {
         let mut src = ParallelVec::new();
@@ -541,6 +2554,195 @@ mod tests {
         assert_eq!(src.len(), 1);
     }
 
+    #[test]
+    fn test_try_push() {
+        let mut src = ParallelVec::new();
+        assert_eq!(src.try_push((1, 2)), Ok(()));
+        assert_eq!(src.try_push((3, 4)), Ok(()));
+        assert_eq!(src.len(), 2);
+        assert!(src.capacity() >= 2);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3]);
+        assert_eq!(b, &[2, 4]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-validate", debug_assertions))]
+    fn test_try_push_validates() {
+        // Every push re-checks the column layout, including the ones that reallocate.
+        let mut src: ParallelVec<(u8, u64, u16)> = ParallelVec::new();
+        for idx in 0..100u8 {
+            assert_eq!(src.try_push((idx, idx as u64, idx as u16)), Ok(()));
+        }
+        let (a, b, c) = src.as_slices();
+        assert!(a.iter().copied().eq(0..100));
+        assert!(b.iter().copied().eq(0..100));
+        assert!(c.iter().copied().eq(0..100));
+    }
+
+    #[test]
+    fn test_as_ptrs() {
+        let mut src = ParallelVec::from(vec![(1u32, 2u8), (3, 4), (5, 6)]);
+        let (a, b) = src.as_ptrs();
+        assert_eq!(unsafe { (*a.add(2), *b.add(1)) }, (5, 4));
+        let (a_mut, b_mut) = src.as_parallel_slice_mut().as_mut_ptrs();
+        assert_eq!(
+            (a, b),
+            (a_mut.as_ptr().cast_const(), b_mut.as_ptr().cast_const())
+        );
+        let slice = src.get(1..).unwrap();
+        assert_eq!(slice.as_ptrs().0, unsafe { a.add(1) });
+    }
+
+    #[test]
+    fn test_parallel_slice_views() {
+        fn sum(slice: ParallelSlice<'_, (u32, u8)>) -> u32 {
+            slice.iter().map(|(a, b)| *a + *b as u32).sum()
+        }
+        fn double(mut slice: ParallelSliceMut<'_, (u32, u8)>) {
+            for (a, _) in slice.iter_mut() {
+                *a *= 2;
+            }
+        }
+
+        let mut src = ParallelVec::from(vec![(1u32, 2u8), (3, 4), (5, 6)]);
+        assert_eq!(sum(src.as_parallel_slice()), 21);
+        let slice = src.get(1..).unwrap();
+        let copy = slice;
+        assert_eq!(sum(slice) + sum(copy), 36);
+
+        let mut view = src.as_parallel_slice_mut();
+        double(view.as_parallel_slice_mut());
+        double(view.get_mut(2..).unwrap());
+        assert_eq!(sum(view.as_parallel_slice()), 40);
+        assert_eq!(sum(ParallelSlice::from(view)), 40);
+        assert_eq!(src.as_slices().0, &[2, 6, 20]);
+    }
+
+    #[test]
+    fn test_parallel_slice_view_ranges() {
+        let mut src = ParallelVec::from(vec![(1u32, 2u8), (3, 4), (5, 6)]);
+        let (start, end) = (3, 1);
+        let view = src.as_parallel_slice();
+        assert!(view.get(start..end).is_none());
+        assert!(view.get(2..=usize::MAX).is_none());
+        assert_eq!(view.get(3..3).unwrap().len(), 0);
+        assert_eq!(view.get(3..).unwrap().len(), 0);
+        let tail = view.get(1..).unwrap();
+        assert_eq!(tail.get(2..).unwrap().len(), 0);
+        assert_eq!(tail.index(1..2).as_slices().0, &[5]);
+
+        let mut view = src.as_parallel_slice_mut();
+        assert!(view.get(start..end).is_none());
+        assert!(view.get_mut(start..end).is_none());
+        assert!(view.get_mut(start..=end).is_none());
+        let mut tail = view.get_mut(2..).unwrap();
+        assert_eq!(tail.get_mut(1..1).unwrap().len(), 0);
+        assert_eq!(tail.index_mut(1..).len(), 0);
+
+        let empty = ParallelVec::<(u32, u8)>::new();
+        assert_eq!(empty.get(..).unwrap().len(), 0);
+        assert_eq!(empty.index(0..).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 3 but ends at 1")]
+    fn test_parallel_slice_view_reversed_range_panics() {
+        let src = ParallelVec::from(vec![(1u32, 2u8), (3, 4), (5, 6)]);
+        let (start, end) = (3, 1);
+        src.as_parallel_slice().index(start..end);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 3 but ends at 1")]
+    fn test_parallel_slice_view_reversed_range_mut_panics() {
+        let mut src = ParallelVec::from(vec![(1u32, 2u8), (3, 4), (5, 6)]);
+        let (start, end) = (3, 1);
+        src.as_parallel_slice_mut().index_mut(start..end);
+    }
+
+    #[test]
+    fn test_to_parallel_vec() {
+        let mut src: ParallelVec<(u32, String, ZST), Exact> =
+            ParallelVec::with_growth_policy(Exact);
+        src.extend((0..4u32).map(|i| (i, i.to_string(), ZST)));
+        let copy = src.get(1..3).unwrap().to_parallel_vec();
+        assert_eq!(copy.as_slices().0, &[1, 2]);
+        assert_eq!(copy.as_slices().1, &["1", "2"]);
+        assert_eq!(copy.capacity(), 2);
+        let copy = src.to_parallel_vec();
+        assert_eq!(copy.as_slices().1, src.as_slices().1);
+        assert!(src.get(1..1).unwrap().to_parallel_vec().is_empty());
+    }
+
+    #[test]
+    fn test_select_columns() {
+        let mut src: ParallelVec<(u32, String, u8, ZST)> = (0..4u32)
+            .map(|i| (i, i.to_string(), i as u8 * 2, ZST))
+            .collect();
+        let view = src.select::<(Column<2>, Column<0>, Column<2>)>();
+        assert_eq!(view.len(), 4);
+        assert_eq!(view.index(3), (&6, &3, &6));
+
+        let mut slice = src.as_parallel_slice_mut();
+        let mut view = slice.select_mut::<(Column<0>, Column<3>)>();
+        for (a, _) in view.iter_mut() {
+            *a += 10;
+        }
+        view.get_mut(1..).unwrap().reverse();
+        assert_eq!(src.as_slices().0, &[10, 13, 12, 11]);
+        assert_eq!(src.as_slices().1, &["0", "1", "2", "3"]);
+
+        let slice = src.get(1..3).unwrap();
+        assert_eq!(slice.select::<(Column<1>,)>().as_slices().0, &["1", "2"]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_split_columns_mut() {
+        let mut src: ParallelVec<(u32, String, u64)> =
+            (0..100u32).map(|i| (i, i.to_string(), 0)).collect();
+        let mut slice = src.as_parallel_slice_mut();
+        let (mut left, mut right) =
+            slice.split_columns_mut::<(Column<0>,), (Column<2>, Column<1>)>();
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                for (a,) in left.iter_mut() {
+                    *a *= 2;
+                }
+            });
+            scope.spawn(|| {
+                for (c, b) in right.iter_mut() {
+                    *c = b.parse().unwrap();
+                }
+            });
+        });
+        assert_eq!(src.as_slices().0[99], 198);
+        assert_eq!(src.as_slices().2[99], 99);
+    }
+
+    #[test]
+    fn test_push_within_capacity() {
+        let mut src = ParallelVec::with_capacity(2);
+        let ptrs = src.as_parallel_slice_mut().as_mut_ptrs();
+        assert_eq!(src.push_within_capacity((1, 2)), Ok(0));
+        assert_eq!(src.push_within_capacity((3, 4)), Ok(1));
+        assert_eq!(src.push_within_capacity((5, 6)), Err((5, 6)));
+        assert_eq!(src.len(), 2);
+        assert_eq!(src.capacity(), 2);
+        assert!(src.as_parallel_slice_mut().as_mut_ptrs() == ptrs);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3]);
+        assert_eq!(b, &[2, 4]);
+    }
+
+    #[test]
+    fn test_push_within_capacity_empty() {
+        let mut src = ParallelVec::new();
+        assert_eq!(src.push_within_capacity((1, 2)), Err((1, 2)));
+        assert_eq!(src.len(), 0);
+    }
+
     #[test]
     fn test_pop() {
         let mut src = ParallelVec::new();
@@ -581,175 +2783,1022 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn test_insert_panics() {
-        let mut src = ParallelVec::new();
-        src.insert(0, (1, 2));
-        src.insert(0, (3, 4));
-        src.insert(1, (4, 5));
-        src.insert(20, (4, 5));
+    #[should_panic]
+    fn test_insert_panics() {
+        let mut src = ParallelVec::new();
+        src.insert(0, (1, 2));
+        src.insert(0, (3, 4));
+        src.insert(1, (4, 5));
+        src.insert(20, (4, 5));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        assert_eq!(src.remove(1), Some((3, 4)));
+        assert_eq!(src.remove(0), Some((1, 2)));
+        assert_eq!(src.len(), 0);
+        assert_eq!(src.remove(5), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-validate", debug_assertions))]
+    fn test_poison_removed_elements() {
+        let mut src: ParallelVec<(u32, u64)> = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        src.push((5, 6));
+        assert_eq!(src.pop(), Some((5, 6)));
+        unsafe {
+            let (a, b) = <(u32, u64)>::ptr_at(src.storage, 2);
+            assert_eq!(a.read(), 0xA5A5A5A5);
+            assert_eq!(b.read(), 0xA5A5A5A5A5A5A5A5);
+        }
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        src.push((5, 6));
+        src.push((7, 8));
+        let mut iter = src.iter();
+        assert_eq!(iter.next(), Some((&1, &2)));
+        assert_eq!(iter.next(), Some((&3, &4)));
+        assert_eq!(iter.next(), Some((&5, &6)));
+        assert_eq!(iter.next(), Some((&7, &8)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        // Shouldn't have removed any of them
+        assert_eq!(src.len(), 4);
+    }
+
+    #[test]
+    fn test_iters() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8), (9, 10), (11, 12)]);
+        let (mut a, mut b) = src.iters();
+        assert_eq!(a.next(), Some(&1));
+        assert_eq!(a.next(), Some(&3));
+        assert_eq!(a.next(), Some(&5));
+        assert_eq!(a.next(), Some(&7));
+        assert_eq!(a.next(), Some(&9));
+        assert_eq!(a.next(), Some(&11));
+        assert_eq!(a.next(), None);
+        assert_eq!(a.next(), None);
+        assert_eq!(b.next(), Some(&2));
+        assert_eq!(b.next(), Some(&4));
+        assert_eq!(b.next(), Some(&6));
+        assert_eq!(b.next(), Some(&8));
+        assert_eq!(b.next(), Some(&10));
+        assert_eq!(b.next(), Some(&12));
+        assert_eq!(b.next(), None);
+        assert_eq!(b.next(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        src.push((5, 6));
+        src.push((7, 8));
+        let mut iter = src.iter_mut();
+        assert_eq!(iter.next(), Some((&mut 1, &mut 2)));
+        assert_eq!(iter.next(), Some((&mut 3, &mut 4)));
+        assert_eq!(iter.next(), Some((&mut 5, &mut 6)));
+        assert_eq!(iter.next(), Some((&mut 7, &mut 8)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        // Shouldn't have removed any of them
+        assert_eq!(src.len(), 4);
+    }
+
+    #[test]
+    fn test_shrink_to() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.reserve(1000);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() >= 1000);
+        src.shrink_to(200);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= 200);
+        src.shrink_to(100);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= 100);
+        src.shrink_to(10);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= 10);
+        src.shrink_to(1);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() >= 4);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 5, 7]);
+        assert_eq!(b, &[2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_realloc_moves_fields() {
+        fn row(v: usize) -> (u8, f64, u16, u64) {
+            (v as u8, v as f64 / 2.0, (v * 3) as u16, (v * 7) as u64)
+        }
+
+        let mut src = ParallelVec::new();
+        src.extend((0..10).map(row));
+        src.reserve(1000);
+        src.extend((10..20).map(row));
+        assert!(src.capacity() >= 1000);
+        src.shrink_to(30);
+        assert_eq!(src.capacity(), 30);
+        src.shrink_to_fit();
+        assert_eq!(src.capacity(), 20);
+        src.reserve_exact(5);
+        assert_eq!(src.capacity(), 25);
+        assert_eq!(src.len(), 20);
+        for (idx, value) in src.iter().enumerate() {
+            let (a, b, c, d) = row(idx);
+            assert_eq!(value, (&a, &b, &c, &d));
+        }
+    }
+
+    #[test]
+    fn test_shrink_to_empty() {
+        let mut src: ParallelVec<(u8, u64)> = ParallelVec::new();
+        src.shrink_to(0);
+        src.shrink_to_fit();
+        assert_eq!(src.capacity(), 0);
+
+        src.extend(vec![(1, 2), (3, 4)]);
+        src.clear();
+        src.shrink_to_fit();
+        assert_eq!(src.capacity(), 0);
+        src.push((5, 6));
+        assert_eq!(src.index(0), (&5, &6));
+    }
+
+    #[test]
+    fn test_shrink_to_is_noop_when_minimal() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.shrink_to_fit();
+        let ptrs = src.as_parallel_slice_mut().as_mut_ptrs();
+        src.shrink_to_fit();
+        src.shrink_to(2);
+        src.shrink_to(100);
+        assert!(src.as_parallel_slice_mut().as_mut_ptrs() == ptrs);
+        assert_eq!(src.capacity(), 4);
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.reserve(1000);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() >= 1000);
+        src.shrink_to_fit();
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= src.len());
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 5, 7]);
+        assert_eq!(b, &[2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[1, 3, 5, 7]);
+            assert_eq!(b, &[2, 4, 6, 8]);
+        }
+        src.truncate(2);
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[1, 3]);
+            assert_eq!(b, &[2, 4]);
+            assert_eq!(src.len(), 2);
+            assert!(src.capacity() >= 4);
+        }
+    }
+
+    #[test]
+    fn test_truncate_drops() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        src.extend(vec![
+            (rc.clone(), rc.clone()),
+            (rc.clone(), rc.clone()),
+            (rc.clone(), rc.clone()),
+            (rc.clone(), rc.clone()),
+        ]);
+        assert_eq!(Rc::strong_count(&rc), 9);
+        src.truncate(1);
+        assert_eq!(Rc::strong_count(&rc), 3);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[1, 3, 5, 7]);
+            assert_eq!(b, &[2, 4, 6, 8]);
+        }
+        src.as_parallel_slice_mut().reverse();
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[7, 5, 3, 1]);
+            assert_eq!(b, &[8, 6, 4, 2]);
+        }
+    }
+
+    #[test]
+    fn test_sort_by() {
+        let mut src: ParallelVec<(i32, String)> = [3, 1, 2, 5, 4, 0]
+            .iter()
+            .map(|&i| (i, i.to_string()))
+            .collect();
+        src.as_parallel_slice_mut()
+            .sort_by(|(a, _), (b, _)| a.cmp(b));
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(b, &["0", "1", "2", "3", "4", "5"]);
+
+        src.as_parallel_slice_mut()
+            .sort_by(|(a, _), (b, _)| b.cmp(a));
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[5, 4, 3, 2, 1, 0]);
+        assert_eq!(b, &["5", "4", "3", "2", "1", "0"]);
+    }
+
+    #[test]
+    fn test_sort_by_is_stable() {
+        let keys = [2, 0, 1, 0, 2, 1, 1, 0, 2, 0];
+        let mut src: ParallelVec<(i32, usize)> = keys
+            .iter()
+            .enumerate()
+            .map(|(idx, &key)| (key, idx))
+            .collect();
+        src.as_parallel_slice_mut()
+            .sort_by(|(a, _), (b, _)| a.cmp(b));
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[0, 0, 0, 0, 1, 1, 1, 2, 2, 2]);
+        assert_eq!(b, &[1, 3, 7, 9, 2, 5, 6, 0, 4, 8]);
+    }
+
+    #[test]
+    fn test_sort_unstable_by_moves_each_element_once() {
+        let rc = Rc::new(());
+        let mut src: ParallelVec<(u32, Rc<()>, [u64; 8])> = (0..32u32)
+            .map(|i| (i * 7 % 32, rc.clone(), [i as u64; 8]))
+            .collect();
+        src.as_parallel_slice_mut()
+            .sort_unstable_by(|(a, _, _), (b, _, _)| a.cmp(b));
+        let (keys, _, rows) = src.as_slices();
+        assert!(keys.iter().enumerate().all(|(idx, key)| *key == idx as u32));
+        assert!(keys
+            .iter()
+            .zip(rows)
+            .all(|(key, row)| row.iter().all(|value| *value * 7 % 32 == *key as u64)));
+        assert_eq!(Rc::strong_count(&rc), 33);
+        drop(src);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_sort_by_column() {
+        let mut src: ParallelVec<(f32, u32, u8)> = (0..20u32)
+            .map(|i| (i as f32, (i * 7) % 5, (i % 3) as u8))
+            .collect();
+        src.as_parallel_slice_mut().sort_by_column::<1>();
+        let (a, b, _) = src.as_slices();
+        assert!(b.windows(2).all(|w| w[0] <= w[1]));
+        // Stable, so elements with equal keys keep their original order.
+        assert!(a
+            .windows(2)
+            .zip(b.windows(2))
+            .all(|(a, b)| b[0] != b[1] || a[0] < a[1]));
+
+        src.as_parallel_slice_mut().sort_unstable_by_column::<2>();
+        let (a, b, c) = src.as_slices();
+        assert!(c.windows(2).all(|w| w[0] <= w[1]));
+        assert!(a
+            .iter()
+            .zip(b)
+            .zip(c)
+            .all(|((a, b), c)| *b == (*a as u32 * 7) % 5 && *c == (*a as u32 % 3) as u8));
+    }
+
+    #[test]
+    fn test_sort_by_cached_key() {
+        let calls = Cell::new(0);
+        let keys = [2, 0, 1, 0, 2, 1, 1, 0, 2, 0];
+        let mut src: ParallelVec<(String, usize)> = keys
+            .iter()
+            .enumerate()
+            .map(|(idx, key)| (key.to_string(), idx))
+            .collect();
+        src.as_parallel_slice_mut().sort_by_cached_key(|(key, _)| {
+            calls.set(calls.get() + 1);
+            key.parse::<u32>().unwrap()
+        });
+        assert_eq!(calls.get(), keys.len());
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &["0", "0", "0", "0", "1", "1", "1", "2", "2", "2"]);
+        assert_eq!(b, &[1, 3, 7, 9, 2, 5, 6, 0, 4, 8]);
+    }
+
+    #[test]
+    fn test_select_nth_unstable_by() {
+        let mut src: ParallelVec<(u32, String)> = (0..25u32)
+            .map(|i| ((i * 7) % 25, ((i * 7) % 25).to_string()))
+            .collect();
+        let mut slice = src.as_parallel_slice_mut();
+        let (before, (key, name), after) =
+            slice.select_nth_unstable_by(10, |(a, _), (b, _)| a.cmp(b));
+        assert_eq!(*key, 10);
+        assert_eq!(name, "10");
+        assert_eq!(before.len(), 10);
+        assert_eq!(after.len(), 14);
+        assert!(before
+            .iter()
+            .all(|(key, name)| *key < 10 && *name == key.to_string()));
+        assert!(after
+            .iter()
+            .all(|(key, name)| *key > 10 && *name == key.to_string()));
+
+        let (before, (key, _), after) = slice.select_nth_unstable_by_key(0, |(key, _)| *key);
+        assert_eq!(*key, 0);
+        assert!(before.is_empty());
+        assert_eq!(after.len(), 24);
+        let (_, (key, _), after) = slice.select_nth_unstable_by_key(24, |(key, _)| *key);
+        assert_eq!(*key, 24);
+        assert!(after.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_select_nth_unstable_by_panics() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        src.as_parallel_slice_mut()
+            .select_nth_unstable_by_key(2, |(a, _)| *a);
+    }
+
+    #[test]
+    fn test_argsort() {
+        let src = ParallelVec::from(vec![(3, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+        assert_eq!(src.argsort_by(|(a, _), (b, _)| a.cmp(b)), vec![1, 3, 2, 0]);
+        assert_eq!(
+            src.argsort_by_key(|(_, c)| core::cmp::Reverse(*c)),
+            vec![3, 2, 1, 0]
+        );
+        assert_eq!(src.as_slices().0, &[3, 1, 2, 1]);
+
+        let slice = src.get(1..).unwrap();
+        assert_eq!(slice.argsort_by_key(|(a, _)| *a), vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn test_argsort_as() {
+        let src = ParallelVec::from(vec![(3, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+        let order = src.argsort_by_as::<u32, _>(|(a, _), (b, _)| a.cmp(b));
+        assert_eq!(order, vec![1, 3, 2, 0]);
+        assert_eq!(
+            src.argsort_by_key_as::<u8, _, _>(|(_, c)| core::cmp::Reverse(*c)),
+            vec![3, 2, 1, 0]
+        );
+        let mut dst = src.clone();
+        dst.as_parallel_slice_mut().apply_permutation_as(&order);
+        assert_eq!(dst.as_slices().1, &['b', 'd', 'c', 'a']);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in the index type")]
+    fn test_argsort_as_overflow() {
+        let src: ParallelVec<(u8,)> = (0..=u8::MAX).chain([0]).map(|a| (a,)).collect();
+        src.argsort_by_key_as::<u8, _, _>(|(a,)| *a);
+    }
+
+    #[test]
+    fn test_apply_permutation() {
+        let mut src: ParallelVec<(u32, String)> = (0..8u32).map(|i| (i, i.to_string())).collect();
+        src.as_parallel_slice_mut()
+            .apply_permutation(&[3, 0, 7, 1, 6, 2, 5, 4]);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[3, 0, 7, 1, 6, 2, 5, 4]);
+        assert_eq!(b, &["3", "0", "7", "1", "6", "2", "5", "4"]);
+
+        let order = src.argsort_by_key(|(a, _)| *a);
+        src.as_parallel_slice_mut().apply_permutation(&order);
+        assert_eq!(src.as_slices().0, &[0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "indices are not a permutation")]
+    fn test_apply_permutation_panics_on_duplicates() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        src.as_parallel_slice_mut().apply_permutation(&[0, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "indices are not a permutation")]
+    fn test_apply_permutation_panics_out_of_bounds() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        src.as_parallel_slice_mut().apply_permutation(&[0, 1, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_apply_permutation_panics_on_length() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        src.as_parallel_slice_mut().apply_permutation(&[0, 1]);
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let src = ParallelVec::from(vec![(1, 'a'), (3, 'b'), (3, 'c'), (5, 'd'), (8, 'e')]);
+        assert_eq!(src.binary_search_by(|(a, _)| a.cmp(&5)), Ok(3));
+        assert_eq!(src.binary_search_by(|(a, _)| a.cmp(&0)), Err(0));
+        assert_eq!(src.binary_search_by(|(a, _)| a.cmp(&9)), Err(5));
+        assert!(matches!(
+            src.binary_search_by_key(&3, |(a, _)| *a),
+            Ok(1..=2)
+        ));
+        assert_eq!(src.binary_search_by_key(&4, |(a, _)| *a), Err(3));
+        assert_eq!(src.binary_search_column::<1>(&'e'), Ok(4));
+        assert_eq!(src.binary_search_column::<0>(&2), Err(1));
+
+        let slice = src.get(1..4).unwrap();
+        assert_eq!(slice.binary_search_by_key(&5, |(a, _)| *a), Ok(2));
+        assert_eq!(slice.binary_search_column::<0>(&8), Err(3));
+
+        let empty: ParallelVec<(u8, u8)> = ParallelVec::new();
+        assert_eq!(empty.binary_search_by(|_| Ordering::Less), Err(0));
+    }
+
+    #[test]
+    fn test_is_sorted() {
+        let mut src = ParallelVec::from(vec![(1, 4.0), (2, 3.0), (2, 2.0), (5, 1.0)]);
+        assert!(src.is_sorted_by(|(a, _), (b, _)| a <= b));
+        assert!(!src.is_sorted_by(|(a, _), (b, _)| a < b));
+        assert!(src.is_sorted_by_key(|(a, _)| *a));
+        assert!(!src.is_sorted_by_key(|(_, b)| *b));
+        assert!(src.is_sorted_column::<0>());
+        assert!(!src.is_sorted_column::<1>());
+
+        src.as_parallel_slice_mut().sort_by_key(|(_, b)| *b as i32);
+        assert!(src.is_sorted_column::<1>());
+        assert!(src.get(1..3).unwrap().is_sorted_column::<1>());
+
+        let empty: ParallelVec<(f32, u8)> = ParallelVec::new();
+        assert!(empty.is_sorted_by(|_, _| false));
+        assert!(empty.is_sorted_column::<0>());
+    }
+
+    #[test]
+    fn test_partition() {
+        let rc = Rc::new(());
+        let src: ParallelVec<(u32, Rc<()>)> = (0..20u32).map(|i| (i, rc.clone())).collect();
+        let (even, odd) = src.partition(|(i, _)| (*i % 4) < 2);
+        assert_eq!(even.as_slices().0, &[0, 1, 4, 5, 8, 9, 12, 13, 16, 17]);
+        assert_eq!(odd.as_slices().0, &[2, 3, 6, 7, 10, 11, 14, 15, 18, 19]);
+        assert_eq!(even.capacity(), 10);
+        assert_eq!(Rc::strong_count(&rc), 21);
+        drop((even, odd));
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let mut src = ParallelVec::from(vec![(1u8, 2u64), (3, 4)]);
+        src.set_column_alignment(64);
+        let (all, none) = src.partition(|_| true);
+        assert_eq!(all.len(), 2);
+        assert_eq!(all.column_alignment(), 64);
+        assert!(none.is_empty());
+        assert_eq!(none.capacity(), 0);
+    }
+
+    #[test]
+    fn test_gather() {
+        let src: ParallelVec<(u32, String, ZST)> =
+            (0..6u32).map(|i| (i, i.to_string(), ZST)).collect();
+        let gathered = src.gather(&[5, 0, 3, 3]);
+        let (a, b, _) = gathered.as_slices();
+        assert_eq!(a, &[5, 0, 3, 3]);
+        assert_eq!(b, &["5", "0", "3", "3"]);
+        assert_eq!(gathered.capacity(), 4);
+
+        let slice = src.get(2..).unwrap();
+        assert_eq!(slice.gather(&[1, 0]).as_slices().0, &[3, 2]);
+        assert!(src.gather(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gather_panics() {
+        let src = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        src.gather(&[0, 2]);
+    }
+
+    #[test]
+    fn test_merge_sorted_by_key() {
+        let counter = Rc::new(());
+        let left: ParallelVec<(u32, usize, Rc<()>)> = [1, 3, 3, 5, 8]
+            .iter()
+            .enumerate()
+            .map(|(idx, key)| (*key, idx, counter.clone()))
+            .collect();
+        let right: ParallelVec<(u32, usize, Rc<()>)> = [0, 3, 4, 9]
+            .iter()
+            .enumerate()
+            .map(|(idx, key)| (*key, idx + 10, counter.clone()))
+            .collect();
+        let merged = left.merge_sorted_by_key(right, |(key, _, _)| *key);
+        assert_eq!(merged.as_slices().0, &[0, 1, 3, 3, 3, 4, 5, 8, 9]);
+        assert_eq!(merged.as_slices().1, &[10, 0, 1, 2, 11, 12, 3, 4, 13]);
+        assert_eq!(merged.capacity(), 9);
+        assert_eq!(Rc::strong_count(&counter), 10);
+        drop(merged);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let empty: ParallelVec<(u32, ZST)> = ParallelVec::new();
+        let right = ParallelVec::from(vec![(2, ZST), (7, ZST)]);
+        let merged = empty.merge_sorted_by_key(right, |(key, _)| *key);
+        assert_eq!(merged.as_slices().0, &[2, 7]);
+        let merged = merged.merge_sorted_by_key(ParallelVec::new(), |(key, _)| *key);
+        assert_eq!(merged.as_slices().0, &[2, 7]);
+    }
+
+    #[test]
+    fn test_join_by_key() {
+        let left = ParallelVec::from(vec![(2u32, 'a'), (1, 'b'), (2, 'c'), (4, 'd')]);
+        let right: ParallelVec<(String, u32)> = [3u32, 2, 1, 2]
+            .iter()
+            .map(|key| (key.to_string(), *key * 10))
+            .collect();
+        let joined: ParallelVec<(char, String, u32)> = left.join_by_key(
+            &right,
+            |(key, _)| *key,
+            |(_, value)| *value / 10,
+            |(_, c), (s, value)| (*c, s.clone(), *value),
+        );
+        assert_eq!(joined.as_slices().0, &['a', 'a', 'b', 'c', 'c']);
+        assert_eq!(joined.as_slices().1, &["2", "2", "1", "2", "2"]);
+        assert_eq!(joined.as_slices().2, &[20, 20, 10, 20, 20]);
+
+        let empty: ParallelVec<(u32, ZST)> = ParallelVec::new();
+        let joined: ParallelVec<(u32,)> = left.join_by_key(
+            &empty,
+            |(key, _)| *key,
+            |(key, _)| *key,
+            |(key, _), _| (*key,),
+        );
+        assert!(joined.is_empty());
+    }
+
+    #[test]
+    fn test_remove_many() {
+        let counter = Rc::new(());
+        let mut src: ParallelVec<(usize, Rc<()>, ZST)> =
+            (0..8usize).map(|i| (i, counter.clone(), ZST)).collect();
+        src.remove_many(&[0, 2, 3, 7]);
+        assert_eq!(src.as_slices().0, &[1, 4, 5, 6]);
+        assert_eq!(Rc::strong_count(&counter), 5);
+        assert_eq!(src.capacity(), 8);
+
+        src.remove_many(&[]);
+        assert_eq!(src.as_slices().0, &[1, 4, 5, 6]);
+        src.remove_many(&[0, 1, 2, 3]);
+        assert!(src.is_empty());
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn test_swap_remove_many() {
+        let counter = Rc::new(());
+        let mut src: ParallelVec<(usize, Rc<()>, ZST)> =
+            (0..8usize).map(|i| (i, counter.clone(), ZST)).collect();
+        src.swap_remove_many(&[1, 3, 6, 7]);
+        assert_eq!(src.as_slices().0, &[0, 4, 2, 5]);
+        assert_eq!(Rc::strong_count(&counter), 5);
+
+        let mut expected: Vec<usize> = src.as_slices().0.to_vec();
+        expected.swap_remove(2);
+        expected.swap_remove(0);
+        src.swap_remove_many(&[0, 2]);
+        assert_eq!(src.as_slices().0, expected.as_slice());
+
+        src.swap_remove_many(&[0, 1]);
+        assert!(src.is_empty());
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "indices are not sorted and deduplicated")]
+    fn test_swap_remove_many_panics_on_unsorted() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        src.swap_remove_many(&[2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "indices are not sorted and deduplicated")]
+    fn test_remove_many_panics_on_unsorted() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        src.remove_many(&[1, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remove_many_panics_out_of_bounds() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        src.remove_many(&[0, 3]);
+    }
+
+    #[test]
+    fn test_transfer_range() {
+        let counter = Rc::new(());
+        let mut src: ParallelVec<(usize, Rc<()>, ZST)> =
+            (0..6usize).map(|i| (i, counter.clone(), ZST)).collect();
+        let mut dst = ParallelVec::new();
+        src.transfer_range(2..4, &mut dst);
+        assert_eq!(src.as_slices().0, &[0, 1, 4, 5]);
+        assert_eq!(dst.as_slices().0, &[2, 3]);
+
+        src.transfer_range(..=0, &mut dst);
+        src.transfer_range(1.., &mut dst);
+        src.transfer_range(.., &mut dst);
+        assert!(src.is_empty());
+        assert_eq!(dst.as_slices().0, &[2, 3, 0, 4, 5, 1]);
+        assert_eq!(Rc::strong_count(&counter), 7);
+        drop(dst);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_transfer_range_panics() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        let mut dst = ParallelVec::new();
+        src.transfer_range(1..3, &mut dst);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let vec = ParallelVec::from_fn(5, |i| (i * 2, i.to_string(), ZST));
+        assert_eq!(vec.as_slices().0, &[0, 2, 4, 6, 8]);
+        assert_eq!(vec.as_slices().1, &["0", "1", "2", "3", "4"]);
+        assert_eq!(vec.capacity(), 5);
+        assert!(ParallelVec::from_fn(0, |i| (i,)).is_empty());
+    }
+
+    #[test]
+    fn test_from_elem() {
+        let counter = Rc::new(());
+        let vec = ParallelVec::from_elem((7u8, counter.clone(), ZST), 4);
+        assert_eq!(vec.as_slices().0, &[7, 7, 7, 7]);
+        assert_eq!(vec.capacity(), 4);
+        assert_eq!(Rc::strong_count(&counter), 5);
+        let empty = crate::parallel_vec![(1u8, counter.clone()); 0];
+        assert!(empty.is_empty());
+        assert_eq!(Rc::strong_count(&counter), 5);
+
+        let vec = crate::parallel_vec![(1, "a".to_string()); 3];
+        assert_eq!(vec.as_slices().1, &["a", "a", "a"]);
+        let vec: ParallelVec<(u8, char)> = crate::parallel_vec![];
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let counter = Rc::new(());
+        let rows: Vec<(u16, Rc<()>, ZST, String)> = (0..100u16)
+            .map(|i| (i, counter.clone(), ZST, i.to_string()))
+            .collect();
+        let vec = ParallelVec::from(rows);
+        assert_eq!(vec.len(), 100);
+        assert_eq!(vec.capacity(), 100);
+        assert_eq!(Rc::strong_count(&counter), 101);
+        let (a, _, _, d) = vec.as_slices();
+        assert!(a
+            .iter()
+            .zip(d)
+            .enumerate()
+            .all(|(i, (a, d))| *a as usize == i && *d == i.to_string()));
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 1);
+        assert!(ParallelVec::from(Vec::<(u8, u8)>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_from_vec_partial_block() {
+        let drops = Rc::new(Cell::new(0));
+        let block = super::DEINTERLEAVE_BLOCK_BYTES / core::mem::size_of::<(DropCounter, usize)>();
+        let len = 3 * block + 7;
+        let rows: Vec<_> = (0..len)
+            .map(|idx| (DropCounter::new(&drops, false), idx))
+            .collect();
+        let vec = ParallelVec::from(rows);
+        assert_eq!(drops.get(), 0);
+        assert_eq!(vec.len(), len);
+        assert!(vec.as_slices().1.iter().copied().eq(0..len));
+        drop(vec);
+        assert_eq!(drops.get(), len);
+    }
+
+    #[test]
+    fn test_into_vec() {
+        let counter = Rc::new(());
+        let vec: ParallelVec<(u16, Rc<()>, ZST)> =
+            (0..10u16).map(|i| (i, counter.clone(), ZST)).collect();
+        let rows = vec.into_vec();
+        assert_eq!(rows.len(), 10);
+        assert!(rows.iter().enumerate().all(|(i, row)| row.0 as usize == i));
+        assert_eq!(Rc::strong_count(&counter), 11);
+        drop(rows);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let rows = vec![(1, "a".to_string()), (2, "b".to_string())];
+        assert_eq!(ParallelVec::from(rows.clone()).into_vec(), rows);
+        assert!(ParallelVec::<(u8,)>::new().into_vec().is_empty());
+    }
+
+    #[test]
+    fn test_into_vecs() {
+        let counter = Rc::new(());
+        let vec: ParallelVec<(u16, Rc<()>, ZST, String)> = (0..5u16)
+            .map(|i| (i, counter.clone(), ZST, i.to_string()))
+            .collect();
+        let (a, b, c, d) = vec.into_vecs();
+        assert_eq!(a, vec![0, 1, 2, 3, 4]);
+        assert_eq!(b.len(), 5);
+        assert_eq!(c.len(), 5);
+        assert_eq!(d, vec!["0", "1", "2", "3", "4"]);
+        assert_eq!(Rc::strong_count(&counter), 6);
+        drop(b);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let vecs = (vec![1, 2, 3], vec!['a', 'b', 'c']);
+        let vec = ParallelVec::try_from(vecs.clone()).unwrap();
+        assert_eq!(vec.into_vecs(), vecs);
+        let (a, b) = ParallelVec::<(u8, u8)>::new().into_vecs();
+        assert!(a.is_empty() && b.is_empty());
+    }
+
+    #[test]
+    fn test_from_array() {
+        let counter = Rc::new(());
+        let vec = ParallelVec::from([
+            (1u8, counter.clone(), ZST),
+            (2, counter.clone(), ZST),
+            (3, counter.clone(), ZST),
+        ]);
+        assert_eq!(vec.as_slices().0, &[1, 2, 3]);
+        assert_eq!(vec.capacity(), 3);
+        assert_eq!(Rc::strong_count(&counter), 4);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn test_leak() {
+        let vec: ParallelVec<(u32, String, ZST)> =
+            (0..3u32).map(|i| (i, i.to_string(), ZST)).collect();
+        let (numbers, strings, zsts) = vec.leak();
+        numbers[1] = 10;
+        assert_eq!(numbers, &[0, 10, 2]);
+        assert_eq!(strings, &["0", "1", "2"]);
+        assert_eq!(zsts.len(), 3);
+        let (empty,) = ParallelVec::<(u8,)>::new().leak();
+        assert!(empty.is_empty());
+
+        // Keep the leaked buffer reachable from a static, so Miri does not report it.
+        static LEAKED: std::sync::Mutex<Option<(&[u32], &[String])>> = std::sync::Mutex::new(None);
+        *LEAKED.lock().unwrap() = Some((numbers, strings));
+    }
+
+    #[test]
+    fn test_spare_capacity_mut() {
+        let mut vec = ParallelVec::from(vec![(1u32, "a".to_string())]);
+        vec.reserve_exact(2);
+        let (numbers, strings) = vec.spare_capacity_mut();
+        assert_eq!(numbers.len(), 2);
+        numbers[0].write(2);
+        strings[0].write("b".to_string());
+        unsafe { vec.set_len(2) };
+        assert_eq!(vec.as_slices().0, &[1, 2]);
+        assert_eq!(vec.as_slices().1, &["a", "b"]);
+
+        let mut empty = ParallelVec::<(u8, ZST)>::new();
+        assert!(empty.spare_capacity_mut().0.is_empty());
+    }
+
+    #[test]
+    fn test_parallel_vec_macro() {
+        let vec = crate::parallel_vec![(1, "a".to_string(), ZST), (2, "b".to_string(), ZST)];
+        assert_eq!(vec.as_slices().0, &[1, 2]);
+        assert_eq!(vec.as_slices().1, &["a", "b"]);
+        assert_eq!(vec.capacity(), 2);
+        let vec = crate::parallel_vec![(1, 'a'), (2, 'b'), (3, 'c'),];
+        assert_eq!(vec, ParallelVec::from(vec![(1, 'a'), (2, 'b'), (3, 'c')]));
+        assert_eq!(vec.capacity(), 3);
+        assert_eq!(ParallelVec::from([(1u8,); 0]).capacity(), 0);
+    }
+
+    #[test]
+    fn test_concat() {
+        let a = ParallelVec::from(vec![(1u8, 'a'), (2, 'b')]);
+        let b: ParallelVec<(u8, char)> = ParallelVec::new();
+        let c = ParallelVec::from(vec![(3u8, 'c')]);
+        let joined = ParallelVec::concat(&[a.clone(), b, c.clone()]);
+        assert_eq!(joined.as_slices().0, &[1, 2, 3]);
+        assert_eq!(joined.as_slices().1, &['a', 'b', 'c']);
+        assert_eq!(joined.capacity(), 3);
+        let empty: [ParallelVec<(u8, char)>; 0] = [];
+        assert!(ParallelVec::concat(&empty).is_empty());
+
+        let counter = Rc::new(());
+        let d = ParallelVec::from(vec![(String::from("d"), counter.clone())]);
+        let joined = ParallelVec::concat(&[d.clone(), d]);
+        assert_eq!(joined.as_slices().0, &["d", "d"]);
+        assert_eq!(Rc::strong_count(&counter), 3);
+        drop(joined);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let counter = Rc::new(());
+        let vecs = (0..3usize).map(|i| {
+            (0..i)
+                .map(|j| (i * 10 + j, counter.clone(), ZST))
+                .collect::<ParallelVec<_>>()
+        });
+        let joined = ParallelVec::concat_iter(vecs);
+        assert_eq!(joined.as_slices().0, &[10, 20, 21]);
+        assert_eq!(joined.capacity(), 3);
+        assert_eq!(Rc::strong_count(&counter), 4);
+        drop(joined);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn test_select_by_mask() {
+        let src: ParallelVec<(u32, String)> = (0..6u32).map(|i| (i, i.to_string())).collect();
+        let selected = src.select_by_mask(&[true, false, false, true, true, false]);
+        assert_eq!(selected.as_slices().0, &[0, 3, 4]);
+        assert_eq!(selected.as_slices().1, &["0", "3", "4"]);
+
+        let selected = src.get(1..).unwrap().select_by_bitmask(&[0b10110]);
+        assert_eq!(selected.as_slices().0, &[2, 3, 5]);
+        assert!(src.select_by_bitmask(&[0]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "mask length does not match")]
+    fn test_select_by_mask_panics() {
+        let src = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        src.select_by_mask(&[true]);
     }
 
     #[test]
-    fn test_remove() {
-        let mut src = ParallelVec::new();
-        src.push((1, 2));
-        src.push((3, 4));
-        assert_eq!(src.remove(1), Some((3, 4)));
-        assert_eq!(src.remove(0), Some((1, 2)));
-        assert_eq!(src.len(), 0);
-        assert_eq!(src.remove(5), None);
+    fn test_retain_by_mask() {
+        let counter = Rc::new(());
+        let mut src: ParallelVec<(u32, Rc<()>, ZST)> =
+            (0..6u32).map(|i| (i, counter.clone(), ZST)).collect();
+        src.retain_by_mask(&[false, true, true, false, true, false]);
+        assert_eq!(src.as_slices().0, &[1, 2, 4]);
+        assert_eq!(Rc::strong_count(&counter), 4);
+        assert_eq!(src.capacity(), 6);
+
+        src.retain_by_bitmask(&[u64::MAX ^ 0b10]);
+        assert_eq!(src.as_slices().0, &[1, 4]);
+        assert_eq!(Rc::strong_count(&counter), 3);
+
+        let mut bits: ParallelVec<(usize,)> = (0..130usize).map(|i| (i,)).collect();
+        bits.retain_by_bitmask(&[1, 1 << 63, 0b11]);
+        assert_eq!(bits.as_slices().0, &[0, 127, 128, 129]);
     }
 
     #[test]
-    fn test_iter() {
-        let mut src = ParallelVec::new();
-        src.push((1, 2));
-        src.push((3, 4));
-        src.push((5, 6));
-        src.push((7, 8));
-        let mut iter = src.iter();
-        assert_eq!(iter.next(), Some((&1, &2)));
-        assert_eq!(iter.next(), Some((&3, &4)));
-        assert_eq!(iter.next(), Some((&5, &6)));
-        assert_eq!(iter.next(), Some((&7, &8)));
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next(), None);
-        // Shouldn't have removed any of them
-        assert_eq!(src.len(), 4);
+    #[should_panic(expected = "bitmask has fewer bits than elements")]
+    fn test_retain_by_bitmask_panics() {
+        let mut src: ParallelVec<(usize,)> = (0..65usize).map(|i| (i,)).collect();
+        src.retain_by_bitmask(&[u64::MAX]);
     }
 
     #[test]
-    fn test_iters() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8), (9, 10), (11, 12)]);
-        let (mut a, mut b) = src.iters();
-        assert_eq!(a.next(), Some(&1));
-        assert_eq!(a.next(), Some(&3));
-        assert_eq!(a.next(), Some(&5));
-        assert_eq!(a.next(), Some(&7));
-        assert_eq!(a.next(), Some(&9));
-        assert_eq!(a.next(), Some(&11));
-        assert_eq!(a.next(), None);
-        assert_eq!(a.next(), None);
-        assert_eq!(b.next(), Some(&2));
-        assert_eq!(b.next(), Some(&4));
-        assert_eq!(b.next(), Some(&6));
-        assert_eq!(b.next(), Some(&8));
-        assert_eq!(b.next(), Some(&10));
-        assert_eq!(b.next(), Some(&12));
-        assert_eq!(b.next(), None);
-        assert_eq!(b.next(), None);
+    #[cfg(feature = "std")]
+    fn test_retain_by_mask_panic_safety() {
+        struct PanicOnDrop(u32);
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                if self.0 == 2 && !std::thread::panicking() {
+                    panic!("dropped");
+                }
+            }
+        }
+
+        let mut src: ParallelVec<(u32, PanicOnDrop)> =
+            (0..5u32).map(|i| (i, PanicOnDrop(i))).collect();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            src.retain_by_mask(&[false, true, false, true, false]);
+        }));
+        assert!(result.is_err());
+        // The element that panicked is gone, and the unprocessed elements are kept.
+        assert_eq!(src.as_slices().0, &[1, 3, 4]);
+        for (_, value) in src.iter_mut() {
+            value.0 = 0;
+        }
     }
 
     #[test]
-    fn test_iter_mut() {
-        let mut src = ParallelVec::new();
-        src.push((1, 2));
-        src.push((3, 4));
-        src.push((5, 6));
-        src.push((7, 8));
-        let mut iter = src.iter_mut();
-        assert_eq!(iter.next(), Some((&mut 1, &mut 2)));
-        assert_eq!(iter.next(), Some((&mut 3, &mut 4)));
-        assert_eq!(iter.next(), Some((&mut 5, &mut 6)));
-        assert_eq!(iter.next(), Some((&mut 7, &mut 8)));
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next(), None);
-        // Shouldn't have removed any of them
-        assert_eq!(src.len(), 4);
+    fn test_scatter() {
+        let mut dst: ParallelVec<(u32, String)> = (0..6u32).map(|i| (i, i.to_string())).collect();
+        let src = ParallelVec::from(vec![(10, "10".to_string()), (20, "20".to_string())]);
+        dst.as_parallel_slice_mut().scatter(&[4, 1], src);
+        let (a, b) = dst.as_slices();
+        assert_eq!(a, &[0, 20, 2, 3, 10, 5]);
+        assert_eq!(b, &["0", "20", "2", "3", "10", "5"]);
+
+        let gathered = dst.gather(&[0, 2]);
+        dst.as_parallel_slice_mut().scatter(&[2, 0], gathered);
+        assert_eq!(dst.as_slices().0, &[2, 20, 0, 3, 10, 5]);
+
+        unsafe {
+            dst.as_parallel_slice_mut()
+                .scatter_unchecked(&[5, 5], vec![(1, "1".to_string()), (2, "2".to_string())])
+        };
+        assert_eq!(dst.as_slices().0, &[2, 20, 0, 3, 10, 2]);
     }
 
     #[test]
-    fn test_shrink_to() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        src.reserve(1000);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() >= 1000);
-        src.shrink_to(200);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= 200);
-        src.shrink_to(100);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= 100);
-        src.shrink_to(10);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= 10);
-        src.shrink_to(1);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() >= 4);
-        let (a, b) = src.as_slices();
-        assert_eq!(a, &[1, 3, 5, 7]);
-        assert_eq!(b, &[2, 4, 6, 8]);
+    #[should_panic(expected = "duplicate index in scatter")]
+    fn test_scatter_panics_on_duplicates() {
+        let mut dst = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        dst.as_parallel_slice_mut()
+            .scatter(&[1, 1], vec![(5, 6), (7, 8)]);
     }
 
     #[test]
-    fn test_shrink_to_fit() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        src.reserve(1000);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() >= 1000);
-        src.shrink_to_fit();
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= src.len());
-        let (a, b) = src.as_slices();
-        assert_eq!(a, &[1, 3, 5, 7]);
-        assert_eq!(b, &[2, 4, 6, 8]);
+    #[should_panic]
+    fn test_scatter_panics_out_of_bounds() {
+        let mut dst = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        dst.as_parallel_slice_mut().scatter(&[2], vec![(5, 6)]);
     }
 
     #[test]
-    fn test_truncate() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[1, 3, 5, 7]);
-            assert_eq!(b, &[2, 4, 6, 8]);
-        }
-        src.truncate(2);
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[1, 3]);
-            assert_eq!(b, &[2, 4]);
-            assert_eq!(src.len(), 2);
-            assert!(src.capacity() >= 4);
-        }
+    #[should_panic(expected = "too few values to scatter")]
+    fn test_scatter_panics_on_too_few_values() {
+        let mut dst = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        dst.as_parallel_slice_mut().scatter(&[0, 1], vec![(5, 6)]);
     }
 
     #[test]
-    fn test_truncate_drops() {
-        let rc = Rc::new(0);
-        let mut src = ParallelVec::new();
-        src.extend(vec![
-            (rc.clone(), rc.clone()),
-            (rc.clone(), rc.clone()),
-            (rc.clone(), rc.clone()),
-            (rc.clone(), rc.clone()),
-        ]);
-        assert_eq!(Rc::strong_count(&rc), 9);
-        src.truncate(1);
-        assert_eq!(Rc::strong_count(&rc), 3);
+    fn test_scatter_checks_values_before_writing() {
+        let mut dst = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            dst.as_parallel_slice_mut().scatter(&[1, 0], vec![(5, 6)]);
+        }));
+        assert!(result.is_err());
+        assert_eq!(dst.as_slices(), (&[1, 3][..], &[2, 4][..]));
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            dst.as_parallel_slice_mut()
+                .scatter(&[1], vec![(5, 6), (7, 8)]);
+        }));
+        assert!(result.is_err());
+        assert_eq!(dst.as_slices(), (&[1, 3][..], &[2, 4][..]));
     }
 
     #[test]
-    fn test_reverse() {
-        let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[1, 3, 5, 7]);
-            assert_eq!(b, &[2, 4, 6, 8]);
-        }
-        src.reverse();
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[7, 5, 3, 1]);
-            assert_eq!(b, &[8, 6, 4, 2]);
-        }
+    fn test_sort_matches_vec() {
+        let values: Vec<(u8, u32)> = (0..64u32).map(|i| ((i * 37 % 11) as u8, i)).collect();
+        let mut expected = values.clone();
+        expected.sort_by_key(|&(key, _)| key);
+        let mut src = ParallelVec::from(values.clone());
+        src.as_parallel_slice_mut().sort_by_key(|(key, _)| *key);
+        assert_eq!(
+            src.iter().map(|(a, b)| (*a, *b)).collect::<Vec<_>>(),
+            expected
+        );
+
+        let mut expected = values.clone();
+        expected.sort_unstable();
+        let mut src = ParallelVec::from(values);
+        src.as_parallel_slice_mut()
+            .sort_unstable_by(|a, b| a.cmp(&b));
+        assert_eq!(
+            src.iter().map(|(a, b)| (*a, *b)).collect::<Vec<_>>(),
+            expected
+        );
+        src.as_parallel_slice_mut().reverse();
+        src.as_parallel_slice_mut()
+            .sort_unstable_by_key(|(_, value)| *value);
+        assert!(src.as_slices().1.windows(2).all(|w| w[0] < w[1]));
     }
 
     #[test]
@@ -760,8 +3809,8 @@ mod tests {
         assert_eq!(src.len(), 0);
         assert!(src.capacity() > 0);
         let (a, b) = src.as_slices();
-        assert_eq!(a, &[]);
-        assert_eq!(b, &[]);
+        assert_eq!(a, &[0; 0]);
+        assert_eq!(b, &[0; 0]);
     }
 
     #[test]
@@ -779,6 +3828,19 @@ mod tests {
         assert_eq!(repeated.len(), 12);
     }
 
+    #[test]
+    fn test_repeat_clone() {
+        let src: ParallelVec<(String, ZST, u8)> =
+            (0..3u8).map(|i| (i.to_string(), ZST, i)).collect();
+        let repeated = src.repeat(5);
+        assert_eq!(repeated.len(), 15);
+        assert_eq!(repeated.capacity(), 15);
+        let expected: Vec<String> = (0..15).map(|i| (i % 3).to_string()).collect();
+        assert_eq!(repeated.as_slices().0, expected.as_slice());
+        assert!(src.repeat(0).is_empty());
+        assert!(ParallelVec::<(String, u8)>::new().repeat(10).is_empty());
+    }
+
     #[test]
     fn test_eq() {
         let a = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
@@ -795,6 +3857,137 @@ mod tests {
         assert!(c == c);
     }
 
+    #[test]
+    fn test_eq_nan() {
+        let a = ParallelVec::from(vec![(1, f64::NAN), (2, 0.0)]);
+        let b = a.clone();
+        assert!(a != a);
+        assert!(a != b);
+    }
+
+    fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_hash() {
+        let values = vec![(1, 2), (3, 4), (5, 6), (7, 8)];
+        let a = ParallelVec::from(values.clone());
+        let b = ParallelVec::from(values.clone());
+        let c = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6)]);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_ne!(hash_of(&a), hash_of(&c));
+        // Rows are hashed in order, the same way as the equivalent `Vec`.
+        assert_eq!(hash_of(&a), hash_of(&values));
+        assert_eq!(hash_of(&a), hash_of(&*a));
+
+        let mut memo = std::collections::HashMap::new();
+        memo.insert(a, "a");
+        assert_eq!(memo.get(&b), Some(&"a"));
+        assert_eq!(memo.get(&c), None);
+    }
+
+    #[test]
+    fn test_ord() {
+        let a = ParallelVec::from(vec![(1, 2), (3, 4)]);
+        let b = ParallelVec::from(vec![(1, 2), (3, 5)]);
+        let c = ParallelVec::from(vec![(1, 2), (3, 4), (0, 0)]);
+        let d = ParallelVec::from(vec![(2, 0)]);
+        // Rows are compared lexicographically, the same way as the equivalent `Vec`.
+        assert_eq!(a.cmp(&b), Ordering::Less);
+        assert_eq!(a.cmp(&c), Ordering::Less);
+        assert_eq!(c.cmp(&b), Ordering::Less);
+        assert_eq!(d.cmp(&a), Ordering::Greater);
+        assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+
+        let mut vecs = vec![d.clone(), b.clone(), c.clone(), a.clone()];
+        vecs.sort();
+        assert!(vecs == vec![a, c, b, d]);
+
+        let x = ParallelVec::from(vec![(1.0, f64::NAN)]);
+        let y = ParallelVec::from(vec![(2.0, 0.0)]);
+        assert_eq!(x.partial_cmp(&x), None);
+        assert_eq!(x.partial_cmp(&y), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn test_try_from_vecs() {
+        let src = ParallelVec::try_from((vec![1, 2, 3], vec![4u64, 5, 6])).unwrap();
+        assert_eq!(src.len(), 3);
+        assert_eq!(src.index(2), (&3, &6));
+        assert_eq!(
+            ParallelVec::try_from((vec![1, 2, 3], vec![4u64, 5])).err(),
+            Some(ParallelVecConversionError::UnevenLengths)
+        );
+
+        let drops = Rc::new(Cell::new(0));
+        let counters = vec![
+            DropCounter::new(&drops, false),
+            DropCounter::new(&drops, false),
+        ];
+        let src = ParallelVec::try_from((counters, vec![0u8, 1])).unwrap();
+        assert_eq!(drops.get(), 0);
+        drop(src);
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn test_try_from_slices() {
+        let numbers = [1, 2, 3];
+        let names = ["a".to_string(), "b".to_string(), "c".to_string()];
+        let src =
+            ParallelVec::<(i32, String)>::try_from_slices((&numbers[..], &names[..])).unwrap();
+        assert_eq!(src.as_slices().0, &numbers);
+        assert_eq!(src.as_slices().1, &names);
+        assert_eq!(src.capacity(), 3);
+
+        let src = ParallelVec::try_from((&numbers[1..], &[ZST, ZST][..])).unwrap();
+        assert_eq!(src.as_slices().0, &[2, 3]);
+        assert_eq!(
+            ParallelVec::try_from((&numbers[..], &names[1..], &numbers[..])).err(),
+            Some(ParallelVecConversionError::UnevenLengths)
+        );
+        let empty: &[u8] = &[];
+        assert!(ParallelVec::<(u8,)>::try_from_slices((empty,))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_try_from_slices_panicking_clone() {
+        struct PanicOnClone(bool);
+
+        impl Clone for PanicOnClone {
+            fn clone(&self) -> Self {
+                assert!(!self.0, "clone panicked");
+                PanicOnClone(false)
+            }
+        }
+
+        let counter = Rc::new(());
+        let counters = vec![counter.clone(); 4];
+        let values = [false, false, true, false].map(PanicOnClone);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            ParallelVec::<(Rc<()>, PanicOnClone)>::try_from_slices((&counters[..], &values[..]))
+        }));
+        assert!(result.is_err());
+        // The rows cloned before the panic are dropped, not leaked.
+        assert_eq!(Rc::strong_count(&counter), 5);
+    }
+
+    #[test]
+    fn test_eq_slice() {
+        let values = vec![(1, 2), (3, 4), (5, 6), (7, 8)];
+        let a = ParallelVec::from(values.clone());
+        assert!(a == values[..]);
+        assert!(a == values.as_slice());
+        assert!(a != values[..3]);
+        assert!(a != [(1, 2), (3, 4), (9, 6), (7, 8)][..]);
+        assert!(ParallelVec::<(i32, i32)>::new() == [][..]);
+    }
+
     #[test]
     fn test_extend() {
         let mut src = ParallelVec::new();
@@ -832,17 +4025,17 @@ mod tests {
     fn test_swap() {
         let mut src = ParallelVec::new();
         src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        src.swap(1, 2);
+        src.as_parallel_slice_mut().swap(1, 2);
         let (a, b) = src.as_slices();
         assert_eq!(a, &[1, 5, 3, 7]);
         assert_eq!(b, &[2, 6, 4, 8]);
         assert_eq!(src.len(), 4);
-        src.swap(0, 3);
+        src.as_parallel_slice_mut().swap(0, 3);
         let (a, b) = src.as_slices();
         assert_eq!(a, &[7, 5, 3, 1]);
         assert_eq!(b, &[8, 6, 4, 2]);
         assert_eq!(src.len(), 4);
-        src.swap(3, 0);
+        src.as_parallel_slice_mut().swap(3, 0);
         let (a, b) = src.as_slices();
         assert_eq!(a, &[1, 5, 3, 7]);
         assert_eq!(b, &[2, 6, 4, 8]);
@@ -854,7 +4047,7 @@ mod tests {
     fn test_swap_panics() {
         let mut src = ParallelVec::new();
         src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        src.swap(20, 2);
+        src.as_parallel_slice_mut().swap(20, 2);
     }
 
     #[test]
@@ -871,7 +4064,9 @@ mod tests {
         assert_eq!(a, &[9, 2, 4, 7]);
         assert_eq!(b, &[9, 2, 4, 7]);
         assert_eq!(src_b.len(), 4);
-        src_a.swap_with(&mut src_b);
+        src_a
+            .as_parallel_slice_mut()
+            .swap_with(&mut src_b.as_parallel_slice_mut());
         let (a, b) = src_a.as_slices();
         assert_eq!(a, &[9, 2, 4, 7]);
         assert_eq!(b, &[9, 2, 4, 7]);
@@ -882,6 +4077,62 @@ mod tests {
         assert_eq!(src_b.len(), 4);
     }
 
+    #[test]
+    fn test_swap_with_slices() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        let mut a_buf = [9, 9];
+        let mut b_buf = [0, 0];
+        src.as_parallel_slice_mut()
+            .swap_with_slices(1..3, (&mut a_buf[..], &mut b_buf[..]));
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 9, 9, 7]);
+        assert_eq!(b, &[2, 0, 0, 8]);
+        assert_eq!(src.len(), 4);
+        assert_eq!(a_buf, [3, 5]);
+        assert_eq!(b_buf, [4, 6]);
+
+        src.as_parallel_slice_mut()
+            .swap_with_slices(.., (&mut [0; 4][..], &mut [1; 4][..]));
+        assert_eq!(src.as_slices(), (&[0; 4][..], &[1; 4][..]));
+        src.as_parallel_slice_mut()
+            .swap_with_slices(4.., (&mut [][..], &mut [][..]));
+    }
+
+    #[test]
+    #[should_panic(expected = "slices of different lengths: 4 vs 3")]
+    fn test_swap_with_slices_panics() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        let mut a_buf = [9, 9, 9];
+        let mut b_buf = [0, 0, 0];
+        src.as_parallel_slice_mut()
+            .swap_with_slices(.., (&mut a_buf[..], &mut b_buf[..]));
+    }
+
+    #[test]
+    fn test_swap_with_slices_uneven_panics() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        let mut a_buf = [9, 9];
+        let mut b_buf = [0, 0, 0];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            src.as_parallel_slice_mut()
+                .swap_with_slices(1..3, (&mut a_buf[..], &mut b_buf[..]));
+        }));
+        assert!(result.is_err());
+        // Nothing is swapped if any of the lengths is wrong.
+        assert_eq!(src.as_slices().0, &[1, 3, 5, 7]);
+        assert_eq!(a_buf, [9, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 5 out of range")]
+    fn test_swap_with_slices_out_of_bounds_panics() {
+        let mut src = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.as_parallel_slice_mut()
+            .swap_with_slices(3..5, (&mut [0, 0][..], &mut [0, 0][..]));
+    }
+
     #[test]
     fn test_drop() {
         let rc = Rc::new(0);
@@ -909,8 +4160,8 @@ mod tests {
         assert_eq!(b, &[2, 4, 6, 8, 9, 2, 4, 7]);
         assert_eq!(src_a.len(), 8);
         let (a, b) = src_b.as_slices();
-        assert_eq!(a, &[]);
-        assert_eq!(b, &[]);
+        assert_eq!(a, &[0; 0]);
+        assert_eq!(b, &[0; 0]);
         assert_eq!(src_b.len(), 0);
     }
 
@@ -921,14 +4172,16 @@ mod tests {
         src_a.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
         let mut src_b = ParallelVec::new();
         src_b.extend(vec![(9, 9), (2, 2), (7, 7)]);
-        src_a.swap_with(&mut src_b);
+        src_a
+            .as_parallel_slice_mut()
+            .swap_with(&mut src_b.as_parallel_slice_mut());
     }
 
     #[test]
     fn test_set() {
         let mut src = ParallelVec::new();
         src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        src.set(2, (0, 0));
+        src.as_parallel_slice_mut().set(2, (0, 0));
         let (a, b) = src.as_slices();
         assert_eq!(a, &[1, 3, 0, 7]);
         assert_eq!(b, &[2, 4, 0, 8]);
@@ -940,7 +4193,51 @@ mod tests {
     fn test_set_panics() {
         let mut src = ParallelVec::new();
         src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        src.set(10, (0, 0));
+        src.as_parallel_slice_mut().set(10, (0, 0));
+    }
+
+    #[test]
+    fn test_replace() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        src.extend(vec![(rc.clone(), 1), (rc.clone(), 2)]);
+        assert_eq!(Rc::strong_count(&rc), 3);
+        let (old, value) = src.as_parallel_slice_mut().replace(1, (Rc::new(1), 3));
+        assert!(Rc::ptr_eq(&old, &rc));
+        assert_eq!(value, 2);
+        assert_eq!(Rc::strong_count(&rc), 3);
+        core::mem::drop(old);
+        assert_eq!(Rc::strong_count(&rc), 2);
+        assert_eq!(*src.index(1).0.as_ref(), 1);
+        assert_eq!(src.index(1).1, &3);
+        assert_eq!(src.len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_replace_panics() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.as_parallel_slice_mut().replace(10, (0, 0));
+    }
+
+    #[test]
+    fn test_take() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        assert_eq!(src.as_parallel_slice_mut().take(2), (5, 6));
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 0, 7]);
+        assert_eq!(b, &[2, 4, 0, 8]);
+        assert_eq!(src.len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_take_panics() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.as_parallel_slice_mut().take(4);
     }
 
     #[test]
@@ -967,6 +4264,27 @@ mod tests {
         assert_eq!(src.get_mut(5), None);
     }
 
+    #[test]
+    fn test_get_many_mut() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        if let Some([(a1, b1), (a2, b2)]) = src.as_parallel_slice_mut().get_many_mut([3, 0]) {
+            core::mem::swap(a1, a2);
+            *b1 += *b2;
+        } else {
+            panic!("Expected disjoint indices to succeed");
+        }
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[7, 3, 5, 1]);
+        assert_eq!(b, &[2, 4, 6, 10]);
+        assert!(src.as_parallel_slice_mut().get_many_mut([1, 1]).is_none());
+        assert!(src.as_parallel_slice_mut().get_many_mut([0, 4]).is_none());
+        assert!(src
+            .as_parallel_slice_mut()
+            .get_many_mut([0, 1, 2, 3])
+            .is_some());
+    }
+
     #[test]
     fn test_first() {
         let mut src = ParallelVec::new();
@@ -1196,4 +4514,58 @@ mod tests {
         assert_eq!(b.next(), None);
         assert_eq!(b.next(), None);
     }
+
+    #[test]
+    fn test_table() {
+        let vec = ParallelVec::from(vec![(1, "alpha", 0.5), (22, "b", 10.25)]);
+        assert_eq!(
+            vec.table().to_string(),
+            "| 1  | alpha | 0.5   |\n| 22 | b     | 10.25 |\n"
+        );
+        assert_eq!(
+            vec.table().headers(&["id", "name"]).to_string(),
+            "| id | name  |       |\n\
+             |----|-------|-------|\n\
+             | 1  | alpha | 0.5   |\n\
+             | 22 | b     | 10.25 |\n"
+        );
+        assert_eq!(
+            vec.get(1..)
+                .unwrap()
+                .table()
+                .headers(&["identifier"])
+                .to_string(),
+            "| identifier |   |       |\n\
+             |------------|---|-------|\n\
+             | 22         | b | 10.25 |\n"
+        );
+
+        let empty: ParallelVec<(u8, u8)> = ParallelVec::new();
+        assert_eq!(empty.table().to_string(), "");
+        assert_eq!(
+            empty.table().headers(&["a", "b"]).to_string(),
+            "| a | b |\n|---|---|\n"
+        );
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn is_send<T: Send>() {}
+        fn is_sync<T: Sync>() {}
+        is_send::<ParallelVec<(u32, Vec<u8>)>>();
+        is_sync::<ParallelVec<(u32, Vec<u8>)>>();
+        is_send::<crate::iter::IntoIter<(u32, Vec<u8>)>>();
+
+        let mut vec = ParallelVec::from(vec![(1, vec![2u8]), (3, vec![4])]);
+        let vec = std::thread::spawn(move || {
+            vec.push((5, vec![6]));
+            vec
+        })
+        .join()
+        .unwrap();
+        std::thread::scope(|scope| {
+            scope.spawn(|| assert_eq!(vec.len(), 3));
+            scope.spawn(|| assert_eq!(vec.index(2), (&5, &vec![6])));
+        });
+    }
 }
@@ -0,0 +1,190 @@
+use crate::{GrowthPolicy, ParallelParam, ParallelVec, Storage};
+use bytemuck::Pod;
+use core::{mem::size_of, ops::Range};
+use wgpu_types::{BufferAddress, ShaderLocation, VertexAttribute, VertexFormat};
+
+/// A field of a [`ParallelVec`], placed within a GPU buffer holding the entire buffer of
+/// the vector.
+///
+/// Each field is its own vertex buffer stream: bind [`GpuColumn::range`] of the GPU buffer
+/// as a vertex buffer, with a layout using [`GpuColumn::array_stride`] and
+/// [`GpuColumn::attribute`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpuColumn<'a> {
+    bytes: &'a [u8],
+    offset: BufferAddress,
+    array_stride: BufferAddress,
+}
+
+impl<'a> GpuColumn<'a> {
+    /// The bytes of the elements of the field.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The offset in bytes of the field from the start of the GPU buffer.
+    pub fn offset(&self) -> BufferAddress {
+        self.offset
+    }
+
+    /// The range of bytes of the GPU buffer occupied by the elements of the field.
+    pub fn range(&self) -> Range<BufferAddress> {
+        self.offset..self.offset + self.bytes.len() as BufferAddress
+    }
+
+    /// The distance in bytes between consecutive elements of the field.
+    pub fn array_stride(&self) -> BufferAddress {
+        self.array_stride
+    }
+
+    /// Creates a vertex attribute reading the field as `format` at `shader_location`.
+    pub fn attribute(
+        &self,
+        format: VertexFormat,
+        shader_location: ShaderLocation,
+    ) -> VertexAttribute {
+        VertexAttribute {
+            format,
+            offset: 0,
+            shader_location,
+        }
+    }
+}
+
+impl<Param: ParallelParam, Growth: GrowthPolicy, A: Storage> ParallelVec<Param, Growth, A> {
+    /// Gets the size in bytes of a GPU buffer that can hold the entire buffer of the vector,
+    /// including the spare capacity of each field.
+    pub fn gpu_buffer_size(&self) -> BufferAddress {
+        if self.capacity == 0 || size_of::<Param>() == 0 {
+            return 0;
+        }
+        self.memory_layout().layout().size() as BufferAddress
+    }
+
+    /// Gets the offset of each field within the buffer of the vector.
+    fn gpu_offsets(&self) -> Param::Offsets {
+        if self.capacity == 0 || size_of::<Param>() == 0 {
+            return Param::field_sizes(0);
+        }
+        self.memory_layout().offsets
+    }
+}
+
+macro_rules! impl_wgpu {
+    ($n:literal, $($ts:ident $vs:ident $idx:tt),*) => {
+        impl<$($ts: Pod,)* Growth: GrowthPolicy, A: Storage> ParallelVec<($($ts,)*), Growth, A> {
+            /// Gets the placement of every field within a GPU buffer holding the entire
+            /// buffer of the vector, as laid out by [`ParallelVec::memory_layout`].
+            ///
+            /// The whole vector can be uploaded at once by writing
+            /// [`ParallelVec::allocation_bytes`] to the start of a buffer of
+            /// [`ParallelVec::gpu_buffer_size`] bytes, or one field at a time by writing
+            /// [`GpuColumn::bytes`] at [`GpuColumn::offset`].
+            ///
+            /// Each offset is aligned to the alignment of its field. wgpu requires vertex
+            /// buffer offsets and copies to be aligned to 4 bytes, which holds for every
+            /// field if the capacity of the vector is a multiple of 4, or if the
+            /// [column alignment] of the vector is at least 4.
+            ///
+            /// [column alignment]: ParallelVec::set_column_alignment
+            pub fn gpu_columns(&self) -> [GpuColumn<'_>; $n] {
+                let ($($vs,)*) = self.as_slices();
+                let offsets = self.gpu_offsets();
+                [$(
+                    GpuColumn {
+                        bytes: bytemuck::cast_slice::<$ts, u8>($vs),
+                        offset: offsets[$idx] as BufferAddress,
+                        array_stride: size_of::<$ts>() as BufferAddress,
+                    }
+                ),*]
+            }
+        }
+    };
+}
+
+impl_wgpu!(1, T1 V1 0);
+impl_wgpu!(2, T1 V1 0, T2 V2 1);
+impl_wgpu!(3, T1 V1 0, T2 V2 1, T3 V3 2);
+impl_wgpu!(4, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3);
+impl_wgpu!(5, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4);
+impl_wgpu!(6, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5);
+impl_wgpu!(7, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6);
+impl_wgpu!(8, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7);
+impl_wgpu!(9, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8);
+impl_wgpu!(
+    10, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9
+);
+impl_wgpu!(
+    11, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8,
+    T10 V10 9, T11 V11 10
+);
+impl_wgpu!(
+    12, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8,
+    T10 V10 9, T11 V11 10, T12 V12 11
+);
+impl_wgpu!(
+    13, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8,
+    T10 V10 9, T11 V11 10, T12 V12 11, T13 V13 12
+);
+impl_wgpu!(
+    14, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8,
+    T10 V10 9, T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13
+);
+impl_wgpu!(
+    15, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8,
+    T10 V10 9, T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13, T15 V15 14
+);
+impl_wgpu!(
+    16, T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8,
+    T10 V10 9, T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13, T15 V15 14, T16 V16 15
+);
+
+#[cfg(test)]
+mod test {
+    use crate::ParallelVec;
+    use std::vec::Vec;
+    use wgpu_types::{VertexFormat, COPY_BUFFER_ALIGNMENT};
+
+    #[test]
+    fn test_gpu_columns_match_allocation() {
+        let mut vec: ParallelVec<([f32; 3], [u8; 4])> = ParallelVec::with_capacity(4);
+        vec.push(([1.0, 2.0, 3.0], [255, 0, 0, 255]));
+        vec.push(([4.0, 5.0, 6.0], [0, 255, 0, 255]));
+        let capacity = vec.capacity() as u64;
+        let size = vec.gpu_buffer_size();
+        let [positions, colors] = vec.gpu_columns();
+        assert_eq!(positions.offset(), 0);
+        assert_eq!(positions.array_stride(), 12);
+        assert_eq!(positions.range(), 0..24);
+        assert_eq!(colors.offset(), 12 * capacity);
+        assert_eq!(colors.array_stride(), 4);
+        assert_eq!(colors.range(), 12 * capacity..12 * capacity + 8);
+        assert_eq!(colors.offset() % COPY_BUFFER_ALIGNMENT, 0);
+        assert_eq!(size, 16 * capacity);
+
+        let attribute = colors.attribute(VertexFormat::Unorm8x4, 1);
+        assert_eq!(attribute.offset, 0);
+        assert_eq!(attribute.shader_location, 1);
+
+        // Writing each column at its offset produces the same buffer as the allocation.
+        let (positions, colors) = (positions.range(), colors.range());
+        let columns: Vec<_> = vec
+            .gpu_columns()
+            .iter()
+            .map(|c| c.bytes().to_vec())
+            .collect();
+        let mut buffer = vec![0u8; size as usize];
+        buffer[positions.start as usize..positions.end as usize].copy_from_slice(&columns[0]);
+        buffer[colors.start as usize..colors.end as usize].copy_from_slice(&columns[1]);
+        assert_eq!(vec.allocation_bytes(), &buffer[..]);
+    }
+
+    #[test]
+    fn test_gpu_columns_empty() {
+        let vec: ParallelVec<(f32, u32)> = ParallelVec::new();
+        assert_eq!(vec.gpu_buffer_size(), 0);
+        let [a, b] = vec.gpu_columns();
+        assert!(a.bytes().is_empty());
+        assert_eq!(b.range(), 0..0);
+    }
+}
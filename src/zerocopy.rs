@@ -0,0 +1,189 @@
+use crate::{
+    ParallelParam, ParallelSlice, ParallelSliceMut, ParallelVec, ParallelVecFromBytesError,
+};
+use core::mem::size_of;
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+/// Checks that `bytes` holds a whole number of `T`, and that it is the same number as
+/// every other column seen so far. Zero-sized columns must be empty.
+fn check_column<T>(bytes: &[u8], len: &mut Option<usize>) -> Result<(), ParallelVecFromBytesError> {
+    let size = size_of::<T>();
+    if size == 0 {
+        return if bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(ParallelVecFromBytesError::IncompleteElement)
+        };
+    }
+    let column_len = bytes.len() / size;
+    if column_len * size != bytes.len() {
+        return Err(ParallelVecFromBytesError::IncompleteElement);
+    }
+    if *len.get_or_insert(column_len) != column_len {
+        return Err(ParallelVecFromBytesError::UnevenLengths);
+    }
+    Ok(())
+}
+
+macro_rules! bytes {
+    ($t:ident) => {
+        &[u8]
+    };
+}
+
+macro_rules! bytes_mut {
+    ($t:ident) => {
+        &mut [u8]
+    };
+}
+
+macro_rules! impl_zerocopy {
+    ($($ts:ident $vs:ident $idx:tt),*) => {
+        impl<'a, $($ts: IntoBytes + Immutable),*> ParallelSlice<'a, ($($ts,)*)> {
+            /// Gets the bytes of every field.
+            pub fn as_column_bytes(&self) -> ($(bytes!($ts),)*) {
+                let ($($vs,)*) = self.as_slices();
+                ($($vs.as_bytes(),)*)
+            }
+        }
+
+        impl<'a, $($ts: IntoBytes + Immutable),*> ParallelSliceMut<'a, ($($ts,)*)> {
+            /// Gets the bytes of every field.
+            pub fn as_column_bytes(&self) -> ($(bytes!($ts),)*) {
+                let ($($vs,)*) = self.as_slices();
+                ($($vs.as_bytes(),)*)
+            }
+        }
+
+        impl<'a, $($ts: IntoBytes + FromBytes),*> ParallelSliceMut<'a, ($($ts,)*)> {
+            /// Gets the mutable bytes of every field.
+            pub fn as_column_bytes_mut(&mut self) -> ($(bytes_mut!($ts),)*) {
+                let ($($vs,)*) = self.as_slices_mut();
+                ($($vs.as_mut_bytes(),)*)
+            }
+        }
+
+        impl<$($ts: FromBytes),*> ParallelVec<($($ts,)*)> {
+            /// Creates a vector by copying the values out of a byte buffer per field,
+            /// such as one returned by [`ParallelSliceMut::as_column_bytes`].
+            ///
+            /// The buffers do not need to be aligned. The buffer of a zero-sized field
+            /// must be empty, and does not affect the length of the vector.
+            ///
+            /// # Errors
+            /// Returns [`ParallelVecFromBytesError::IncompleteElement`] if the length
+            /// of a buffer is not a multiple of the size of its field, and
+            /// [`ParallelVecFromBytesError::UnevenLengths`] if the buffers do not hold
+            /// the same number of elements.
+            pub fn from_column_bytes(
+                columns: ($(bytes!($ts),)*),
+            ) -> Result<Self, ParallelVecFromBytesError> {
+                let ($($vs,)*) = columns;
+                let mut len = None;
+                $(check_column::<$ts>($vs, &mut len)?;)*
+                let len = len.unwrap_or(0);
+                let mut vec = Self::with_capacity(len);
+                let dst = <($($ts,)*) as ParallelParam>::as_ptr(vec.storage);
+                // SAFE: Every buffer holds exactly `len` elements, the vector has the
+                // capacity for `len` elements, and any bytes are valid values of each field.
+                unsafe {
+                    $($vs.as_ptr().copy_to_nonoverlapping(dst.$idx.cast::<u8>().as_ptr(), $vs.len());)*
+                    vec.len = len;
+                }
+                Ok(vec)
+            }
+        }
+    };
+}
+
+impl_zerocopy!(T1 V1 0);
+impl_zerocopy!(T1 V1 0, T2 V2 1);
+impl_zerocopy!(T1 V1 0, T2 V2 1, T3 V3 2);
+impl_zerocopy!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3);
+impl_zerocopy!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4);
+impl_zerocopy!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5);
+impl_zerocopy!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6);
+impl_zerocopy!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7);
+impl_zerocopy!(T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8);
+impl_zerocopy!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9
+);
+impl_zerocopy!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10
+);
+impl_zerocopy!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11
+);
+impl_zerocopy!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12
+);
+impl_zerocopy!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13
+);
+impl_zerocopy!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13, T15 V15 14
+);
+impl_zerocopy!(
+    T1 V1 0, T2 V2 1, T3 V3 2, T4 V4 3, T5 V5 4, T6 V6 5, T7 V7 6, T8 V8 7, T9 V9 8, T10 V10 9,
+    T11 V11 10, T12 V12 11, T13 V13 12, T14 V14 13, T15 V15 14, T16 V16 15
+);
+
+#[cfg(test)]
+mod test {
+    use crate::{ParallelVec, ParallelVecFromBytesError};
+
+    #[test]
+    fn test_column_bytes_round_trip() {
+        let mut vec = ParallelVec::from(vec![(1u8, 2u32, 3.5f64), (4, 5, 6.5)]);
+        let (a, b, c) = vec.as_column_bytes();
+        assert_eq!(a, &[1, 4]);
+        assert_eq!(&b[..4], &2u32.to_ne_bytes());
+        assert_eq!(&c[8..], &6.5f64.to_ne_bytes());
+
+        let copy = ParallelVec::<(u8, u32, f64)>::from_column_bytes((a, b, c)).unwrap();
+        assert!(copy == vec);
+
+        let mut slice = vec.as_parallel_slice_mut();
+        let (a, _, _) = slice.as_column_bytes_mut();
+        a[0] = 7;
+        assert_eq!(vec.index(0), (&7, &2, &3.5));
+    }
+
+    #[test]
+    fn test_from_column_bytes_unaligned() {
+        let bytes = [0u8, 1, 0, 0, 0, 2, 0, 0, 0];
+        let vec = ParallelVec::<(u32,)>::from_column_bytes((&bytes[1..],)).unwrap();
+        assert_eq!(
+            vec.as_slices().0,
+            &[
+                u32::from_ne_bytes([1, 0, 0, 0]),
+                u32::from_ne_bytes([2, 0, 0, 0])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_column_bytes_errors() {
+        assert_eq!(
+            ParallelVec::<(u16, u8)>::from_column_bytes((&[0; 3], &[0; 1])).err(),
+            Some(ParallelVecFromBytesError::IncompleteElement)
+        );
+        assert_eq!(
+            ParallelVec::<(u16, u8)>::from_column_bytes((&[0; 4], &[0; 1])).err(),
+            Some(ParallelVecFromBytesError::UnevenLengths)
+        );
+        assert_eq!(
+            ParallelVec::<(u16, ())>::from_column_bytes((&[0; 4], &[0; 1])).err(),
+            Some(ParallelVecFromBytesError::IncompleteElement)
+        );
+        let vec = ParallelVec::<(u16, ())>::from_column_bytes((&[0; 4], &[])).unwrap();
+        assert_eq!(vec.len(), 2);
+        let vec = ParallelVec::<(u16, u8)>::from_column_bytes((&[], &[])).unwrap();
+        assert!(vec.is_empty());
+    }
+}